@@ -9,6 +9,9 @@ use crate::types::{AgreementStatus, PaymentSplit, RentAgreement};
 const TTL_THRESHOLD: u32 = 500000;
 const TTL_BUMP: u32 = 500000;
 
+/// Interval between rent payments (30 days)
+pub const PAYMENT_INTERVAL: u64 = 2_592_000;
+
 /// Validate agreement parameters
 ///
 /// Ensures monthly_rent is strictly positive (i128 > 0) to prevent logical errors
@@ -309,3 +312,100 @@ pub fn get_payment_split(
         .get(month)
         .ok_or(RentalError::AgreementNotFound)
 }
+
+/// Record a rent period paid in full, as reported by the configured payment
+/// contract.
+///
+/// Updates the agreement's running totals and payment history, and advances
+/// the next due date by one payment interval.
+pub fn record_payment(
+    env: &Env,
+    agreement_id: String,
+    amount: i128,
+    split: PaymentSplit,
+) -> Result<(), RentalError> {
+    let payment_contract: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::PaymentContract)
+        .ok_or(RentalError::PaymentContractNotSet)?;
+
+    // Only the payment contract can report payments
+    payment_contract.require_auth();
+
+    let mut agreement: RentAgreement = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)?;
+
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    if amount <= 0 {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    agreement
+        .payment_history
+        .set(agreement.payment_count, split);
+    agreement.payment_count += 1;
+    agreement.total_rent_paid += amount;
+    agreement.next_payment_due += PAYMENT_INTERVAL;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Agreement(agreement_id),
+        TTL_THRESHOLD,
+        TTL_BUMP,
+    );
+
+    events::rent_recorded(env, &agreement, amount);
+
+    Ok(())
+}
+
+pub fn reverse_payment(env: &Env, agreement_id: String, periods: u32) -> Result<(), RentalError> {
+    let payment_contract: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::PaymentContract)
+        .ok_or(RentalError::PaymentContractNotSet)?;
+
+    // Only the payment contract can reverse payments
+    payment_contract.require_auth();
+
+    let mut agreement: RentAgreement = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)?;
+
+    if periods == 0 || periods > agreement.payment_count {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    // Reopen the most recently paid periods
+    for _ in 0..periods {
+        agreement.payment_count -= 1;
+        agreement.payment_history.remove(agreement.payment_count);
+        agreement.total_rent_paid -= agreement.monthly_rent;
+        agreement.next_payment_due -= PAYMENT_INTERVAL;
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Agreement(agreement_id),
+        TTL_THRESHOLD,
+        TTL_BUMP,
+    );
+
+    events::rent_reversed(env, &agreement, periods);
+
+    Ok(())
+}
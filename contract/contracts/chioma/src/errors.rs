@@ -18,4 +18,5 @@ pub enum RentalError {
     InvalidState = 15,
     Expired = 16,
     ContractPaused = 17,
+    PaymentContractNotSet = 19,
 }
@@ -1,4 +1,4 @@
-use crate::Config;
+use crate::{Config, RentAgreement};
 use soroban_sdk::{contractevent, Address, Env, String};
 
 /// Event emitted when the contract is initialized
@@ -76,6 +76,45 @@ pub struct ConfigUpdated {
     pub new_paused: bool,
 }
 
+/// Event emitted when the admin sets the payment contract
+/// Topics: ["pay_contract", admin: Address]
+#[contractevent(topics = ["pay_contract"])]
+pub struct PaymentContractSet {
+    #[topic]
+    pub admin: Address,
+    pub payment_contract: Address,
+}
+
+/// Event emitted when the payment contract records a rent payment
+/// Topics: ["rent_recorded", tenant: Address, landlord: Address]
+#[contractevent(topics = ["rent_recorded"])]
+pub struct RentRecorded {
+    #[topic]
+    pub tenant: Address,
+    #[topic]
+    pub landlord: Address,
+    pub agreement_id: String,
+    pub amount: i128,
+    pub payment_count: u32,
+    pub total_rent_paid: i128,
+    pub next_payment_due: u64,
+}
+
+/// Event emitted when the payment contract reopens refunded rent periods
+/// Topics: ["rent_reversed", tenant: Address, landlord: Address]
+#[contractevent(topics = ["rent_reversed"])]
+pub struct RentReversed {
+    #[topic]
+    pub tenant: Address,
+    #[topic]
+    pub landlord: Address,
+    pub agreement_id: String,
+    pub periods: u32,
+    pub payment_count: u32,
+    pub total_rent_paid: i128,
+    pub next_payment_due: u64,
+}
+
 /// Helper function to emit contract initialized event
 pub(crate) fn contract_initialized(env: &Env, admin: Address, config: Config) {
     ContractInitialized {
@@ -173,3 +212,40 @@ pub(crate) fn config_updated(env: &Env, admin: Address, old_config: Config, new_
     }
     .publish(env);
 }
+
+/// Helper function to emit payment contract set event
+pub(crate) fn payment_contract_set(env: &Env, admin: Address, payment_contract: Address) {
+    PaymentContractSet {
+        admin,
+        payment_contract,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit rent recorded event
+pub(crate) fn rent_recorded(env: &Env, agreement: &RentAgreement, amount: i128) {
+    RentRecorded {
+        tenant: agreement.tenant.clone(),
+        landlord: agreement.landlord.clone(),
+        agreement_id: agreement.agreement_id.clone(),
+        amount,
+        payment_count: agreement.payment_count,
+        total_rent_paid: agreement.total_rent_paid,
+        next_payment_due: agreement.next_payment_due,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit rent reversed event
+pub(crate) fn rent_reversed(env: &Env, agreement: &RentAgreement, periods: u32) {
+    RentReversed {
+        tenant: agreement.tenant.clone(),
+        landlord: agreement.landlord.clone(),
+        agreement_id: agreement.agreement_id.clone(),
+        periods,
+        payment_count: agreement.payment_count,
+        total_rent_paid: agreement.total_rent_paid,
+        next_payment_due: agreement.next_payment_due,
+    }
+    .publish(env);
+}
@@ -14,7 +14,8 @@ mod tests;
 
 pub use agreement::{
     cancel_agreement, create_agreement, get_agreement, get_agreement_count, get_payment_split,
-    has_agreement, sign_agreement, submit_agreement, validate_agreement_params,
+    has_agreement, record_payment, reverse_payment, sign_agreement, submit_agreement,
+    validate_agreement_params, PAYMENT_INTERVAL,
 };
 pub use errors::RentalError;
 pub use storage::DataKey;
@@ -273,4 +274,85 @@ impl Contract {
     ) -> Result<PaymentSplit, RentalError> {
         agreement::get_payment_split(&env, agreement_id, month)
     }
+
+    /// Set the payment contract allowed to record rent payments (admin only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `payment_contract` - Address of the payment contract
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if set, otherwise an error
+    pub fn set_payment_contract(env: Env, payment_contract: Address) -> Result<(), RentalError> {
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+
+        state.admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::PaymentContract, &payment_contract);
+        env.storage().instance().extend_ttl(500000, 500000);
+
+        events::payment_contract_set(&env, state.admin, payment_contract);
+
+        Ok(())
+    }
+
+    /// Get the payment contract allowed to record rent payments.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The payment contract if set, otherwise None
+    pub fn get_payment_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PaymentContract)
+    }
+
+    /// Record a rent period paid in full against an agreement (payment contract only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `amount` - The rent amount paid
+    /// * `split` - How the payment was distributed
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if recorded, otherwise an error
+    ///
+    /// # Errors
+    /// * `PaymentContractNotSet` - If no payment contract is configured
+    /// * `AgreementNotFound` - If the agreement does not exist
+    /// * `AgreementNotActive` - If the agreement is not active
+    pub fn record_payment(
+        env: Env,
+        agreement_id: String,
+        amount: i128,
+        split: PaymentSplit,
+    ) -> Result<(), RentalError> {
+        agreement::record_payment(&env, agreement_id, amount, split)
+    }
+
+    /// Reopen the most recently paid rent periods of an agreement after their
+    /// rent was refunded (payment contract only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `periods` - Number of paid periods to reopen
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if reversed, otherwise an error
+    ///
+    /// # Errors
+    /// * `PaymentContractNotSet` - If no payment contract is configured
+    /// * `AgreementNotFound` - If the agreement does not exist
+    /// * `InvalidAmount` - If `periods` is zero or exceeds the periods paid
+    pub fn reverse_payment(
+        env: Env,
+        agreement_id: String,
+        periods: u32,
+    ) -> Result<(), RentalError> {
+        agreement::reverse_payment(&env, agreement_id, periods)
+    }
 }
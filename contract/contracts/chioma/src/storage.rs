@@ -7,4 +7,5 @@ pub enum DataKey {
     AgreementCount,
     State,
     Initialized,
+    PaymentContract,
 }
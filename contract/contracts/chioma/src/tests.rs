@@ -1172,3 +1172,122 @@ fn test_contract_paused_operations() {
     let res_sign_success = client.try_sign_agreement(&tenant, &agreement_id);
     assert!(res_sign_success.is_ok());
 }
+
+fn create_active_agreement(
+    env: &Env,
+    client: &ContractClient,
+    agreement_id: &str,
+    tenant: &Address,
+    landlord: &Address,
+) {
+    create_pending_agreement(env, client, agreement_id, tenant, landlord);
+    client.sign_agreement(tenant, &String::from_str(env, agreement_id));
+}
+
+#[test]
+fn test_record_payment_updates_agreement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    create_active_agreement(&env, &client, "PAY_001", &tenant, &landlord);
+
+    let payment_contract = Address::generate(&env);
+    client.set_payment_contract(&payment_contract);
+    assert_eq!(client.get_payment_contract(), Some(payment_contract));
+
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    let agreement_id = String::from_str(&env, "PAY_001");
+    let split = PaymentSplit {
+        landlord_amount: 900,
+        platform_amount: 100,
+        token: Address::generate(&env),
+        payment_date: 500,
+        payer: tenant.clone(),
+    };
+    client.record_payment(&agreement_id, &1000, &split);
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.total_rent_paid, 1000);
+    assert_eq!(agreement.payment_count, 1);
+    assert_eq!(agreement.next_payment_due, 100 + PAYMENT_INTERVAL);
+    assert_eq!(client.get_payment_split(&agreement_id, &0), split);
+}
+
+#[test]
+fn test_record_payment_requires_payment_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    create_pending_agreement(&env, &client, "PAY_002", &tenant, &landlord);
+
+    let agreement_id = String::from_str(&env, "PAY_002");
+    let split = PaymentSplit {
+        landlord_amount: 1000,
+        platform_amount: 0,
+        token: Address::generate(&env),
+        payment_date: 0,
+        payer: tenant.clone(),
+    };
+    let result = client.try_record_payment(&agreement_id, &1000, &split);
+    assert_eq!(result, Err(Ok(RentalError::PaymentContractNotSet)));
+
+    client.set_payment_contract(&Address::generate(&env));
+    let result = client.try_record_payment(&agreement_id, &1000, &split);
+    assert_eq!(result, Err(Ok(RentalError::AgreementNotActive)));
+}
+
+#[test]
+fn test_reverse_payment_reopens_periods() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    create_active_agreement(&env, &client, "PAY_003", &tenant, &landlord);
+    client.set_payment_contract(&Address::generate(&env));
+
+    let agreement_id = String::from_str(&env, "PAY_003");
+    let split = PaymentSplit {
+        landlord_amount: 900,
+        platform_amount: 100,
+        token: Address::generate(&env),
+        payment_date: 0,
+        payer: tenant.clone(),
+    };
+    client.record_payment(&agreement_id, &1000, &split);
+    client.record_payment(&agreement_id, &1000, &split);
+    let paid = client.get_agreement(&agreement_id).unwrap();
+
+    let result = client.try_reverse_payment(&agreement_id, &3);
+    assert_eq!(result, Err(Ok(RentalError::InvalidAmount)));
+
+    client.reverse_payment(&agreement_id, &1);
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.payment_count, 1);
+    assert_eq!(
+        agreement.total_rent_paid,
+        paid.total_rent_paid - paid.monthly_rent
+    );
+    assert_eq!(
+        agreement.next_payment_due,
+        paid.next_payment_due - PAYMENT_INTERVAL
+    );
+    assert_eq!(agreement.payment_history.len(), 1);
+}
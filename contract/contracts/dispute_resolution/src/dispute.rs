@@ -1,9 +1,9 @@
-use soroban_sdk::{contracttype, Address, Env, Map, String};
+use soroban_sdk::{contracttype, vec, Address, BytesN, Env, IntoVal, Map, String, Symbol};
 
 use crate::errors::DisputeError;
 use crate::events;
 use crate::storage::DataKey;
-use crate::types::{Arbiter, ContractState, Dispute, DisputeOutcome, Vote};
+use crate::types::{Arbiter, ContractState, Dispute, DisputeOutcome, EscrowLink, Vote};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -254,15 +254,147 @@ pub fn resolve_dispute(env: &Env, agreement_id: String) -> Result<DisputeOutcome
 
     events::dispute_resolved(
         env,
-        agreement_id,
+        agreement_id.clone(),
         outcome.clone(),
         dispute.votes_favor_landlord,
         dispute.votes_favor_tenant,
     );
 
+    // Execute the outcome on a linked escrow. A failing callback must not
+    // block resolution; it is recorded on the link and can be retried.
+    if let Some(link) = get_escrow_link(env, agreement_id.clone()) {
+        execute_escrow_outcome(env, agreement_id, link, outcome.clone());
+    }
+
     Ok(outcome)
 }
 
+pub fn execute_linked_outcome(
+    env: &Env,
+    agreement_id: String,
+) -> Result<DisputeOutcome, DisputeError> {
+    if !env.storage().instance().has(&DataKey::State) {
+        return Err(DisputeError::NotInitialized);
+    }
+
+    let dispute = get_dispute(env, agreement_id.clone()).ok_or(DisputeError::DisputeNotFound)?;
+    let outcome = dispute
+        .get_outcome()
+        .ok_or(DisputeError::DisputeNotResolved)?;
+
+    let link = get_escrow_link(env, agreement_id.clone()).ok_or(DisputeError::EscrowNotLinked)?;
+    if link.executed {
+        return Err(DisputeError::OutcomeAlreadyExecuted);
+    }
+
+    if !execute_escrow_outcome(env, agreement_id, link, outcome.clone()) {
+        return Err(DisputeError::OutcomeExecutionFailed);
+    }
+
+    Ok(outcome)
+}
+
+/// Deliver `outcome` to the linked escrow's `execute_outcome` callback and
+/// record on the link whether it succeeded.
+fn execute_escrow_outcome(
+    env: &Env,
+    agreement_id: String,
+    mut link: EscrowLink,
+    outcome: DisputeOutcome,
+) -> bool {
+    let executed = env
+        .try_invoke_contract::<(), soroban_sdk::Error>(
+            &link.escrow_contract,
+            &Symbol::new(env, "execute_outcome"),
+            vec![
+                env,
+                link.escrow_id.clone().into_val(env),
+                outcome.clone().into_val(env),
+            ],
+        )
+        .is_ok_and(|result| result.is_ok());
+
+    if executed {
+        link.executed = true;
+        let key = DataKey::EscrowLink(agreement_id.clone());
+        env.storage().persistent().set(&key, &link);
+        env.storage().persistent().extend_ttl(&key, 500000, 500000);
+    }
+
+    events::escrow_outcome_executed(
+        env,
+        agreement_id,
+        link.escrow_contract,
+        link.escrow_id,
+        outcome,
+        executed,
+    );
+
+    executed
+}
+
+pub fn register_escrow(
+    env: &Env,
+    escrow_contract: Address,
+    escrow_id: BytesN<32>,
+    agreement_id: String,
+    caller: Address,
+    depositor: Address,
+    beneficiary: Address,
+) -> Result<(), DisputeError> {
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(DisputeError::NotInitialized)?;
+
+    escrow_contract.require_auth();
+
+    let key = DataKey::EscrowLink(agreement_id.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(DisputeError::EscrowAlreadyLinked);
+    }
+
+    // Cross-contract call to get agreement from chioma contract
+    let agreement: Option<RentAgreement> = env.invoke_contract(
+        &state.chioma_contract,
+        &soroban_sdk::symbol_short!("get_agr"),
+        soroban_sdk::vec![env, agreement_id.clone().into()],
+    );
+
+    let agreement = agreement.ok_or(DisputeError::AgreementNotFound)?;
+
+    // The escrow must hold the agreement's deposit: tenant in, landlord out
+    if depositor != agreement.tenant || beneficiary != agreement.landlord {
+        return Err(DisputeError::Unauthorized);
+    }
+
+    // Only an agreement party can submit its escrow to this panel
+    if caller != agreement.tenant && caller != agreement.landlord {
+        return Err(DisputeError::Unauthorized);
+    }
+    caller.require_auth();
+
+    let link = EscrowLink {
+        escrow_contract: escrow_contract.clone(),
+        escrow_id: escrow_id.clone(),
+        linked_at: env.ledger().timestamp(),
+        executed: false,
+    };
+
+    env.storage().persistent().set(&key, &link);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+
+    events::escrow_linked(env, agreement_id, escrow_contract, escrow_id);
+
+    Ok(())
+}
+
+pub fn get_escrow_link(env: &Env, agreement_id: String) -> Option<EscrowLink> {
+    let key = DataKey::EscrowLink(agreement_id);
+    env.storage().persistent().get(&key)
+}
+
 pub fn get_dispute(env: &Env, agreement_id: String) -> Option<Dispute> {
     let key = DataKey::Dispute(agreement_id);
     env.storage().persistent().get(&key)
@@ -17,4 +17,9 @@ pub enum DisputeError {
     InsufficientVotes = 11,
     AgreementNotFound = 12,
     InvalidAgreementState = 13,
+    EscrowAlreadyLinked = 14,
+    DisputeNotResolved = 15,
+    EscrowNotLinked = 16,
+    OutcomeAlreadyExecuted = 17,
+    OutcomeExecutionFailed = 18,
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{contractevent, Address, Env, String};
+use soroban_sdk::{contractevent, Address, BytesN, Env, String};
 
 use crate::types::DisputeOutcome;
 
@@ -42,6 +42,24 @@ pub struct DisputeResolved {
     pub votes_favor_tenant: u32,
 }
 
+#[contractevent(topics = ["escrow_linked"])]
+pub struct EscrowLinked {
+    #[topic]
+    pub agreement_id: String,
+    pub escrow_contract: Address,
+    pub escrow_id: BytesN<32>,
+}
+
+#[contractevent(topics = ["escrow_outcome"])]
+pub struct EscrowOutcomeExecuted {
+    #[topic]
+    pub agreement_id: String,
+    pub escrow_contract: Address,
+    pub escrow_id: BytesN<32>,
+    pub outcome: DisputeOutcome,
+    pub executed: bool,
+}
+
 pub(crate) fn contract_initialized(env: &Env, admin: Address, min_votes_required: u32) {
     ContractInitialized {
         admin,
@@ -86,3 +104,35 @@ pub(crate) fn dispute_resolved(
     }
     .publish(env);
 }
+
+pub(crate) fn escrow_linked(
+    env: &Env,
+    agreement_id: String,
+    escrow_contract: Address,
+    escrow_id: BytesN<32>,
+) {
+    EscrowLinked {
+        agreement_id,
+        escrow_contract,
+        escrow_id,
+    }
+    .publish(env);
+}
+
+pub(crate) fn escrow_outcome_executed(
+    env: &Env,
+    agreement_id: String,
+    escrow_contract: Address,
+    escrow_id: BytesN<32>,
+    outcome: DisputeOutcome,
+    executed: bool,
+) {
+    EscrowOutcomeExecuted {
+        agreement_id,
+        escrow_contract,
+        escrow_id,
+        outcome,
+        executed,
+    }
+    .publish(env);
+}
@@ -1,6 +1,6 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String};
 
 mod dispute;
 mod errors;
@@ -12,12 +12,12 @@ mod types;
 mod tests;
 
 pub use dispute::{
-    add_arbiter, get_arbiter, get_arbiter_count, get_dispute, get_vote, raise_dispute,
-    resolve_dispute, vote_on_dispute,
+    add_arbiter, execute_linked_outcome, get_arbiter, get_arbiter_count, get_dispute,
+    get_escrow_link, get_vote, raise_dispute, register_escrow, resolve_dispute, vote_on_dispute,
 };
 pub use errors::DisputeError;
 pub use storage::DataKey;
-pub use types::{Arbiter, ContractState, Dispute, DisputeOutcome, Vote};
+pub use types::{Arbiter, ContractState, Dispute, DisputeOutcome, EscrowLink, Vote};
 
 #[contract]
 pub struct DisputeResolutionContract;
@@ -132,6 +132,10 @@ impl DisputeResolutionContract {
 
     /// Resolve a dispute by evaluating votes and determining the outcome.
     ///
+    /// If an escrow is linked to the agreement, the outcome is executed on it
+    /// through the escrow's `execute_outcome` callback. A failed callback does
+    /// not block resolution; it can be retried with `execute_linked_outcome`.
+    ///
     /// # Arguments
     /// * `agreement_id` - The ID of the agreement in dispute
     ///
@@ -147,6 +151,82 @@ impl DisputeResolutionContract {
         dispute::resolve_dispute(&env, agreement_id)
     }
 
+    /// Retry delivering a resolved outcome to the linked escrow.
+    ///
+    /// Used when the callback failed during `resolve_dispute`, for example
+    /// because the escrow had not been put into dispute yet.
+    ///
+    /// # Arguments
+    /// * `agreement_id` - The ID of the resolved agreement dispute
+    ///
+    /// # Returns
+    /// * `DisputeOutcome` - The outcome delivered to the escrow
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `DisputeNotFound` - If the dispute doesn't exist
+    /// * `DisputeNotResolved` - If the dispute hasn't been resolved yet
+    /// * `EscrowNotLinked` - If no escrow is linked to the agreement
+    /// * `OutcomeAlreadyExecuted` - If the escrow already received the outcome
+    /// * `OutcomeExecutionFailed` - If the escrow rejected the outcome again
+    pub fn execute_linked_outcome(
+        env: Env,
+        agreement_id: String,
+    ) -> Result<DisputeOutcome, DisputeError> {
+        dispute::execute_linked_outcome(&env, agreement_id)
+    }
+
+    /// Register an escrow whose arbitration is delegated to this contract.
+    ///
+    /// Called by the escrow contract itself on behalf of an agreement party,
+    /// who must also authorize. The escrow's depositor and beneficiary must be
+    /// the agreement's tenant and landlord.
+    ///
+    /// # Arguments
+    /// * `escrow_contract` - The escrow contract holding the deposit
+    /// * `escrow_id` - The escrow's identifier within that contract
+    /// * `agreement_id` - The agreement whose dispute decides the escrow
+    /// * `caller` - The agreement party delegating arbitration
+    /// * `depositor` - The escrow depositor (must be the tenant)
+    /// * `beneficiary` - The escrow beneficiary (must be the landlord)
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `EscrowAlreadyLinked` - If an escrow is already linked to the agreement
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `Unauthorized` - If the escrow parties don't match the agreement or
+    ///   the caller is not the tenant or landlord
+    pub fn register_escrow(
+        env: Env,
+        escrow_contract: Address,
+        escrow_id: BytesN<32>,
+        agreement_id: String,
+        caller: Address,
+        depositor: Address,
+        beneficiary: Address,
+    ) -> Result<(), DisputeError> {
+        dispute::register_escrow(
+            &env,
+            escrow_contract,
+            escrow_id,
+            agreement_id,
+            caller,
+            depositor,
+            beneficiary,
+        )
+    }
+
+    /// Get the escrow linked to an agreement, if any.
+    ///
+    /// # Arguments
+    /// * `agreement_id` - The ID of the agreement
+    ///
+    /// # Returns
+    /// * `Option<EscrowLink>` - The linked escrow if one is registered
+    pub fn get_escrow_link(env: Env, agreement_id: String) -> Option<EscrowLink> {
+        dispute::get_escrow_link(&env, agreement_id)
+    }
+
     /// Get information about a specific dispute.
     ///
     /// # Arguments
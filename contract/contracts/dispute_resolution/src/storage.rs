@@ -9,4 +9,5 @@ pub enum DataKey {
     ArbiterCount,
     Dispute(String),
     Vote(String, Address),
+    EscrowLink(String),
 }
@@ -1,6 +1,11 @@
 use super::*;
-use crate::dispute::RentAgreement;
-use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+use crate::dispute::{AgreementStatus, RentAgreement};
+use soroban_sdk::{
+    contract, contracterror, contractimpl,
+    testutils::Address as _,
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, BytesN, Env, Map, String,
+};
 
 /// Mock chioma contract that returns a valid RentAgreement for testing.
 #[contract]
@@ -17,6 +22,73 @@ impl MockChiomaContract {
     }
 }
 
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum MockEscrowError {
+    InvalidState = 2,
+}
+
+/// Mock escrow contract that records the outcome delivered by the callback.
+#[contract]
+pub struct MockEscrowContract;
+
+#[contractimpl]
+impl MockEscrowContract {
+    pub fn set_failing(env: Env, failing: bool) {
+        env.storage().instance().set(&1u32, &failing);
+    }
+
+    pub fn execute_outcome(
+        env: Env,
+        escrow_id: BytesN<32>,
+        outcome: DisputeOutcome,
+    ) -> Result<(), MockEscrowError> {
+        if env.storage().instance().get(&1u32).unwrap_or(false) {
+            return Err(MockEscrowError::InvalidState);
+        }
+        env.storage().instance().set(&escrow_id, &outcome);
+        Ok(())
+    }
+
+    pub fn get_outcome(env: Env, escrow_id: BytesN<32>) -> Option<DisputeOutcome> {
+        env.storage().instance().get(&escrow_id)
+    }
+}
+
+/// Register a mock chioma contract holding one active agreement.
+/// Returns the contract address, tenant and landlord.
+fn setup_mock_chioma(env: &Env) -> (Address, Address, Address) {
+    let contract_id = env.register(MockChiomaContract, ());
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+
+    let agreement = RentAgreement {
+        agreement_id: String::from_str(env, "agreement_001"),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        monthly_rent: 1000,
+        security_deposit: 2000,
+        start_date: 0,
+        end_date: 1000,
+        agent_commission_rate: 0,
+        status: AgreementStatus::Active,
+        total_rent_paid: 0,
+        payment_count: 0,
+        signed_at: None,
+        payment_token: Address::generate(env),
+        next_payment_due: 0,
+        payment_history: Map::new(env),
+    };
+
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&0u32, &agreement);
+    });
+
+    (contract_id, tenant, landlord)
+}
+
 fn create_contract(env: &Env) -> DisputeResolutionContractClient<'_> {
     let contract_id = env.register(DisputeResolutionContract, ());
     DisputeResolutionContractClient::new(env, &contract_id)
@@ -549,3 +621,305 @@ fn test_get_arbiter_count() {
     client.add_arbiter(&admin, &arbiter3);
     assert_eq!(client.get_arbiter_count(), 3);
 }
+
+#[test]
+fn test_resolved_outcome_executed_on_linked_escrow() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(&env);
+    let escrow_contract = env.register(MockEscrowContract, ());
+    let escrow_client = MockEscrowContractClient::new(&env, &escrow_contract);
+    let escrow_id = BytesN::from_array(&env, &[7u8; 32]);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &1, &mock_chioma);
+    client.add_arbiter(&admin, &arbiter);
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    client.register_escrow(
+        &escrow_contract,
+        &escrow_id,
+        &agreement_id,
+        &tenant,
+        &tenant,
+        &landlord,
+    );
+
+    let link = client.get_escrow_link(&agreement_id).unwrap();
+    assert_eq!(link.escrow_contract, escrow_contract);
+    assert_eq!(link.escrow_id, escrow_id);
+
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    client.raise_dispute(&tenant, &agreement_id, &details_hash);
+    client.vote_on_dispute(&arbiter, &agreement_id, &false);
+
+    let outcome = client.resolve_dispute(&agreement_id);
+    assert_eq!(outcome, DisputeOutcome::FavorTenant);
+    assert_eq!(
+        escrow_client.get_outcome(&escrow_id),
+        Some(DisputeOutcome::FavorTenant)
+    );
+}
+
+#[test]
+fn test_resolution_not_blocked_by_failing_escrow_callback() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(&env);
+    let escrow_contract = env.register(MockEscrowContract, ());
+    let escrow_client = MockEscrowContractClient::new(&env, &escrow_contract);
+    let escrow_id = BytesN::from_array(&env, &[7u8; 32]);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &1, &mock_chioma);
+    client.add_arbiter(&admin, &arbiter);
+    escrow_client.set_failing(&true);
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    client.register_escrow(
+        &escrow_contract,
+        &escrow_id,
+        &agreement_id,
+        &tenant,
+        &tenant,
+        &landlord,
+    );
+
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    client.raise_dispute(&landlord, &agreement_id, &details_hash);
+    client.vote_on_dispute(&arbiter, &agreement_id, &true);
+
+    let outcome = client.resolve_dispute(&agreement_id);
+    assert_eq!(outcome, DisputeOutcome::FavorLandlord);
+    assert!(client.get_dispute(&agreement_id).unwrap().resolved);
+    assert_eq!(escrow_client.get_outcome(&escrow_id), None);
+    assert!(!client.get_escrow_link(&agreement_id).unwrap().executed);
+
+    // The outcome can be delivered once the escrow accepts it
+    let result = client.try_execute_linked_outcome(&agreement_id);
+    assert_eq!(result, Err(Ok(DisputeError::OutcomeExecutionFailed)));
+
+    escrow_client.set_failing(&false);
+    assert_eq!(
+        client.execute_linked_outcome(&agreement_id),
+        DisputeOutcome::FavorLandlord
+    );
+    assert_eq!(
+        escrow_client.get_outcome(&escrow_id),
+        Some(DisputeOutcome::FavorLandlord)
+    );
+    assert!(client.get_escrow_link(&agreement_id).unwrap().executed);
+
+    let result = client.try_execute_linked_outcome(&agreement_id);
+    assert_eq!(result, Err(Ok(DisputeError::OutcomeAlreadyExecuted)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_register_escrow_fails_with_mismatched_parties() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let (mock_chioma, tenant, _landlord) = setup_mock_chioma(&env);
+    let escrow_contract = env.register(MockEscrowContract, ());
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &1, &mock_chioma);
+    client.register_escrow(
+        &escrow_contract,
+        &BytesN::from_array(&env, &[7u8; 32]),
+        &String::from_str(&env, "agreement_001"),
+        &tenant,
+        &tenant,
+        &Address::generate(&env),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_register_escrow_fails_when_already_linked() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(&env);
+    let escrow_contract = env.register(MockEscrowContract, ());
+    let escrow_id = BytesN::from_array(&env, &[7u8; 32]);
+    let agreement_id = String::from_str(&env, "agreement_001");
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &1, &mock_chioma);
+    client.register_escrow(
+        &escrow_contract,
+        &escrow_id,
+        &agreement_id,
+        &tenant,
+        &tenant,
+        &landlord,
+    );
+    client.register_escrow(
+        &escrow_contract,
+        &escrow_id,
+        &agreement_id,
+        &tenant,
+        &tenant,
+        &landlord,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_register_escrow_fails_when_caller_not_party() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(&env);
+    let fake_escrow = env.register(MockEscrowContract, ());
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &1, &mock_chioma);
+    client.register_escrow(
+        &fake_escrow,
+        &BytesN::from_array(&env, &[7u8; 32]),
+        &String::from_str(&env, "agreement_001"),
+        &Address::generate(&env),
+        &tenant,
+        &landlord,
+    );
+}
+
+#[test]
+fn test_panel_outcome_settles_real_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+
+    client.initialize(&admin, &1, &mock_chioma);
+    client.add_arbiter(&admin, &arbiter);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&tenant, &2000);
+
+    let escrow_contract = env.register(escrow::EscrowContract, ());
+    let escrow_client = escrow::escrow_impl::EscrowContractClient::new(&env, &escrow_contract);
+    let escrow_id = escrow_client.create_and_fund(
+        &agreement_id,
+        &tenant,
+        &landlord,
+        &client.address,
+        &2000,
+        &token,
+    );
+
+    escrow_client.delegate_arbitration(&escrow_id, &tenant, &agreement_id);
+    let link = client.get_escrow_link(&agreement_id).unwrap();
+    assert_eq!(link.escrow_contract, escrow_contract);
+    assert_eq!(link.escrow_id, escrow_id);
+
+    escrow_client.initiate_dispute(
+        &escrow_id,
+        &tenant,
+        &String::from_str(&env, "Deposit withheld"),
+    );
+
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    client.raise_dispute(&tenant, &agreement_id, &details_hash);
+    client.vote_on_dispute(&arbiter, &agreement_id, &false);
+
+    assert_eq!(
+        client.resolve_dispute(&agreement_id),
+        DisputeOutcome::FavorTenant
+    );
+    assert!(client.get_escrow_link(&agreement_id).unwrap().executed);
+
+    let escrow = escrow_client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, escrow::EscrowStatus::Released);
+    assert_eq!(TokenClient::new(&env, &token).balance(&tenant), 2000);
+    assert_eq!(TokenClient::new(&env, &token).balance(&escrow_contract), 0);
+}
+
+#[test]
+fn test_outcome_retried_when_resolved_before_escrow_disputed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+
+    client.initialize(&admin, &1, &mock_chioma);
+    client.add_arbiter(&admin, &arbiter);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&tenant, &2000);
+
+    let escrow_contract = env.register(escrow::EscrowContract, ());
+    let escrow_client = escrow::escrow_impl::EscrowContractClient::new(&env, &escrow_contract);
+    let escrow_id = escrow_client.create_and_fund(
+        &agreement_id,
+        &tenant,
+        &landlord,
+        &client.address,
+        &2000,
+        &token,
+    );
+    escrow_client.delegate_arbitration(&escrow_id, &tenant, &agreement_id);
+
+    // The panel resolves while the escrow is still only funded
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    client.raise_dispute(&landlord, &agreement_id, &details_hash);
+    client.vote_on_dispute(&arbiter, &agreement_id, &true);
+    assert_eq!(
+        client.resolve_dispute(&agreement_id),
+        DisputeOutcome::FavorLandlord
+    );
+    assert!(!client.get_escrow_link(&agreement_id).unwrap().executed);
+    assert_eq!(
+        escrow_client.get_escrow(&escrow_id).status,
+        escrow::EscrowStatus::Funded
+    );
+
+    let result = client.try_resolve_dispute(&agreement_id);
+    assert_eq!(result, Err(Ok(DisputeError::DisputeAlreadyResolved)));
+
+    // Once the escrow is disputed the outcome can still be delivered
+    escrow_client.initiate_dispute(
+        &escrow_id,
+        &landlord,
+        &String::from_str(&env, "Damage to the property"),
+    );
+    assert_eq!(
+        client.execute_linked_outcome(&agreement_id),
+        DisputeOutcome::FavorLandlord
+    );
+    assert!(client.get_escrow_link(&agreement_id).unwrap().executed);
+
+    let escrow = escrow_client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, escrow::EscrowStatus::Released);
+    assert_eq!(TokenClient::new(&env, &token).balance(&landlord), 2000);
+    assert_eq!(TokenClient::new(&env, &token).balance(&escrow_contract), 0);
+}
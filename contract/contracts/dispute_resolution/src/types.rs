@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, BytesN, String};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -58,3 +58,13 @@ pub struct Vote {
     pub favor_landlord: bool,
     pub voted_at: u64,
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowLink {
+    pub escrow_contract: Address,
+    pub escrow_id: BytesN<32>,
+    pub linked_at: u64,
+    /// Whether the resolved outcome has been delivered to the escrow
+    pub executed: bool,
+}
@@ -1,9 +1,9 @@
 //! Access control and role-based authorization for the Escrow contract.
 //! Validates that callers have the proper role to perform actions.
-#[allow(unused_imports)]
 use soroban_sdk::{Address, Env};
 
 use crate::errors::EscrowError;
+use crate::storage::EscrowStorage;
 use crate::types::Escrow;
 
 /// Access control validation functions.
@@ -56,4 +56,12 @@ impl AccessControl {
             Err(EscrowError::NotAuthorized)
         }
     }
+
+    /// Verify the contract admin has authorized the call.
+    /// Returns the admin address.
+    pub fn require_admin(env: &Env) -> Result<Address, EscrowError> {
+        let admin = EscrowStorage::get_admin(env).ok_or(EscrowError::NotInitialized)?;
+        admin.require_auth();
+        Ok(admin)
+    }
 }
@@ -0,0 +1,181 @@
+//! Deposit amount changes for running escrows.
+//! Supports depositor top-ups and mutually approved amount adjustments,
+//! recording every change in the escrow's amount history.
+use soroban_sdk::{token, Address, BytesN, Env};
+
+use crate::access::AccessControl;
+use crate::errors::EscrowError;
+use crate::events;
+use crate::storage::EscrowStorage;
+use crate::types::{AmountChange, AmountChangeKind, Escrow, EscrowStatus};
+use crate::vault::VaultHandler;
+
+/// Escrow amount adjustment handling.
+pub struct AdjustmentHandler;
+
+impl AdjustmentHandler {
+    /// Add funds to a funded escrow.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Funded
+    /// - Caller must be depositor
+    /// - Amount must be positive
+    ///
+    /// EFFECTS:
+    /// - Increase escrow amount and record the change
+    /// - Clear pending approvals, which were given for the old amount
+    ///
+    /// INTERACTIONS:
+    /// - Token transfer from depositor to escrow contract
+    pub fn top_up(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        amount: i128,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        // Verify caller is depositor
+        AccessControl::is_depositor(&escrow, &caller)?;
+
+        // Verify escrow is in Funded state
+        if escrow.status != EscrowStatus::Funded {
+            return Err(EscrowError::InvalidState);
+        }
+
+        if amount <= 0 {
+            return Err(EscrowError::InsufficientFunds);
+        }
+
+        // Authorize the top-up
+        caller.require_auth();
+
+        // EFFECTS: Update amount and record history
+        let previous_amount = escrow.amount;
+        escrow.amount += amount;
+        Self::record(
+            &env,
+            &escrow,
+            previous_amount,
+            caller.clone(),
+            AmountChangeKind::TopUp,
+        );
+
+        // INTERACTIONS: Token transfer from depositor to escrow contract
+        let token_client = token::Client::new(&env, &escrow.token);
+        token_client.transfer(&caller, env.current_contract_address(), &amount);
+
+        Ok(())
+    }
+
+    /// Change the escrow amount with approval from both primary parties.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Pending or Funded
+    /// - Caller must be depositor or beneficiary
+    /// - New amount must be positive and differ from the current amount
+    /// - Depositor and beneficiary must both authorize
+    /// - A decrease must not exceed the funds held outside a vault
+    ///
+    /// EFFECTS:
+    /// - Update escrow amount and record the change against the caller
+    /// - Clear pending approvals, which were given for the old amount
+    ///
+    /// INTERACTIONS (Funded only):
+    /// - An increase is pulled from the depositor
+    /// - Any excess is returned to the depositor
+    pub fn adjust_amount(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        new_amount: i128,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        // Verify caller is a primary party (depositor or beneficiary)
+        AccessControl::is_primary_party(&escrow, &caller)?;
+
+        if escrow.status != EscrowStatus::Pending && escrow.status != EscrowStatus::Funded {
+            return Err(EscrowError::InvalidState);
+        }
+
+        if new_amount <= 0 || new_amount == escrow.amount {
+            return Err(EscrowError::InvalidAmount);
+        }
+
+        // Both primary parties must agree on the new amount
+        escrow.depositor.require_auth();
+        escrow.beneficiary.require_auth();
+
+        let previous_amount = escrow.amount;
+        let funded = escrow.status == EscrowStatus::Funded;
+
+        // Excess can only be returned from funds not locked in a vault
+        if funded
+            && new_amount < previous_amount
+            && previous_amount - new_amount > VaultHandler::held_amount(&env, &escrow)
+        {
+            return Err(EscrowError::InsufficientFunds);
+        }
+
+        // EFFECTS: Update amount and record history
+        escrow.amount = new_amount;
+        Self::record(
+            &env,
+            &escrow,
+            previous_amount,
+            caller,
+            AmountChangeKind::Adjustment,
+        );
+
+        // INTERACTIONS: Settle the difference with the depositor
+        if funded {
+            let depositor = &escrow.depositor;
+            let token_client = token::Client::new(&env, &escrow.token);
+            let contract = env.current_contract_address();
+            if new_amount > previous_amount {
+                token_client.transfer(depositor, &contract, &(new_amount - previous_amount));
+            } else {
+                token_client.transfer(&contract, depositor, &(previous_amount - new_amount));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Persist an amount change, reset approvals and emit the change event.
+    fn record(
+        env: &Env,
+        escrow: &Escrow,
+        previous_amount: i128,
+        changed_by: Address,
+        kind: AmountChangeKind,
+    ) {
+        EscrowStorage::save(env, escrow);
+        EscrowStorage::add_amount_change(
+            env,
+            &escrow.id,
+            AmountChange {
+                previous_amount,
+                new_amount: escrow.amount,
+                changed_by,
+                kind,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // Approvals were given for the old amount
+        EscrowStorage::clear_approvals(env, &escrow.id);
+        let targets = [escrow.beneficiary.clone(), escrow.depositor.clone()];
+        let signers = [
+            escrow.depositor.clone(),
+            escrow.beneficiary.clone(),
+            escrow.arbiter.clone(),
+        ];
+        EscrowStorage::clear_approval_counts(env, &escrow.id, &targets, &signers);
+
+        events::escrow_amount_changed(env, escrow, previous_amount);
+    }
+}
@@ -1,16 +1,130 @@
 //! Dispute resolution and admin override for the Escrow contract.
 //! Allows either party to freeze funds and requires admin to resolve.
-use soroban_sdk::{token, Address, BytesN, Env, String};
+//! An optional dispute fee is posted by the initiator and must be matched by
+//! the respondent before the dispute can be resolved; on resolution the
+//! losing side's fee pays the arbiter its share and the rest goes to the
+//! winner, while the winner's fee is returned. The share of a delegated
+//! dispute panel is paid to the contract admin.
+//! While a dispute is open both parties can append to its evidence log.
+//! Arbitration can be delegated to a dispute_resolution contract, whose
+//! panel outcome is executed through the `execute_outcome` callback.
+use soroban_sdk::{token, vec, Address, BytesN, Env, IntoVal, String, Symbol, Vec};
 
 use crate::access::AccessControl;
 use crate::errors::EscrowError;
+use crate::events;
 use crate::storage::EscrowStorage;
-use crate::types::EscrowStatus;
+use crate::types::{
+    DisputeFeeConfig, DisputeOutcome, Escrow, EscrowStatus, EvidenceEntry, PostedDisputeFee,
+};
+use crate::vault::VaultHandler;
+
+/// Maximum number of evidence entries returned per page.
+pub const MAX_EVIDENCE_PAGE: u32 = 50;
+
+/// Maximum number of evidence entries each party can submit per escrow.
+pub const MAX_EVIDENCE_PER_PARTY: u32 = 20;
 
 /// Dispute handling and resolution.
 pub struct DisputeHandler;
 
 impl DisputeHandler {
+    /// Set the fee each side of a dispute must post, with approval from both
+    /// primary parties.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Pending or Funded
+    /// - Fee must not be negative
+    /// - Depositor and beneficiary must both authorize
+    ///
+    /// EFFECTS:
+    /// - Store the fee, applied to disputes initiated afterwards
+    pub fn set_dispute_fee(env: Env, escrow_id: BytesN<32>, fee: i128) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        if escrow.status != EscrowStatus::Pending && escrow.status != EscrowStatus::Funded {
+            return Err(EscrowError::InvalidState);
+        }
+
+        if fee < 0 {
+            return Err(EscrowError::InvalidDisputeFee);
+        }
+
+        // Both primary parties must agree on the fee
+        escrow.depositor.require_auth();
+        escrow.beneficiary.require_auth();
+
+        // EFFECTS: Store the fee, keeping the arbiter's share
+        let mut config = Self::fee_config(&env, &escrow_id);
+        config.fee = fee;
+        EscrowStorage::set_dispute_fee(&env, &escrow_id, &config);
+
+        events::dispute_fee_set(&env, &escrow, &config);
+
+        Ok(())
+    }
+
+    /// Set the arbiter's share of a forfeited dispute fee.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Pending or Funded
+    /// - Caller must be the escrow arbiter or the contract admin
+    /// - Share must not exceed 10000 bps
+    ///
+    /// EFFECTS:
+    /// - Store the share, applied to disputes initiated afterwards
+    pub fn set_arbiter_fee(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        arbiter_share_bps: u32,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        if caller != escrow.arbiter && Some(caller.clone()) != EscrowStorage::get_admin(&env) {
+            return Err(EscrowError::NotAuthorized);
+        }
+
+        if escrow.status != EscrowStatus::Pending && escrow.status != EscrowStatus::Funded {
+            return Err(EscrowError::InvalidState);
+        }
+
+        if arbiter_share_bps > 10_000 {
+            return Err(EscrowError::InvalidDisputeFee);
+        }
+
+        // Authorize the arbiter fee
+        caller.require_auth();
+
+        // EFFECTS: Store the share, keeping the parties' fee
+        let mut config = Self::fee_config(&env, &escrow_id);
+        config.arbiter_share_bps = arbiter_share_bps;
+        EscrowStorage::set_dispute_fee(&env, &escrow_id, &config);
+
+        events::dispute_fee_set(&env, &escrow, &config);
+
+        Ok(())
+    }
+
+    /// Current dispute fee terms, or zero terms if none were set.
+    fn fee_config(env: &Env, escrow_id: &BytesN<32>) -> DisputeFeeConfig {
+        EscrowStorage::get_dispute_fee(env, escrow_id).unwrap_or(DisputeFeeConfig {
+            fee: 0,
+            arbiter_share_bps: 0,
+        })
+    }
+
+    /// Get the dispute fee terms of an escrow, if any were set.
+    pub fn get_dispute_fee(
+        env: Env,
+        escrow_id: BytesN<32>,
+    ) -> Result<Option<DisputeFeeConfig>, EscrowError> {
+        EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        Ok(EscrowStorage::get_dispute_fee(&env, &escrow_id))
+    }
+
     /// Initiate a dispute on an escrow.
     /// Either depositor or beneficiary can call this.
     ///
@@ -24,6 +138,10 @@ impl DisputeHandler {
     /// - Update escrow status to Disputed
     /// - Store dispute reason
     /// - Clear existing approvals (freeze funds)
+    /// - Record the posted dispute fee, if the escrow has one
+    ///
+    /// INTERACTIONS:
+    /// - Dispute fee transfer from caller to escrow contract
     pub fn initiate_dispute(
         env: Env,
         escrow_id: BytesN<32>,
@@ -51,31 +169,110 @@ impl DisputeHandler {
 
         // EFFECTS: Update status and store reason
         escrow.status = EscrowStatus::Disputed;
-        escrow.dispute_reason = Some(reason);
+        escrow.dispute_reason = Some(reason.clone());
         EscrowStorage::save(&env, &escrow);
 
         // Freeze funds by clearing all approvals
         EscrowStorage::clear_approvals(&env, &escrow_id);
 
+        let posted = EscrowStorage::get_dispute_fee(&env, &escrow_id)
+            .filter(|config| config.fee > 0)
+            .map(|config| PostedDisputeFee {
+                payer: caller.clone(),
+                amount: config.fee,
+                respondent_amount: 0,
+                arbiter_share_bps: config.arbiter_share_bps,
+            });
+        if let Some(posted) = &posted {
+            EscrowStorage::set_posted_dispute_fee(&env, &escrow_id, posted);
+        }
+
+        events::dispute_initiated(&env, &escrow, caller.clone(), reason);
+
+        // INTERACTIONS: Post the dispute fee
+        if let Some(posted) = posted {
+            let token_client = token::Client::new(&env, &escrow.token);
+            token_client.transfer(&caller, env.current_contract_address(), &posted.amount);
+            events::dispute_fee_posted(&env, &escrow, caller, posted.amount);
+        }
+
+        Ok(())
+    }
+
+    /// Post the respondent's matching dispute fee.
+    /// The other primary party answers the initiator's fee so that whichever
+    /// side loses bears the arbiter's share. Required before resolution.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Disputed with a posted fee
+    /// - Caller must be the primary party that did not initiate
+    /// - Respondent fee must not already be posted
+    ///
+    /// EFFECTS:
+    /// - Record the respondent's fee
+    ///
+    /// INTERACTIONS:
+    /// - Dispute fee transfer from caller to escrow contract
+    pub fn post_dispute_fee(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        // Verify caller is a primary party (depositor or beneficiary)
+        AccessControl::is_primary_party(&escrow, &caller)?;
+
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(EscrowError::InvalidState);
+        }
+
+        let mut posted = EscrowStorage::get_posted_dispute_fee(&env, &escrow_id)
+            .ok_or(EscrowError::InvalidDisputeFee)?;
+
+        if caller == posted.payer {
+            return Err(EscrowError::NotAuthorized);
+        }
+
+        if posted.respondent_amount > 0 {
+            return Err(EscrowError::InvalidState);
+        }
+
+        // Authorize the fee payment
+        caller.require_auth();
+
+        // EFFECTS: Record the respondent's fee
+        posted.respondent_amount = posted.amount;
+        EscrowStorage::set_posted_dispute_fee(&env, &escrow_id, &posted);
+
+        // INTERACTIONS: Post the matching fee
+        let token_client = token::Client::new(&env, &escrow.token);
+        token_client.transfer(&caller, env.current_contract_address(), &posted.amount);
+
+        events::dispute_fee_posted(&env, &escrow, caller, posted.amount);
+
         Ok(())
     }
 
-    /// Resolve a dispute (admin only).
-    /// Admin can release funds to either party or refund to depositor.
+    /// Resolve a dispute (arbiter only).
+    /// Arbiter releases the funds to either the beneficiary or the depositor.
     ///
     /// CHECKS:
     /// - Escrow must exist
     /// - Escrow must be in Disputed state
     /// - Caller must be arbiter
+    /// - Both sides' dispute fees must be posted
     /// - Release target must be beneficiary or depositor
     ///
     /// EFFECTS:
-    /// - Update escrow status to Released or Refunded
+    /// - Update escrow status to Released
     /// - Clear dispute reason
     /// - Clear approvals
     ///
     /// INTERACTIONS:
-    /// - Token transfer would happen after state update
+    /// - Escrowed funds to `release_to`
+    /// - Posted dispute fee allocated against the losing side
     pub fn resolve_dispute(
         env: Env,
         escrow_id: BytesN<32>,
@@ -93,6 +290,8 @@ impl DisputeHandler {
             return Err(EscrowError::InvalidState);
         }
 
+        Self::require_fees_posted(&env, &escrow_id)?;
+
         // Authorize the dispute resolution
         caller.require_auth();
 
@@ -101,19 +300,372 @@ impl DisputeHandler {
             return Err(EscrowError::InvalidApprovalTarget);
         }
 
+        let beneficiary_amount = Self::beneficiary_amount(&escrow, &release_to);
+        Self::settle(&env, &mut escrow, beneficiary_amount);
+
+        events::dispute_resolved(&env, &escrow, release_to);
+
+        Ok(())
+    }
+
+    /// Resolve a dispute by splitting the funds between both parties (arbiter only).
+    ///
+    /// CHECKS:
+    /// - Escrow must exist
+    /// - Escrow must be in Disputed state
+    /// - Caller must be arbiter
+    /// - Both sides' dispute fees must be posted
+    /// - Beneficiary amount must be between zero and the escrow amount
+    ///
+    /// EFFECTS:
+    /// - Update escrow status to Released
+    /// - Clear dispute reason and approvals
+    ///
+    /// INTERACTIONS:
+    /// - `beneficiary_amount` to the beneficiary, the rest to the depositor
+    /// - Posted dispute fee allocated in the same proportion
+    pub fn resolve_dispute_split(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        beneficiary_amount: i128,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        // Verify caller is arbiter
+        AccessControl::is_arbiter(&escrow, &caller)?;
+
+        // Verify escrow is in Disputed state
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(EscrowError::InvalidState);
+        }
+
+        Self::require_fees_posted(&env, &escrow_id)?;
+
+        // Authorize the dispute resolution
+        caller.require_auth();
+
+        if beneficiary_amount < 0 || beneficiary_amount > escrow.amount {
+            return Err(EscrowError::InvalidRelease);
+        }
+
+        Self::settle(&env, &mut escrow, beneficiary_amount);
+
+        events::dispute_split(&env, &escrow, beneficiary_amount);
+
+        Ok(())
+    }
+
+    /// Append an entry to the evidence log of a disputed escrow.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Disputed
+    /// - Caller must be depositor or beneficiary
+    /// - Caller must not exceed `MAX_EVIDENCE_PER_PARTY` entries
+    ///
+    /// EFFECTS:
+    /// - Append the entry to the evidence log
+    pub fn submit_evidence(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        content_hash: BytesN<32>,
+        label: Option<String>,
+    ) -> Result<u32, EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        // Verify caller is a primary party (depositor or beneficiary)
+        AccessControl::is_primary_party(&escrow, &caller)?;
+
+        // Evidence is accepted until the dispute is resolved
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(EscrowError::InvalidState);
+        }
+
+        if EscrowStorage::get_party_evidence_count(&env, &escrow_id, &caller)
+            >= MAX_EVIDENCE_PER_PARTY
+        {
+            return Err(EscrowError::EvidenceLimitReached);
+        }
+
+        // Authorize the submission
+        caller.require_auth();
+
+        // EFFECTS: Append to the evidence log
+        let index = EscrowStorage::add_evidence(
+            &env,
+            &escrow_id,
+            EvidenceEntry {
+                submitter: caller.clone(),
+                content_hash: content_hash.clone(),
+                label,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        events::evidence_submitted(&env, &escrow, caller, index, content_hash);
+
+        Ok(index)
+    }
+
+    /// Get a page of an escrow's evidence log, oldest first.
+    /// Returns at most `MAX_EVIDENCE_PAGE` entries starting at `start`.
+    pub fn get_evidence(
+        env: Env,
+        escrow_id: BytesN<32>,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<EvidenceEntry>, EscrowError> {
+        EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        let end = start
+            .saturating_add(limit.min(MAX_EVIDENCE_PAGE))
+            .min(EscrowStorage::get_evidence_count(&env, &escrow_id));
+
+        let mut page = Vec::new(&env);
+        for index in start..end {
+            if let Some(entry) = EscrowStorage::get_evidence(&env, &escrow_id, index) {
+                page.push_back(entry);
+            }
+        }
+        Ok(page)
+    }
+
+    /// Get the number of entries in an escrow's evidence log.
+    pub fn get_evidence_count(env: Env, escrow_id: BytesN<32>) -> Result<u32, EscrowError> {
+        EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        Ok(EscrowStorage::get_evidence_count(&env, &escrow_id))
+    }
+
+    /// Delegate arbitration of an escrow to its dispute_resolution arbiter.
+    /// The escrow's `arbiter` must be the dispute_resolution contract; this
+    /// registers the escrow there against the agreement under dispute.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and not be settled or cancelled
+    /// - Caller must be depositor or beneficiary
+    /// - Agreement must be the one the escrow was created for
+    /// - Arbitration must not already be delegated
+    ///
+    /// EFFECTS:
+    /// - Store the agreement link
+    ///
+    /// INTERACTIONS:
+    /// - Register the escrow with the dispute_resolution contract, which
+    ///   verifies the escrow parties against the agreement
+    pub fn delegate_arbitration(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        agreement_id: String,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        // Verify caller is a primary party (depositor or beneficiary)
+        AccessControl::is_primary_party(&escrow, &caller)?;
+
+        if escrow.status != EscrowStatus::Pending
+            && escrow.status != EscrowStatus::Funded
+            && escrow.status != EscrowStatus::Disputed
+        {
+            return Err(EscrowError::InvalidState);
+        }
+
+        if agreement_id != escrow.agreement_id {
+            return Err(EscrowError::AgreementMismatch);
+        }
+
+        if EscrowStorage::get_arbitration(&env, &escrow_id).is_some() {
+            return Err(EscrowError::ArbitrationAlreadyDelegated);
+        }
+
+        // Authorize the delegation
+        caller.require_auth();
+
+        // EFFECTS: Store the agreement link
+        EscrowStorage::set_arbitration(&env, &escrow_id, &agreement_id);
+
+        // INTERACTIONS: Register with the dispute_resolution contract
+        env.invoke_contract::<()>(
+            &escrow.arbiter,
+            &Symbol::new(&env, "register_escrow"),
+            vec![
+                &env,
+                env.current_contract_address().into_val(&env),
+                escrow_id.clone().into_val(&env),
+                agreement_id.clone().into_val(&env),
+                caller.clone().into_val(&env),
+                escrow.depositor.clone().into_val(&env),
+                escrow.beneficiary.clone().into_val(&env),
+            ],
+        );
+
+        events::arbitration_delegated(&env, &escrow, caller, agreement_id);
+
+        Ok(())
+    }
+
+    /// Execute a dispute_resolution panel outcome on a delegated escrow.
+    /// Called back by the dispute_resolution contract acting as arbiter.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist with delegated arbitration
+    /// - Escrow must be Disputed
+    /// - Both sides' dispute fees must be posted
+    /// - Invoker must be the arbiter contract
+    ///
+    /// EFFECTS:
+    /// - Update escrow status to Released
+    /// - Clear dispute reason and approvals
+    ///
+    /// INTERACTIONS:
+    /// - FavorLandlord pays the beneficiary, FavorTenant refunds the depositor
+    pub fn execute_outcome(
+        env: Env,
+        escrow_id: BytesN<32>,
+        outcome: DisputeOutcome,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        if EscrowStorage::get_arbitration(&env, &escrow_id).is_none() {
+            return Err(EscrowError::ArbitrationNotDelegated);
+        }
+
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(EscrowError::InvalidState);
+        }
+
+        Self::require_fees_posted(&env, &escrow_id)?;
+
+        // Only the delegated arbiter contract can deliver an outcome
+        escrow.arbiter.require_auth();
+
+        let release_to = match outcome {
+            DisputeOutcome::FavorLandlord => escrow.beneficiary.clone(),
+            DisputeOutcome::FavorTenant => escrow.depositor.clone(),
+        };
+
+        let beneficiary_amount = Self::beneficiary_amount(&escrow, &release_to);
+        Self::settle(&env, &mut escrow, beneficiary_amount);
+
+        events::dispute_resolved(&env, &escrow, release_to);
+
+        Ok(())
+    }
+
+    /// Get the agreement whose dispute panel arbitrates an escrow, if delegated.
+    pub fn get_arbitration(env: Env, escrow_id: BytesN<32>) -> Result<Option<String>, EscrowError> {
+        EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        Ok(EscrowStorage::get_arbitration(&env, &escrow_id))
+    }
+
+    /// Fail if a dispute fee was posted but the respondent has not matched it,
+    /// so that whichever side loses has a fee to forfeit.
+    fn require_fees_posted(env: &Env, escrow_id: &BytesN<32>) -> Result<(), EscrowError> {
+        match EscrowStorage::get_posted_dispute_fee(env, escrow_id) {
+            Some(posted) if posted.respondent_amount < posted.amount => {
+                Err(EscrowError::DisputeFeeNotPosted)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Amount the beneficiary receives when everything goes to `release_to`.
+    fn beneficiary_amount(escrow: &Escrow, release_to: &Address) -> i128 {
+        if *release_to == escrow.beneficiary {
+            escrow.amount
+        } else {
+            0
+        }
+    }
+
+    /// Settle a disputed escrow, paying `beneficiary_amount` to the beneficiary
+    /// and the rest to the depositor, and close the dispute.
+    fn settle(env: &Env, escrow: &mut Escrow, beneficiary_amount: i128) {
         // EFFECTS: Update status and clear dispute
         escrow.status = EscrowStatus::Released;
         escrow.dispute_reason = None;
-        EscrowStorage::save(&env, &escrow);
+        EscrowStorage::save(env, escrow);
 
         // Clear approvals
-        EscrowStorage::clear_approvals(&env, &escrow_id);
+        EscrowStorage::clear_approvals(env, &escrow.id);
 
-        // INTERACTIONS: Token transfer from escrow contract to release target
-        let token_client = token::Client::new(&env, &escrow.token);
-        token_client.transfer(&env.current_contract_address(), &release_to, &escrow.amount);
+        // INTERACTIONS: Token transfers from escrow contract to the parties
+        VaultHandler::payout_split(env, escrow, beneficiary_amount);
+        Self::allocate_fee(env, escrow, beneficiary_amount);
+    }
 
-        Ok(())
+    /// Allocate the fees posted for a resolved dispute.
+    ///
+    /// Each side forfeits its fee in the proportion of the escrowed funds it
+    /// lost, so a full loss forfeits it all and a split outcome splits it.
+    /// The arbiter is paid its share of each forfeited amount, the rest goes
+    /// to the other side, and the unforfeited part is returned.
+    fn allocate_fee(env: &Env, escrow: &Escrow, beneficiary_amount: i128) {
+        let Some(posted) = EscrowStorage::get_posted_dispute_fee(env, &escrow.id) else {
+            return;
+        };
+        EscrowStorage::remove_posted_dispute_fee(env, &escrow.id);
+
+        let (depositor_fee, beneficiary_fee) = if posted.payer == escrow.depositor {
+            (posted.amount, posted.respondent_amount)
+        } else {
+            (posted.respondent_amount, posted.amount)
+        };
+
+        // The depositor loses what goes to the beneficiary, and vice versa
+        let depositor_forfeit = depositor_fee * beneficiary_amount / escrow.amount;
+        let beneficiary_forfeit =
+            beneficiary_fee - beneficiary_fee * beneficiary_amount / escrow.amount;
+        let (arbiter_payee, arbiter_share_bps) = match Self::arbiter_fee_payee(env, escrow) {
+            Some(payee) => (payee, posted.arbiter_share_bps as i128),
+            None => (escrow.arbiter.clone(), 0),
+        };
+        let depositor_arbiter = depositor_forfeit * arbiter_share_bps / 10_000;
+        let beneficiary_arbiter = beneficiary_forfeit * arbiter_share_bps / 10_000;
+
+        let arbiter_amount = depositor_arbiter + beneficiary_arbiter;
+        let to_depositor =
+            depositor_fee - depositor_forfeit + beneficiary_forfeit - beneficiary_arbiter;
+        let to_beneficiary =
+            beneficiary_fee - beneficiary_forfeit + depositor_forfeit - depositor_arbiter;
+
+        let contract = env.current_contract_address();
+        let token_client = token::Client::new(env, &escrow.token);
+        if arbiter_amount > 0 {
+            token_client.transfer(&contract, &arbiter_payee, &arbiter_amount);
+        }
+        if to_beneficiary > 0 {
+            token_client.transfer(&contract, &escrow.beneficiary, &to_beneficiary);
+        }
+        if to_depositor > 0 {
+            token_client.transfer(&contract, &escrow.depositor, &to_depositor);
+        }
+
+        events::dispute_fee_allocated(
+            env,
+            escrow,
+            posted.payer,
+            arbiter_payee,
+            arbiter_amount,
+            to_depositor,
+            to_beneficiary,
+        );
+    }
+
+    /// Address paid the arbiter's share of forfeited dispute fees.
+    /// A delegated dispute_resolution contract cannot spend tokens, so its
+    /// share goes to the contract admin; without an admin no share is taken.
+    fn arbiter_fee_payee(env: &Env, escrow: &Escrow) -> Option<Address> {
+        if EscrowStorage::get_arbitration(env, &escrow.id).is_some() {
+            EscrowStorage::get_admin(env)
+        } else {
+            Some(escrow.arbiter.clone())
+        }
     }
 
     /// Get dispute information for an escrow.
@@ -28,4 +28,28 @@ pub enum EscrowError {
     EmptyDisputeReason = 10,
     /// Invalid approval target (neither beneficiary nor depositor)
     InvalidApprovalTarget = 11,
+    /// Contract has already been initialized
+    AlreadyInitialized = 12,
+    /// Contract has not been initialized
+    NotInitialized = 13,
+    /// Vault is not on the admin allowlist
+    VaultNotAllowed = 14,
+    /// Escrow funds are already deposited in a vault
+    VaultAlreadyActive = 15,
+    /// Arbitration has already been delegated for this escrow
+    ArbitrationAlreadyDelegated = 16,
+    /// Arbitration has not been delegated for this escrow
+    ArbitrationNotDelegated = 17,
+    /// Dispute fee or arbiter share is out of range
+    InvalidDisputeFee = 18,
+    /// An escrow with the same ID already exists
+    AlreadyExists = 19,
+    /// Agreement does not match the one the escrow was created for
+    AgreementMismatch = 20,
+    /// New amount is not positive or does not change the current amount
+    InvalidAmount = 21,
+    /// Party has reached the evidence entry limit for this escrow
+    EvidenceLimitReached = 22,
+    /// Respondent has not posted its dispute fee
+    DisputeFeeNotPosted = 23,
 }
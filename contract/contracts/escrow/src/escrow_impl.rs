@@ -1,13 +1,19 @@
 //! Core escrow lifecycle logic: creation, funding, approvals, and release.
 //! Implements checks-effects-interactions pattern for reentrancy safety.
-use soroban_sdk::{contract, contractimpl, token, xdr::ToXdr, Address, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, token, xdr::ToXdr, Address, BytesN, Env, String, Vec};
 
+use crate::adjustment::AdjustmentHandler;
 use crate::dispute::DisputeHandler;
 
 use crate::access::AccessControl;
 use crate::errors::EscrowError;
+use crate::events;
 use crate::storage::EscrowStorage;
-use crate::types::{Escrow, EscrowStatus, ReleaseApproval};
+use crate::types::{
+    AmountChange, DisputeFeeConfig, DisputeOutcome, Escrow, EscrowStatus, EvidenceEntry,
+    ReleaseApproval, VaultPosition,
+};
+use crate::vault::VaultHandler;
 
 /// Core escrow contract implementation.
 #[contract]
@@ -15,9 +21,50 @@ pub struct EscrowContract;
 
 #[contractimpl]
 impl EscrowContract {
+    /// Initialize the contract with an admin who manages the vault allowlist.
+    ///
+    /// # Errors
+    /// * `AlreadyInitialized` - If the contract has already been initialized
+    pub fn initialize(env: Env, admin: Address) -> Result<(), EscrowError> {
+        if EscrowStorage::get_admin(&env).is_some() {
+            return Err(EscrowError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
+        EscrowStorage::set_admin(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the contract admin, if initialized.
+    pub fn get_admin(env: Env) -> Option<Address> {
+        EscrowStorage::get_admin(&env)
+    }
+
+    /// Add or remove a vault from the allowlist (admin only).
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract has not been initialized
+    pub fn set_vault_allowed(env: Env, vault: Address, allowed: bool) -> Result<(), EscrowError> {
+        let admin = AccessControl::require_admin(&env)?;
+
+        EscrowStorage::set_vault_allowed(&env, &vault, allowed);
+
+        events::vault_allowlist_updated(&env, admin, vault, allowed);
+
+        Ok(())
+    }
+
+    /// Check whether a vault is on the allowlist.
+    pub fn is_vault_allowed(env: Env, vault: Address) -> bool {
+        EscrowStorage::is_vault_allowed(&env, &vault)
+    }
+
     /// Create a new escrow.
     ///
     /// CHECKS:
+    /// - Depositor must authorize
     /// - Amount must be positive
     /// - All addresses must be distinct
     ///
@@ -27,54 +74,121 @@ impl EscrowContract {
     /// - Increments escrow counter
     ///
     /// INTERACTIONS:
-    /// - Token transfer from depositor (not yet implemented in this version)
-    ///   would happen after state update
+    /// - None; funds arrive via `fund_escrow`, or use `create_and_fund`
     pub fn create(
         env: Env,
+        agreement_id: String,
         depositor: Address,
         beneficiary: Address,
         arbiter: Address,
         amount: i128,
         token: Address,
     ) -> Result<BytesN<32>, EscrowError> {
-        // CHECKS: Validate inputs
-        if amount <= 0 {
-            return Err(EscrowError::InsufficientFunds);
-        }
+        // CHECKS: Only the depositor may open an escrow in their name
+        depositor.require_auth();
 
-        // Ensure all parties are distinct
-        if depositor == beneficiary || depositor == arbiter || beneficiary == arbiter {
-            return Err(EscrowError::InvalidSigner);
-        }
+        // CHECKS + EFFECTS: Validate inputs, create and save escrow
+        let escrow = Self::new_escrow(
+            &env,
+            agreement_id,
+            depositor,
+            beneficiary,
+            arbiter,
+            amount,
+            token,
+            EscrowStatus::Pending,
+        )?;
 
-        // Generate unique escrow ID from hash of parameters
-        let mut data = soroban_sdk::Bytes::new(&env);
-        data.append(&depositor.clone().to_xdr(&env));
-        data.append(&beneficiary.clone().to_xdr(&env));
-        data.append(&arbiter.clone().to_xdr(&env));
-        data.append(&amount.to_xdr(&env));
-        data.append(&token.clone().to_xdr(&env));
-        data.append(&env.ledger().timestamp().to_xdr(&env));
+        events::escrow_created(&env, &escrow);
 
-        let escrow_id: BytesN<32> = env.crypto().sha256(&data).into();
+        Ok(escrow.id)
+    }
 
-        // EFFECTS: Create and save escrow
-        let escrow = Escrow {
-            id: escrow_id.clone(),
-            depositor: depositor.clone(),
-            beneficiary: beneficiary.clone(),
-            arbiter: arbiter.clone(),
+    /// Create a new escrow and fund it in the same invocation.
+    /// Avoids leaving unfunded Pending escrows behind.
+    ///
+    /// CHECKS:
+    /// - Depositor must authorize
+    /// - Amount must be positive
+    /// - All addresses must be distinct
+    ///
+    /// EFFECTS:
+    /// - Creates new Escrow with Funded status
+    /// - Stores escrow in persistent storage
+    /// - Increments escrow counter
+    ///
+    /// INTERACTIONS:
+    /// - Token transfer from depositor to escrow contract after state update
+    pub fn create_and_fund(
+        env: Env,
+        agreement_id: String,
+        depositor: Address,
+        beneficiary: Address,
+        arbiter: Address,
+        amount: i128,
+        token: Address,
+    ) -> Result<BytesN<32>, EscrowError> {
+        // CHECKS: Authorize the deposit
+        depositor.require_auth();
+
+        // EFFECTS: Create and save escrow as already funded
+        let escrow = Self::new_escrow(
+            &env,
+            agreement_id,
+            depositor,
+            beneficiary,
+            arbiter,
             amount,
             token,
-            status: EscrowStatus::Pending,
-            created_at: env.ledger().timestamp(),
-            dispute_reason: None,
-        };
+            EscrowStatus::Funded,
+        )?;
+
+        // INTERACTIONS: Token transfer from depositor to escrow contract
+        let token_client = token::Client::new(&env, &escrow.token);
+        token_client.transfer(
+            &escrow.depositor,
+            env.current_contract_address(),
+            &escrow.amount,
+        );
+
+        events::escrow_created(&env, &escrow);
+        events::escrow_funded(&env, &escrow);
+
+        Ok(escrow.id)
+    }
+
+    /// Cancel an unfunded escrow.
+    /// Transitions status from Pending to Cancelled.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist
+    /// - Escrow must be in Pending state
+    /// - Caller must be depositor
+    ///
+    /// EFFECTS:
+    /// - Update escrow status to Cancelled
+    pub fn cancel(env: Env, escrow_id: BytesN<32>, caller: Address) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        // Verify caller is depositor
+        AccessControl::is_depositor(&escrow, &caller)?;
+
+        // Only unfunded escrows can be withdrawn
+        if escrow.status != EscrowStatus::Pending {
+            return Err(EscrowError::InvalidState);
+        }
 
+        // Authorize the cancellation
+        caller.require_auth();
+
+        // EFFECTS: Update status
+        escrow.status = EscrowStatus::Cancelled;
         EscrowStorage::save(&env, &escrow);
-        EscrowStorage::increment_count(&env);
 
-        Ok(escrow_id)
+        events::escrow_cancelled(&env, &escrow);
+
+        Ok(())
     }
 
     /// Fund an existing escrow by depositing funds.
@@ -89,7 +203,7 @@ impl EscrowContract {
     /// - Update escrow status to Funded
     ///
     /// INTERACTIONS:
-    /// - Token transfer would happen after state update (not yet in this version)
+    /// - Token transfer from depositor to the escrow contract
     pub fn fund_escrow(
         env: Env,
         escrow_id: BytesN<32>,
@@ -117,9 +231,42 @@ impl EscrowContract {
         let token_client = token::Client::new(&env, &escrow.token);
         token_client.transfer(&caller, env.current_contract_address(), &escrow.amount);
 
+        events::escrow_funded(&env, &escrow);
+
         Ok(())
     }
 
+    /// Add funds to a funded escrow (depositor only).
+    pub fn top_up(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        amount: i128,
+    ) -> Result<(), EscrowError> {
+        AdjustmentHandler::top_up(env, escrow_id, caller, amount)
+    }
+
+    /// Change the escrow amount with approval from depositor and beneficiary.
+    /// Increases are pulled from the depositor; any excess is returned to them.
+    /// The change is recorded against `caller`, who must be one of the two.
+    pub fn adjust_amount(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        new_amount: i128,
+    ) -> Result<(), EscrowError> {
+        AdjustmentHandler::adjust_amount(env, escrow_id, caller, new_amount)
+    }
+
+    /// Get the history of amount changes for an escrow.
+    pub fn get_amount_history(
+        env: Env,
+        escrow_id: BytesN<32>,
+    ) -> Result<Vec<AmountChange>, EscrowError> {
+        EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        Ok(EscrowStorage::get_amount_history(&env, &escrow_id))
+    }
+
     /// Approve release of funds to a target address.
     /// Implements 2-of-3 multi-sig: executes transfer when ≥2 unique signers approve same target.
     ///
@@ -182,6 +329,14 @@ impl EscrowContract {
         let approval_count =
             EscrowStorage::get_approval_count_for_target(&env, &escrow_id, &release_to);
 
+        events::release_approved(
+            &env,
+            &escrow,
+            caller.clone(),
+            release_to.clone(),
+            approval_count,
+        );
+
         // If 2 or more unique signers approve, execute release
         if approval_count >= 2 {
             let mut escrow_to_update =
@@ -202,8 +357,9 @@ impl EscrowContract {
             EscrowStorage::clear_approval_counts(&env, &escrow_id, &targets, &signers);
 
             // INTERACTIONS: Token transfer from escrow contract to release target
-            let token_client = token::Client::new(&env, &escrow.token);
-            token_client.transfer(&env.current_contract_address(), &release_to, &escrow.amount);
+            VaultHandler::payout(&env, &escrow_to_update, &release_to);
+
+            events::escrow_released(&env, &escrow_to_update, release_to);
         }
 
         Ok(())
@@ -219,7 +375,35 @@ impl EscrowContract {
         DisputeHandler::initiate_dispute(env, escrow_id, caller, reason)
     }
 
-    /// Resolve a dispute by releasing funds to a target.
+    /// Append evidence to an open dispute. Returns the entry's index in the log.
+    pub fn submit_evidence(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        content_hash: BytesN<32>,
+        label: Option<soroban_sdk::String>,
+    ) -> Result<u32, EscrowError> {
+        DisputeHandler::submit_evidence(env, escrow_id, caller, content_hash, label)
+    }
+
+    /// Get a page of an escrow's dispute evidence log, oldest first.
+    pub fn get_evidence(
+        env: Env,
+        escrow_id: BytesN<32>,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<EvidenceEntry>, EscrowError> {
+        DisputeHandler::get_evidence(env, escrow_id, start, limit)
+    }
+
+    /// Get the number of entries in an escrow's dispute evidence log.
+    pub fn get_evidence_count(env: Env, escrow_id: BytesN<32>) -> Result<u32, EscrowError> {
+        DisputeHandler::get_evidence_count(env, escrow_id)
+    }
+
+    /// Resolve a dispute by releasing funds to a target (arbiter only).
+    /// Escrows with delegated arbitration are settled by the dispute panel
+    /// through `execute_outcome` instead.
     pub fn resolve_dispute(
         env: Env,
         escrow_id: BytesN<32>,
@@ -229,6 +413,105 @@ impl EscrowContract {
         DisputeHandler::resolve_dispute(env, escrow_id, caller, release_to)
     }
 
+    /// Resolve a dispute by splitting funds between beneficiary and depositor.
+    pub fn resolve_dispute_split(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        beneficiary_amount: i128,
+    ) -> Result<(), EscrowError> {
+        DisputeHandler::resolve_dispute_split(env, escrow_id, caller, beneficiary_amount)
+    }
+
+    /// Set the fee each side must post in a dispute.
+    /// Requires both depositor and beneficiary to authorize.
+    pub fn set_dispute_fee(env: Env, escrow_id: BytesN<32>, fee: i128) -> Result<(), EscrowError> {
+        DisputeHandler::set_dispute_fee(env, escrow_id, fee)
+    }
+
+    /// Set the arbiter's share of a forfeited dispute fee.
+    /// Only the escrow arbiter or the contract admin can call this.
+    pub fn set_arbiter_fee(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        arbiter_share_bps: u32,
+    ) -> Result<(), EscrowError> {
+        DisputeHandler::set_arbiter_fee(env, escrow_id, caller, arbiter_share_bps)
+    }
+
+    /// Post the respondent's matching fee for an open dispute.
+    pub fn post_dispute_fee(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+    ) -> Result<(), EscrowError> {
+        DisputeHandler::post_dispute_fee(env, escrow_id, caller)
+    }
+
+    /// Get the dispute fee terms of an escrow, if any were set.
+    pub fn get_dispute_fee(
+        env: Env,
+        escrow_id: BytesN<32>,
+    ) -> Result<Option<DisputeFeeConfig>, EscrowError> {
+        DisputeHandler::get_dispute_fee(env, escrow_id)
+    }
+
+    /// Delegate arbitration to the dispute_resolution contract named as arbiter,
+    /// linking the escrow to the agreement whose dispute panel decides it.
+    pub fn delegate_arbitration(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        agreement_id: soroban_sdk::String,
+    ) -> Result<(), EscrowError> {
+        DisputeHandler::delegate_arbitration(env, escrow_id, caller, agreement_id)
+    }
+
+    /// Execute a resolved dispute outcome (dispute_resolution callback).
+    pub fn execute_outcome(
+        env: Env,
+        escrow_id: BytesN<32>,
+        outcome: DisputeOutcome,
+    ) -> Result<(), EscrowError> {
+        DisputeHandler::execute_outcome(env, escrow_id, outcome)
+    }
+
+    /// Get the agreement whose dispute panel arbitrates an escrow, if delegated.
+    pub fn get_arbitration(
+        env: Env,
+        escrow_id: BytesN<32>,
+    ) -> Result<Option<soroban_sdk::String>, EscrowError> {
+        DisputeHandler::get_arbitration(env, escrow_id)
+    }
+
+    /// Deposit a funded escrow into an allowlisted yield vault.
+    /// Accrued yield is paid to the depositor when the escrow settles.
+    pub fn deposit_to_vault(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        vault: Address,
+    ) -> Result<(), EscrowError> {
+        VaultHandler::deposit(env, escrow_id, caller, vault)
+    }
+
+    /// Get the vault position of an escrow, if its funds are in a vault.
+    pub fn get_vault_position(
+        env: Env,
+        escrow_id: BytesN<32>,
+    ) -> Result<Option<VaultPosition>, EscrowError> {
+        EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        Ok(EscrowStorage::get_vault_position(&env, &escrow_id))
+    }
+
+    /// Get the current value of an escrow's vault position, including yield.
+    /// Returns 0 if the escrow holds no vault position.
+    pub fn get_vault_balance(env: Env, escrow_id: BytesN<32>) -> Result<i128, EscrowError> {
+        EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+        Ok(VaultHandler::position_value(&env, &escrow_id).unwrap_or(0))
+    }
+
     /// Get details of an escrow.
     /// Read-only view function.
     pub fn get_escrow(env: Env, escrow_id: BytesN<32>) -> Result<Escrow, EscrowError> {
@@ -251,4 +534,60 @@ impl EscrowContract {
             &release_to,
         ))
     }
+
+    /// Validate parameters, derive the escrow ID and persist a new escrow.
+    fn new_escrow(
+        env: &Env,
+        agreement_id: String,
+        depositor: Address,
+        beneficiary: Address,
+        arbiter: Address,
+        amount: i128,
+        token: Address,
+        status: EscrowStatus,
+    ) -> Result<Escrow, EscrowError> {
+        if amount <= 0 {
+            return Err(EscrowError::InsufficientFunds);
+        }
+
+        // Ensure all parties are distinct
+        if depositor == beneficiary || depositor == arbiter || beneficiary == arbiter {
+            return Err(EscrowError::InvalidSigner);
+        }
+
+        // Generate unique escrow ID from hash of parameters
+        let mut data = soroban_sdk::Bytes::new(env);
+        data.append(&agreement_id.clone().to_xdr(env));
+        data.append(&depositor.clone().to_xdr(env));
+        data.append(&beneficiary.clone().to_xdr(env));
+        data.append(&arbiter.clone().to_xdr(env));
+        data.append(&amount.to_xdr(env));
+        data.append(&token.clone().to_xdr(env));
+        data.append(&env.ledger().timestamp().to_xdr(env));
+
+        let escrow_id: BytesN<32> = env.crypto().sha256(&data).into();
+
+        // Identical parameters in the same ledger would collide
+        if EscrowStorage::get(env, &escrow_id).is_some() {
+            return Err(EscrowError::AlreadyExists);
+        }
+
+        let escrow = Escrow {
+            id: escrow_id,
+            agreement_id,
+            depositor,
+            beneficiary,
+            arbiter,
+            amount,
+            token,
+            status,
+            created_at: env.ledger().timestamp(),
+            dispute_reason: None,
+        };
+
+        EscrowStorage::save(env, &escrow);
+        EscrowStorage::increment_count(env);
+
+        Ok(escrow)
+    }
 }
@@ -0,0 +1,503 @@
+//! Event definitions for the Escrow contract.
+//! Every lifecycle transition publishes a typed event keyed by escrow ID and
+//! the ID of the agreement the escrow secures.
+use soroban_sdk::{contractevent, Address, BytesN, Env, String};
+
+use crate::types::{DisputeFeeConfig, Escrow, EscrowStatus};
+
+/// Event emitted when an escrow is created
+/// Topics: ["esc_created", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["esc_created"])]
+pub struct EscrowCreated {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub depositor: Address,
+    pub beneficiary: Address,
+    pub arbiter: Address,
+    pub amount: i128,
+    pub token: Address,
+    pub status: EscrowStatus,
+}
+
+/// Event emitted when an escrow is funded by the depositor
+/// Topics: ["esc_funded", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["esc_funded"])]
+pub struct EscrowFunded {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub depositor: Address,
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub status: EscrowStatus,
+}
+
+/// Event emitted when an unfunded escrow is cancelled by its depositor
+/// Topics: ["esc_cancelled", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["esc_cancelled"])]
+pub struct EscrowCancelled {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub depositor: Address,
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub status: EscrowStatus,
+}
+
+/// Event emitted when the amount held by an escrow changes
+/// Topics: ["esc_amount", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["esc_amount"])]
+pub struct EscrowAmountChanged {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub depositor: Address,
+    pub beneficiary: Address,
+    pub previous_amount: i128,
+    pub new_amount: i128,
+    pub status: EscrowStatus,
+}
+
+/// Event emitted when a party approves a release
+/// Topics: ["esc_approved", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["esc_approved"])]
+pub struct ReleaseApproved {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub signer: Address,
+    pub release_to: Address,
+    pub approval_count: u32,
+    pub status: EscrowStatus,
+}
+
+/// Event emitted when escrowed funds are released by multi-sig
+/// Topics: ["esc_released", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["esc_released"])]
+pub struct EscrowReleased {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub depositor: Address,
+    pub beneficiary: Address,
+    pub release_to: Address,
+    pub amount: i128,
+    pub status: EscrowStatus,
+}
+
+/// Event emitted when a dispute is raised on an escrow
+/// Topics: ["esc_disputed", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["esc_disputed"])]
+pub struct DisputeInitiated {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub initiator: Address,
+    pub depositor: Address,
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub reason: String,
+    pub status: EscrowStatus,
+}
+
+/// Event emitted when a party adds to the evidence log of a dispute
+/// Topics: ["esc_evidence", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["esc_evidence"])]
+pub struct EvidenceSubmitted {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub submitter: Address,
+    pub index: u32,
+    pub content_hash: BytesN<32>,
+}
+
+/// Event emitted when the arbiter resolves a dispute
+/// Topics: ["esc_resolved", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["esc_resolved"])]
+pub struct DisputeResolved {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub arbiter: Address,
+    pub depositor: Address,
+    pub beneficiary: Address,
+    pub release_to: Address,
+    pub amount: i128,
+    pub status: EscrowStatus,
+}
+
+/// Event emitted when the arbiter splits a disputed escrow between the parties
+/// Topics: ["esc_split", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["esc_split"])]
+pub struct DisputeSplit {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub arbiter: Address,
+    pub depositor: Address,
+    pub beneficiary: Address,
+    pub beneficiary_amount: i128,
+    pub depositor_amount: i128,
+    pub status: EscrowStatus,
+}
+
+/// Event emitted when the parties agree on dispute fee terms
+/// Topics: ["esc_fee_set", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["esc_fee_set"])]
+pub struct DisputeFeeSet {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub fee: i128,
+    pub arbiter_share_bps: u32,
+}
+
+/// Event emitted when the dispute initiator or respondent posts the dispute fee
+/// Topics: ["esc_fee_post", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["esc_fee_post"])]
+pub struct DisputeFeePosted {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub payer: Address,
+    pub amount: i128,
+}
+
+/// Event emitted when a posted dispute fee is allocated on resolution
+/// Topics: ["esc_fee_alloc", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["esc_fee_alloc"])]
+pub struct DisputeFeeAllocated {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub payer: Address,
+    pub arbiter_payee: Address,
+    pub arbiter_amount: i128,
+    pub depositor_amount: i128,
+    pub beneficiary_amount: i128,
+}
+
+/// Event emitted when escrow arbitration is delegated to a dispute_resolution contract
+/// Topics: ["esc_delegated", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["esc_delegated"])]
+pub struct ArbitrationDelegated {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub caller: Address,
+    pub arbiter: Address,
+    pub status: EscrowStatus,
+}
+
+/// Event emitted when the admin changes the vault allowlist
+/// Topics: ["vault_allow", vault: Address]
+#[contractevent(topics = ["vault_allow"])]
+pub struct VaultAllowlistUpdated {
+    #[topic]
+    pub vault: Address,
+    pub admin: Address,
+    pub allowed: bool,
+}
+
+/// Event emitted when escrowed funds are deposited into a vault
+/// Topics: ["vault_deposit", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["vault_deposit"])]
+pub struct VaultDeposited {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub vault: Address,
+    pub depositor: Address,
+    pub amount: i128,
+    pub shares: i128,
+    pub status: EscrowStatus,
+}
+
+/// Event emitted when escrowed funds are redeemed from a vault on settlement
+/// Topics: ["vault_redeem", escrow_id: BytesN<32>, agreement_id: String]
+#[contractevent(topics = ["vault_redeem"])]
+pub struct VaultRedeemed {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    #[topic]
+    pub agreement_id: String,
+    pub vault: Address,
+    pub depositor: Address,
+    pub redeemed: i128,
+    pub yield_amount: i128,
+    pub status: EscrowStatus,
+}
+
+/// Helper function to emit escrow created event
+pub(crate) fn escrow_created(env: &Env, escrow: &Escrow) {
+    EscrowCreated {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        depositor: escrow.depositor.clone(),
+        beneficiary: escrow.beneficiary.clone(),
+        arbiter: escrow.arbiter.clone(),
+        amount: escrow.amount,
+        token: escrow.token.clone(),
+        status: escrow.status.clone(),
+    }
+    .publish(env);
+}
+
+/// Helper function to emit escrow funded event
+pub(crate) fn escrow_funded(env: &Env, escrow: &Escrow) {
+    EscrowFunded {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        depositor: escrow.depositor.clone(),
+        beneficiary: escrow.beneficiary.clone(),
+        amount: escrow.amount,
+        status: escrow.status.clone(),
+    }
+    .publish(env);
+}
+
+/// Helper function to emit escrow cancelled event
+pub(crate) fn escrow_cancelled(env: &Env, escrow: &Escrow) {
+    EscrowCancelled {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        depositor: escrow.depositor.clone(),
+        beneficiary: escrow.beneficiary.clone(),
+        amount: escrow.amount,
+        status: escrow.status.clone(),
+    }
+    .publish(env);
+}
+
+/// Helper function to emit escrow amount changed event
+pub(crate) fn escrow_amount_changed(env: &Env, escrow: &Escrow, previous_amount: i128) {
+    EscrowAmountChanged {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        depositor: escrow.depositor.clone(),
+        beneficiary: escrow.beneficiary.clone(),
+        previous_amount,
+        new_amount: escrow.amount,
+        status: escrow.status.clone(),
+    }
+    .publish(env);
+}
+
+/// Helper function to emit release approved event
+pub(crate) fn release_approved(
+    env: &Env,
+    escrow: &Escrow,
+    signer: Address,
+    release_to: Address,
+    approval_count: u32,
+) {
+    ReleaseApproved {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        signer,
+        release_to,
+        approval_count,
+        status: escrow.status.clone(),
+    }
+    .publish(env);
+}
+
+/// Helper function to emit escrow released event
+pub(crate) fn escrow_released(env: &Env, escrow: &Escrow, release_to: Address) {
+    EscrowReleased {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        depositor: escrow.depositor.clone(),
+        beneficiary: escrow.beneficiary.clone(),
+        release_to,
+        amount: escrow.amount,
+        status: escrow.status.clone(),
+    }
+    .publish(env);
+}
+
+/// Helper function to emit dispute initiated event
+pub(crate) fn dispute_initiated(env: &Env, escrow: &Escrow, initiator: Address, reason: String) {
+    DisputeInitiated {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        initiator,
+        depositor: escrow.depositor.clone(),
+        beneficiary: escrow.beneficiary.clone(),
+        amount: escrow.amount,
+        reason,
+        status: escrow.status.clone(),
+    }
+    .publish(env);
+}
+
+/// Helper function to emit evidence submitted event
+pub(crate) fn evidence_submitted(
+    env: &Env,
+    escrow: &Escrow,
+    submitter: Address,
+    index: u32,
+    content_hash: BytesN<32>,
+) {
+    EvidenceSubmitted {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        submitter,
+        index,
+        content_hash,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit dispute resolved event
+pub(crate) fn dispute_resolved(env: &Env, escrow: &Escrow, release_to: Address) {
+    DisputeResolved {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        arbiter: escrow.arbiter.clone(),
+        depositor: escrow.depositor.clone(),
+        beneficiary: escrow.beneficiary.clone(),
+        release_to,
+        amount: escrow.amount,
+        status: escrow.status.clone(),
+    }
+    .publish(env);
+}
+
+/// Helper function to emit dispute split event
+pub(crate) fn dispute_split(env: &Env, escrow: &Escrow, beneficiary_amount: i128) {
+    DisputeSplit {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        arbiter: escrow.arbiter.clone(),
+        depositor: escrow.depositor.clone(),
+        beneficiary: escrow.beneficiary.clone(),
+        beneficiary_amount,
+        depositor_amount: escrow.amount - beneficiary_amount,
+        status: escrow.status.clone(),
+    }
+    .publish(env);
+}
+
+/// Helper function to emit dispute fee set event
+pub(crate) fn dispute_fee_set(env: &Env, escrow: &Escrow, config: &DisputeFeeConfig) {
+    DisputeFeeSet {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        fee: config.fee,
+        arbiter_share_bps: config.arbiter_share_bps,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit dispute fee posted event
+pub(crate) fn dispute_fee_posted(env: &Env, escrow: &Escrow, payer: Address, amount: i128) {
+    DisputeFeePosted {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        payer,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit dispute fee allocated event
+pub(crate) fn dispute_fee_allocated(
+    env: &Env,
+    escrow: &Escrow,
+    payer: Address,
+    arbiter_payee: Address,
+    arbiter_amount: i128,
+    depositor_amount: i128,
+    beneficiary_amount: i128,
+) {
+    DisputeFeeAllocated {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        payer,
+        arbiter_payee,
+        arbiter_amount,
+        depositor_amount,
+        beneficiary_amount,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit vault allowlist updated event
+pub(crate) fn vault_allowlist_updated(env: &Env, admin: Address, vault: Address, allowed: bool) {
+    VaultAllowlistUpdated {
+        vault,
+        admin,
+        allowed,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit vault deposited event
+pub(crate) fn vault_deposited(env: &Env, escrow: &Escrow, vault: Address, shares: i128) {
+    VaultDeposited {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        vault,
+        depositor: escrow.depositor.clone(),
+        amount: escrow.amount,
+        shares,
+        status: escrow.status.clone(),
+    }
+    .publish(env);
+}
+
+/// Helper function to emit vault redeemed event
+pub(crate) fn vault_redeemed(
+    env: &Env,
+    escrow: &Escrow,
+    vault: Address,
+    redeemed: i128,
+    yield_amount: i128,
+) {
+    VaultRedeemed {
+        escrow_id: escrow.id.clone(),
+        agreement_id: escrow.agreement_id.clone(),
+        vault,
+        depositor: escrow.depositor.clone(),
+        redeemed,
+        yield_amount,
+        status: escrow.status.clone(),
+    }
+    .publish(env);
+}
+
+/// Helper function to emit arbitration delegated event
+pub(crate) fn arbitration_delegated(
+    env: &Env,
+    escrow: &Escrow,
+    caller: Address,
+    agreement_id: String,
+) {
+    ArbitrationDelegated {
+        escrow_id: escrow.id.clone(),
+        agreement_id,
+        caller,
+        arbiter: escrow.arbiter.clone(),
+        status: escrow.status.clone(),
+    }
+    .publish(env);
+}
@@ -4,22 +4,31 @@
 //! Escrow Contract
 //!
 //! Manages security deposit escrows with 2-of-3 multi-sig release mechanism.
-//! Supports dispute resolution with arbiter involvement.
+//! Supports dispute resolution with arbiter involvement and optional
+//! interest-bearing deposits through allowlisted vaults.
 
 pub mod access;
+pub mod adjustment;
 pub mod dispute;
 pub mod errors;
 pub mod escrow_impl;
+pub mod events;
 pub mod storage;
 pub mod types;
+pub mod vault;
 
 #[cfg(test)]
 mod tests;
 
 // Re-export public APIs
 pub use access::AccessControl;
+pub use adjustment::AdjustmentHandler;
 pub use dispute::DisputeHandler;
 pub use errors::EscrowError;
 pub use escrow_impl::EscrowContract;
 pub use storage::EscrowStorage;
-pub use types::{DataKey, Escrow, EscrowStatus, ReleaseApproval};
+pub use types::{
+    DataKey, DisputeFeeConfig, DisputeOutcome, Escrow, EscrowStatus, EvidenceEntry,
+    PostedDisputeFee, ReleaseApproval, VaultPosition,
+};
+pub use vault::{VaultClient, VaultHandler, VaultInterface};
@@ -1,8 +1,11 @@
 //! Storage operations for the Escrow contract.
 //! Implements single-responsibility getter/setter helpers.
-use soroban_sdk::{Address, BytesN, Env, Vec};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
-use crate::types::{DataKey, Escrow, ReleaseApproval};
+use crate::types::{
+    AmountChange, DataKey, DisputeFeeConfig, Escrow, EvidenceEntry, PostedDisputeFee,
+    ReleaseApproval, VaultPosition,
+};
 
 /// Escrow storage management.
 pub struct EscrowStorage;
@@ -127,4 +130,164 @@ impl EscrowStorage {
             .instance()
             .set(&DataKey::EscrowCount, &(count + 1));
     }
+
+    /// Get the contract admin, if initialized.
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get::<_, Address>(&DataKey::Admin)
+    }
+
+    /// Set the contract admin.
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Check whether a vault is on the allowlist.
+    pub fn is_vault_allowed(env: &Env, vault: &Address) -> bool {
+        let key = DataKey::AllowedVault(vault.clone());
+        env.storage()
+            .persistent()
+            .get::<_, bool>(&key)
+            .unwrap_or(false)
+    }
+
+    /// Add or remove a vault from the allowlist.
+    pub fn set_vault_allowed(env: &Env, vault: &Address, allowed: bool) {
+        let key = DataKey::AllowedVault(vault.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Get the vault position of an escrow, if any.
+    pub fn get_vault_position(env: &Env, escrow_id: &BytesN<32>) -> Option<VaultPosition> {
+        let key = DataKey::VaultPosition(escrow_id.clone());
+        env.storage().persistent().get::<_, VaultPosition>(&key)
+    }
+
+    /// Save the vault position of an escrow.
+    pub fn set_vault_position(env: &Env, escrow_id: &BytesN<32>, position: &VaultPosition) {
+        let key = DataKey::VaultPosition(escrow_id.clone());
+        env.storage().persistent().set(&key, position);
+    }
+
+    /// Remove the vault position of an escrow.
+    pub fn remove_vault_position(env: &Env, escrow_id: &BytesN<32>) {
+        let key = DataKey::VaultPosition(escrow_id.clone());
+        env.storage().persistent().remove(&key);
+    }
+
+    /// Get the total shares this contract holds in a vault.
+    pub fn get_vault_shares(env: &Env, vault: &Address) -> i128 {
+        let key = DataKey::VaultShares(vault.clone());
+        env.storage().persistent().get::<_, i128>(&key).unwrap_or(0)
+    }
+
+    /// Set the total shares this contract holds in a vault.
+    pub fn set_vault_shares(env: &Env, vault: &Address, shares: i128) {
+        let key = DataKey::VaultShares(vault.clone());
+        env.storage().persistent().set(&key, &shares);
+    }
+
+    /// Get the agreement ID whose dispute panel arbitrates an escrow.
+    pub fn get_arbitration(env: &Env, escrow_id: &BytesN<32>) -> Option<String> {
+        let key = DataKey::Arbitration(escrow_id.clone());
+        env.storage().persistent().get::<_, String>(&key)
+    }
+
+    /// Link an escrow to the agreement whose dispute panel arbitrates it.
+    pub fn set_arbitration(env: &Env, escrow_id: &BytesN<32>, agreement_id: &String) {
+        let key = DataKey::Arbitration(escrow_id.clone());
+        env.storage().persistent().set(&key, agreement_id);
+    }
+
+    /// Retrieve the amount change history of an escrow.
+    /// Returns empty Vec if the amount never changed.
+    pub fn get_amount_history(env: &Env, escrow_id: &BytesN<32>) -> Vec<AmountChange> {
+        let key = DataKey::AmountHistory(escrow_id.clone());
+        env.storage()
+            .persistent()
+            .get::<_, Vec<AmountChange>>(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Append a change to the amount history of an escrow.
+    pub fn add_amount_change(env: &Env, escrow_id: &BytesN<32>, change: AmountChange) {
+        let mut history = Self::get_amount_history(env, escrow_id);
+        history.push_back(change);
+        let key = DataKey::AmountHistory(escrow_id.clone());
+        env.storage().persistent().set(&key, &history);
+    }
+
+    /// Retrieve the dispute fee terms of an escrow.
+    pub fn get_dispute_fee(env: &Env, escrow_id: &BytesN<32>) -> Option<DisputeFeeConfig> {
+        let key = DataKey::DisputeFee(escrow_id.clone());
+        env.storage().persistent().get::<_, DisputeFeeConfig>(&key)
+    }
+
+    /// Store the dispute fee terms of an escrow.
+    pub fn set_dispute_fee(env: &Env, escrow_id: &BytesN<32>, config: &DisputeFeeConfig) {
+        let key = DataKey::DisputeFee(escrow_id.clone());
+        env.storage().persistent().set(&key, config);
+    }
+
+    /// Retrieve the fee posted for an escrow's open dispute.
+    pub fn get_posted_dispute_fee(env: &Env, escrow_id: &BytesN<32>) -> Option<PostedDisputeFee> {
+        let key = DataKey::PostedDisputeFee(escrow_id.clone());
+        env.storage().persistent().get::<_, PostedDisputeFee>(&key)
+    }
+
+    /// Store the fee posted for an escrow's open dispute.
+    pub fn set_posted_dispute_fee(env: &Env, escrow_id: &BytesN<32>, posted: &PostedDisputeFee) {
+        let key = DataKey::PostedDisputeFee(escrow_id.clone());
+        env.storage().persistent().set(&key, posted);
+    }
+
+    /// Remove the posted dispute fee once it has been allocated.
+    pub fn remove_posted_dispute_fee(env: &Env, escrow_id: &BytesN<32>) {
+        let key = DataKey::PostedDisputeFee(escrow_id.clone());
+        env.storage().persistent().remove(&key);
+    }
+
+    /// Get the number of entries in the dispute evidence log of an escrow.
+    pub fn get_evidence_count(env: &Env, escrow_id: &BytesN<32>) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<_, u32>(&DataKey::EvidenceCount(escrow_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Get the number of evidence entries a party has submitted for an escrow.
+    pub fn get_party_evidence_count(env: &Env, escrow_id: &BytesN<32>, party: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<_, u32>(&DataKey::PartyEvidenceCount(
+                escrow_id.clone(),
+                party.clone(),
+            ))
+            .unwrap_or(0)
+    }
+
+    /// Retrieve an entry of the dispute evidence log by index.
+    pub fn get_evidence(env: &Env, escrow_id: &BytesN<32>, index: u32) -> Option<EvidenceEntry> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Evidence(escrow_id.clone(), index))
+    }
+
+    /// Append an entry to the dispute evidence log of an escrow.
+    /// Returns the index of the new entry.
+    pub fn add_evidence(env: &Env, escrow_id: &BytesN<32>, entry: EvidenceEntry) -> u32 {
+        let index = Self::get_evidence_count(env, escrow_id);
+        let party_count = Self::get_party_evidence_count(env, escrow_id, &entry.submitter);
+        let persistent = env.storage().persistent();
+        persistent.set(
+            &DataKey::PartyEvidenceCount(escrow_id.clone(), entry.submitter.clone()),
+            &(party_count + 1),
+        );
+        persistent.set(&DataKey::Evidence(escrow_id.clone(), index), &entry);
+        persistent.set(&DataKey::EvidenceCount(escrow_id.clone()), &(index + 1));
+        index
+    }
 }
@@ -1,12 +1,20 @@
 //! Tests for the Escrow contract.
 
-use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke};
 use soroban_sdk::token::Client as TokenClient;
 use soroban_sdk::token::StellarAssetClient as TokenAdminClient;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, Address, BytesN, Env, IntoVal, Map, String, Symbol,
+    TryFromVal, Val, Vec,
+};
 
+use crate::errors::EscrowError;
 use crate::escrow_impl::{EscrowContract, EscrowContractClient};
-use crate::types::EscrowStatus;
+use crate::types::{AmountChangeKind, DisputeOutcome, EscrowStatus};
+
+fn agreement_id(env: &Env) -> String {
+    String::from_str(env, "agreement_001")
+}
 
 fn setup_test(env: &Env) -> (EscrowContractClient<'_>, Address, Address, Address, Address) {
     let contract_id = env.register(EscrowContract, ());
@@ -33,7 +41,14 @@ fn test_escrow_lifecycle() {
     let amount = 1000i128;
 
     // 1. Create Escrow
-    let escrow_id = client.create(&depositor, &beneficiary, &arbiter, &amount, &token_address);
+    let escrow_id = client.create(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
     let escrow = client.get_escrow(&escrow_id);
     assert_eq!(escrow.status, EscrowStatus::Pending);
     assert_eq!(escrow.amount, amount);
@@ -82,7 +97,14 @@ fn test_dispute_resolution() {
     let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
     let amount = 1000i128;
 
-    let escrow_id = client.create(&depositor, &beneficiary, &arbiter, &amount, &token_address);
+    let escrow_id = client.create(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
 
     let token_admin = TokenAdminClient::new(&env, &token_address);
     token_admin.mint(&depositor, &amount);
@@ -113,7 +135,16 @@ fn test_unauthorized_funding() {
     let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
     let amount = 1000i128;
 
-    let escrow_id = client.create(&depositor, &beneficiary, &arbiter, &amount, &token_address);
+    env.mock_all_auths();
+    let escrow_id = client.create(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    env.set_auths(&[]);
 
     // Try to fund from beneficiary (should fail since only depositor can fund)
     // We expect an error, but AccessControl check happens before require_auth
@@ -124,12 +155,12 @@ fn test_unauthorized_funding() {
 #[test]
 fn test_unique_escrow_ids() {
     use crate::escrow_impl::EscrowContract;
-    use soroban_sdk::contract;
 
     #[contract]
     struct TestContract;
 
     let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
     let contract_id = env.register(TestContract, ());
 
     let depositor = Address::generate(&env);
@@ -141,6 +172,7 @@ fn test_unique_escrow_ids() {
         .as_contract(&contract_id, || {
             EscrowContract::create(
                 env.clone(),
+                agreement_id(&env),
                 depositor.clone(),
                 beneficiary.clone(),
                 arbiter.clone(),
@@ -156,6 +188,7 @@ fn test_unique_escrow_ids() {
         .as_contract(&contract_id, || {
             EscrowContract::create(
                 env.clone(),
+                agreement_id(&env),
                 depositor.clone(),
                 beneficiary.clone(),
                 arbiter.clone(),
@@ -185,6 +218,52 @@ fn test_unique_escrow_ids() {
     assert_eq!(escrow2.amount, 1000);
 }
 
+#[test]
+#[should_panic]
+fn test_create_requires_depositor_auth() {
+    let env = Env::default();
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+
+    client.create(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+}
+
+#[test]
+fn test_create_rejects_existing_escrow_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1000);
+
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+
+    // Same parameters in the same ledger must not overwrite the funded escrow
+    let result = client.try_create(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    assert_eq!(result, Err(Ok(EscrowError::AlreadyExists)));
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Funded);
+}
+
 #[test]
 fn test_duplicate_approval_rejected() {
     let env = Env::default();
@@ -193,7 +272,14 @@ fn test_duplicate_approval_rejected() {
     let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
     let amount = 1000i128;
 
-    let escrow_id = client.create(&depositor, &beneficiary, &arbiter, &amount, &token_address);
+    let escrow_id = client.create(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
 
     let token_admin = TokenAdminClient::new(&env, &token_address);
     token_admin.mint(&depositor, &amount);
@@ -219,7 +305,14 @@ fn test_approval_count_tracks_per_target() {
     let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
     let amount = 1000i128;
 
-    let escrow_id = client.create(&depositor, &beneficiary, &arbiter, &amount, &token_address);
+    let escrow_id = client.create(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
 
     let token_admin = TokenAdminClient::new(&env, &token_address);
     token_admin.mint(&depositor, &amount);
@@ -244,3 +337,1170 @@ fn test_approval_count_tracks_per_target() {
     let token_client = TokenClient::new(&env, &token_address);
     assert_eq!(token_client.balance(&beneficiary), amount);
 }
+
+/// Events published by `contract` in the last invocation, as topics and
+/// decoded data fields
+fn contract_events(env: &Env, contract: &Address) -> Vec<(Vec<Val>, Map<Symbol, Val>)> {
+    let mut events = Vec::new(env);
+    for event in env.events().all().iter() {
+        if event.0 == *contract {
+            events.push_back((event.1, event.2.into_val(env)));
+        }
+    }
+    events
+}
+
+/// Check an escrow event's name, escrow ID and agreement ID topics
+fn assert_escrow_topics(env: &Env, topics: &Vec<Val>, name: &str, escrow_id: &BytesN<32>) {
+    assert_eq!(topics.len(), 3);
+    let topic_name: Symbol = topics.get(0).unwrap().into_val(env);
+    assert_eq!(topic_name, Symbol::new(env, name));
+    let topic_id: BytesN<32> = topics.get(1).unwrap().into_val(env);
+    assert_eq!(topic_id, *escrow_id);
+    let topic_agreement: String = topics.get(2).unwrap().into_val(env);
+    assert_eq!(topic_agreement, agreement_id(env));
+}
+
+fn event_field<T: TryFromVal<Env, Val>>(env: &Env, data: &Map<Symbol, Val>, field: &str) -> T {
+    T::try_from_val(env, &data.get(Symbol::new(env, field)).unwrap()).unwrap()
+}
+
+#[test]
+fn test_lifecycle_events_emitted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    let events = contract_events(&env, &client.address);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events.get(0).unwrap();
+    assert_escrow_topics(&env, &topics, "esc_created", &escrow_id);
+    assert_eq!(event_field::<Address>(&env, &data, "depositor"), depositor);
+    assert_eq!(
+        event_field::<Address>(&env, &data, "beneficiary"),
+        beneficiary
+    );
+    assert_eq!(event_field::<Address>(&env, &data, "arbiter"), arbiter);
+    assert_eq!(event_field::<i128>(&env, &data, "amount"), amount);
+    assert_eq!(event_field::<Address>(&env, &data, "token"), token_address);
+    assert_eq!(
+        event_field::<EscrowStatus>(&env, &data, "status"),
+        EscrowStatus::Pending
+    );
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+    let events = contract_events(&env, &client.address);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events.get(0).unwrap();
+    assert_escrow_topics(&env, &topics, "esc_funded", &escrow_id);
+    assert_eq!(event_field::<i128>(&env, &data, "amount"), amount);
+    assert_eq!(
+        event_field::<EscrowStatus>(&env, &data, "status"),
+        EscrowStatus::Funded
+    );
+
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+    let events = contract_events(&env, &client.address);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events.get(0).unwrap();
+    assert_escrow_topics(&env, &topics, "esc_approved", &escrow_id);
+    assert_eq!(event_field::<Address>(&env, &data, "signer"), depositor);
+    assert_eq!(
+        event_field::<Address>(&env, &data, "release_to"),
+        beneficiary
+    );
+    assert_eq!(event_field::<u32>(&env, &data, "approval_count"), 1);
+
+    // Second approval emits both the approval and the release
+    client.approve_release(&escrow_id, &arbiter, &beneficiary);
+    let events = contract_events(&env, &client.address);
+    assert_eq!(events.len(), 2);
+    let (topics, data) = events.get(0).unwrap();
+    assert_escrow_topics(&env, &topics, "esc_approved", &escrow_id);
+    assert_eq!(event_field::<u32>(&env, &data, "approval_count"), 2);
+    let (topics, data) = events.get(1).unwrap();
+    assert_escrow_topics(&env, &topics, "esc_released", &escrow_id);
+    assert_eq!(
+        event_field::<Address>(&env, &data, "release_to"),
+        beneficiary
+    );
+    assert_eq!(event_field::<i128>(&env, &data, "amount"), amount);
+    assert_eq!(
+        event_field::<EscrowStatus>(&env, &data, "status"),
+        EscrowStatus::Released
+    );
+}
+
+#[test]
+fn test_dispute_events_emitted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+
+    let reason = String::from_str(&env, "Damage to property");
+    client.initiate_dispute(&escrow_id, &depositor, &reason);
+    let events = contract_events(&env, &client.address);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events.get(0).unwrap();
+    assert_escrow_topics(&env, &topics, "esc_disputed", &escrow_id);
+    assert_eq!(event_field::<Address>(&env, &data, "initiator"), depositor);
+    assert_eq!(event_field::<String>(&env, &data, "reason"), reason);
+    assert_eq!(
+        event_field::<EscrowStatus>(&env, &data, "status"),
+        EscrowStatus::Disputed
+    );
+
+    client.resolve_dispute(&escrow_id, &arbiter, &beneficiary);
+    let events = contract_events(&env, &client.address);
+    assert_eq!(events.len(), 1);
+    let (topics, data) = events.get(0).unwrap();
+    assert_escrow_topics(&env, &topics, "esc_resolved", &escrow_id);
+    assert_eq!(event_field::<Address>(&env, &data, "arbiter"), arbiter);
+    assert_eq!(
+        event_field::<Address>(&env, &data, "release_to"),
+        beneficiary
+    );
+    assert_eq!(event_field::<i128>(&env, &data, "amount"), amount);
+    assert_eq!(
+        event_field::<EscrowStatus>(&env, &data, "status"),
+        EscrowStatus::Released
+    );
+}
+
+#[test]
+fn test_create_and_fund() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Funded);
+    assert_eq!(escrow.amount, amount);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&depositor), 0);
+    assert_eq!(token_client.balance(&client.address), amount);
+}
+
+#[test]
+#[should_panic]
+fn test_create_and_fund_requires_depositor_auth() {
+    let env = Env::default();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+
+    client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+}
+
+#[test]
+fn test_cancel_pending_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+
+    let escrow_id = client.create(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    client.cancel(&escrow_id, &depositor);
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Cancelled);
+
+    // A cancelled escrow can no longer be funded
+    let result = client.try_fund_escrow(&escrow_id, &depositor);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidState)));
+}
+
+#[test]
+fn test_cancel_rejected_for_non_depositor_or_funded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+    let result = client.try_cancel(&escrow_id, &beneficiary);
+    assert_eq!(result, Err(Ok(EscrowError::NotAuthorized)));
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    let result = client.try_cancel(&escrow_id, &depositor);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidState)));
+}
+
+/// Mock yield vault holding a single token and tracking per-owner balances.
+/// Yield is simulated by calling `accrue` after minting tokens to the vault.
+#[contract]
+pub struct MockVault;
+
+#[contractimpl]
+impl MockVault {
+    pub fn init(env: Env, token: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("token"), &token);
+    }
+
+    pub fn deposit(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("token"))
+            .unwrap();
+        TokenClient::new(&env, &token).transfer(&from, env.current_contract_address(), &amount);
+        let balance = Self::balance(env.clone(), from.clone());
+        env.storage().persistent().set(&from, &(balance + amount));
+    }
+
+    pub fn withdraw(env: Env, to: Address, amount: i128) {
+        to.require_auth();
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("token"))
+            .unwrap();
+        let balance = Self::balance(env.clone(), to.clone());
+        assert!(balance >= amount, "insufficient vault balance");
+        env.storage().persistent().set(&to, &(balance - amount));
+        TokenClient::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+    }
+
+    pub fn balance(env: Env, owner: Address) -> i128 {
+        env.storage().persistent().get(&owner).unwrap_or(0)
+    }
+
+    pub fn accrue(env: Env, owner: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), owner.clone());
+        env.storage().persistent().set(&owner, &(balance + amount));
+    }
+}
+
+fn setup_vault(env: &Env, client: &EscrowContractClient<'_>, token_address: &Address) -> Address {
+    let vault = env.register(MockVault, ());
+    MockVaultClient::new(env, &vault).init(token_address);
+
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    client.set_vault_allowed(&vault, &true);
+
+    vault
+}
+
+fn accrue_yield(
+    env: &Env,
+    vault: &Address,
+    token_address: &Address,
+    owner: &Address,
+    amount: i128,
+) {
+    TokenAdminClient::new(env, token_address).mint(vault, &amount);
+    MockVaultClient::new(env, vault).accrue(owner, &amount);
+}
+
+#[test]
+fn test_vault_yield_paid_to_depositor_on_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+    let vault = setup_vault(&env, &client, &token_address);
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &amount,
+        &token_address,
+    );
+
+    client.deposit_to_vault(&escrow_id, &depositor, &vault);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(token_client.balance(&vault), amount);
+    assert_eq!(
+        client.get_vault_position(&escrow_id).unwrap().shares,
+        amount
+    );
+
+    accrue_yield(&env, &vault, &token_address, &client.address, 50);
+    assert_eq!(client.get_vault_balance(&escrow_id), 1050);
+
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+    client.approve_release(&escrow_id, &arbiter, &beneficiary);
+
+    assert_eq!(token_client.balance(&beneficiary), amount);
+    assert_eq!(token_client.balance(&depositor), 50);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_vault_position(&escrow_id), None);
+}
+
+#[test]
+fn test_vault_yield_split_between_escrows() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let vault = setup_vault(&env, &client, &token_address);
+    let token_admin = TokenAdminClient::new(&env, &token_address);
+
+    token_admin.mint(&depositor, &3500);
+    let first = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    client.deposit_to_vault(&first, &depositor, &vault);
+
+    // First escrow earns 250 before the second joins
+    accrue_yield(&env, &vault, &token_address, &client.address, 250);
+
+    let second = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &2500,
+        &token_address,
+    );
+    client.deposit_to_vault(&second, &depositor, &vault);
+
+    assert_eq!(client.get_vault_balance(&first), 1250);
+    assert_eq!(client.get_vault_balance(&second), 2500);
+
+    // Refund via dispute: depositor receives principal plus yield
+    let reason = soroban_sdk::String::from_str(&env, "Lease ended");
+    client.initiate_dispute(&first, &depositor, &reason);
+    client.resolve_dispute(&first, &arbiter, &depositor);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&depositor), 1250);
+    assert_eq!(client.get_vault_balance(&second), 2500);
+}
+
+#[test]
+fn test_vault_must_be_allowlisted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let vault = setup_vault(&env, &client, &token_address);
+    client.set_vault_allowed(&vault, &false);
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1000);
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+
+    let result = client.try_deposit_to_vault(&escrow_id, &depositor, &vault);
+    assert_eq!(result, Err(Ok(EscrowError::VaultNotAllowed)));
+}
+
+#[test]
+fn test_vault_deposit_rejected_twice_or_by_non_depositor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let vault = setup_vault(&env, &client, &token_address);
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1000);
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+
+    let result = client.try_deposit_to_vault(&escrow_id, &beneficiary, &vault);
+    assert_eq!(result, Err(Ok(EscrowError::NotAuthorized)));
+
+    client.deposit_to_vault(&escrow_id, &depositor, &vault);
+    let result = client.try_deposit_to_vault(&escrow_id, &depositor, &vault);
+    assert_eq!(result, Err(Ok(EscrowError::VaultAlreadyActive)));
+}
+
+#[test]
+fn test_initialize_twice_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _, _, _, _) = setup_test(&env);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin);
+    assert_eq!(client.get_admin(), Some(admin.clone()));
+
+    let result = client.try_initialize(&admin);
+    assert_eq!(result, Err(Ok(EscrowError::AlreadyInitialized)));
+}
+
+/// Mock dispute_resolution contract: records registrations and delivers
+/// outcomes through the escrow's `execute_outcome` callback.
+#[contract]
+pub struct MockDisputeResolution;
+
+#[contractimpl]
+impl MockDisputeResolution {
+    pub fn register_escrow(
+        env: Env,
+        escrow_contract: Address,
+        escrow_id: BytesN<32>,
+        agreement_id: soroban_sdk::String,
+        caller: Address,
+        _depositor: Address,
+        _beneficiary: Address,
+    ) {
+        escrow_contract.require_auth();
+        caller.require_auth();
+        env.storage()
+            .instance()
+            .set(&agreement_id, &(escrow_contract, escrow_id));
+    }
+
+    pub fn resolve(env: Env, agreement_id: soroban_sdk::String, outcome: DisputeOutcome) {
+        let (escrow_contract, escrow_id): (Address, BytesN<32>) =
+            env.storage().instance().get(&agreement_id).unwrap();
+        EscrowContractClient::new(&env, &escrow_contract).execute_outcome(&escrow_id, &outcome);
+    }
+}
+
+#[test]
+fn test_delegated_arbitration_outcome_executed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, _, token_address) = setup_test(&env);
+    let panel = env.register(MockDisputeResolution, ());
+    let panel_client = MockDisputeResolutionClient::new(&env, &panel);
+    let amount = 1000i128;
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &panel,
+        &amount,
+        &token_address,
+    );
+
+    let agreement_id = soroban_sdk::String::from_str(&env, "agreement_001");
+    client.delegate_arbitration(&escrow_id, &beneficiary, &agreement_id);
+    assert_eq!(
+        client.get_arbitration(&escrow_id),
+        Some(agreement_id.clone())
+    );
+
+    let reason = soroban_sdk::String::from_str(&env, "Unpaid damages");
+    client.initiate_dispute(&escrow_id, &beneficiary, &reason);
+
+    panel_client.resolve(&agreement_id, &DisputeOutcome::FavorLandlord);
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.dispute_reason, None);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&beneficiary), amount);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_execute_outcome_requires_delegation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1000);
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+
+    let result = client.try_execute_outcome(&escrow_id, &DisputeOutcome::FavorTenant);
+    assert_eq!(result, Err(Ok(EscrowError::ArbitrationNotDelegated)));
+}
+
+#[test]
+fn test_delegate_arbitration_only_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, _, token_address) = setup_test(&env);
+    let panel = env.register(MockDisputeResolution, ());
+    let escrow_id = client.create(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &panel,
+        &1000,
+        &token_address,
+    );
+
+    let agreement_id = soroban_sdk::String::from_str(&env, "agreement_001");
+    client.delegate_arbitration(&escrow_id, &depositor, &agreement_id);
+
+    let result = client.try_delegate_arbitration(&escrow_id, &beneficiary, &agreement_id);
+    assert_eq!(result, Err(Ok(EscrowError::ArbitrationAlreadyDelegated)));
+}
+
+#[test]
+fn test_delegate_arbitration_rejects_other_agreement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, _, token_address) = setup_test(&env);
+    let panel = env.register(MockDisputeResolution, ());
+    let escrow_id = client.create(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &panel,
+        &1000,
+        &token_address,
+    );
+
+    let other_agreement = soroban_sdk::String::from_str(&env, "agreement_002");
+    let result = client.try_delegate_arbitration(&escrow_id, &depositor, &other_agreement);
+    assert_eq!(result, Err(Ok(EscrowError::AgreementMismatch)));
+}
+
+#[test]
+fn test_execute_outcome_requires_dispute() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, _, token_address) = setup_test(&env);
+    let panel = env.register(MockDisputeResolution, ());
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1000);
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &panel,
+        &1000,
+        &token_address,
+    );
+    client.delegate_arbitration(&escrow_id, &depositor, &agreement_id(&env));
+
+    // A funded escrow nobody has disputed cannot be settled by the panel
+    let result = client.try_execute_outcome(&escrow_id, &DisputeOutcome::FavorTenant);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidState)));
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Funded);
+}
+
+#[test]
+fn test_top_up_increases_amount_and_records_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1500);
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+
+    client.top_up(&escrow_id, &depositor, &500);
+
+    assert_eq!(client.get_escrow(&escrow_id).amount, 1500);
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&client.address), 1500);
+
+    let history = client.get_amount_history(&escrow_id);
+    assert_eq!(history.len(), 1);
+    let change = history.get(0).unwrap();
+    assert_eq!(change.previous_amount, 1000);
+    assert_eq!(change.new_amount, 1500);
+    assert_eq!(change.changed_by, depositor);
+    assert_eq!(change.kind, AmountChangeKind::TopUp);
+
+    // Release pays out the topped-up amount
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+    client.approve_release(&escrow_id, &arbiter, &beneficiary);
+    assert_eq!(token_client.balance(&beneficiary), 1500);
+}
+
+#[test]
+fn test_top_up_rejected_for_non_depositor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1000);
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+
+    let result = client.try_top_up(&escrow_id, &beneficiary, &500);
+    assert_eq!(result, Err(Ok(EscrowError::NotAuthorized)));
+}
+
+#[test]
+fn test_adjust_amount_returns_excess() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1000);
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+
+    // Pending approvals are reset by the adjustment
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+
+    client.adjust_amount(&escrow_id, &depositor, &700);
+
+    assert_eq!(client.get_escrow(&escrow_id).amount, 700);
+    assert_eq!(client.get_approval_count(&escrow_id, &beneficiary), 0);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&depositor), 300);
+    assert_eq!(token_client.balance(&client.address), 700);
+
+    let change = client.get_amount_history(&escrow_id).get(0).unwrap();
+    assert_eq!(change.kind, AmountChangeKind::Adjustment);
+    assert_eq!(change.new_amount, 700);
+}
+
+#[test]
+fn test_adjust_amount_increase_pulls_from_depositor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1200);
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+
+    // The beneficiary proposes the increase; the depositor co-signs
+    client.adjust_amount(&escrow_id, &beneficiary, &1200);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&depositor), 0);
+    assert_eq!(token_client.balance(&client.address), 1200);
+
+    let change = client.get_amount_history(&escrow_id).get(0).unwrap();
+    assert_eq!(change.changed_by, beneficiary);
+}
+
+#[test]
+fn test_adjust_amount_rejects_unchanged_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let escrow_id = client.create(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+
+    let result = client.try_adjust_amount(&escrow_id, &depositor, &1000);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidAmount)));
+
+    let result = client.try_adjust_amount(&escrow_id, &depositor, &0);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidAmount)));
+
+    let result = client.try_adjust_amount(&escrow_id, &arbiter, &800);
+    assert_eq!(result, Err(Ok(EscrowError::NotAuthorized)));
+}
+
+#[test]
+#[should_panic]
+fn test_adjust_amount_requires_both_parties() {
+    let env = Env::default();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let escrow_id = client.create(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+
+    client
+        .mock_auths(&[MockAuth {
+            address: &depositor,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "adjust_amount",
+                args: (escrow_id.clone(), depositor.clone(), 800i128).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .adjust_amount(&escrow_id, &depositor, &800);
+}
+
+#[test]
+fn test_adjust_amount_cannot_return_vaulted_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let vault = setup_vault(&env, &client, &token_address);
+
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1200);
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    client.deposit_to_vault(&escrow_id, &depositor, &vault);
+    client.top_up(&escrow_id, &depositor, &200);
+
+    // Only the 200 top-up is held outside the vault
+    let result = client.try_adjust_amount(&escrow_id, &depositor, &700);
+    assert_eq!(result, Err(Ok(EscrowError::InsufficientFunds)));
+
+    client.adjust_amount(&escrow_id, &depositor, &1000);
+    assert_eq!(
+        TokenClient::new(&env, &token_address).balance(&depositor),
+        200
+    );
+
+    // Settlement redeems the vault position
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+    client.approve_release(&escrow_id, &arbiter, &beneficiary);
+    assert_eq!(
+        TokenClient::new(&env, &token_address).balance(&beneficiary),
+        1000
+    );
+}
+
+#[test]
+fn test_dispute_fee_losing_initiator_pays() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let token_admin = TokenAdminClient::new(&env, &token_address);
+    token_admin.mint(&depositor, &1100);
+    token_admin.mint(&beneficiary, &100);
+
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    client.set_dispute_fee(&escrow_id, &100);
+    client.set_arbiter_fee(&escrow_id, &arbiter, &4000);
+
+    let reason = soroban_sdk::String::from_str(&env, "Damages");
+    client.initiate_dispute(&escrow_id, &beneficiary, &reason);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&beneficiary), 0);
+    assert_eq!(token_client.balance(&client.address), 1100);
+
+    // The dispute cannot be resolved until the respondent matches the fee
+    let result = client.try_resolve_dispute(&escrow_id, &arbiter, &depositor);
+    assert_eq!(result, Err(Ok(EscrowError::DisputeFeeNotPosted)));
+    client.post_dispute_fee(&escrow_id, &depositor);
+
+    // Beneficiary loses: arbiter takes 40%, the rest goes to the depositor
+    client.resolve_dispute(&escrow_id, &arbiter, &depositor);
+
+    assert_eq!(token_client.balance(&arbiter), 40);
+    assert_eq!(token_client.balance(&depositor), 1000 + 100 + 60);
+    assert_eq!(token_client.balance(&beneficiary), 0);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_dispute_fee_split_outcome() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let token_admin = TokenAdminClient::new(&env, &token_address);
+    token_admin.mint(&depositor, &1100);
+    token_admin.mint(&beneficiary, &100);
+
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    client.set_dispute_fee(&escrow_id, &100);
+    client.set_arbiter_fee(&escrow_id, &arbiter, &2000);
+
+    let reason = soroban_sdk::String::from_str(&env, "Partial damages");
+    client.initiate_dispute(&escrow_id, &depositor, &reason);
+    client.post_dispute_fee(&escrow_id, &beneficiary);
+
+    // 300 of the deposit goes to the beneficiary, so the depositor forfeits
+    // 30% of its fee and the beneficiary 70% of its own; 20% of each
+    // forfeit goes to the arbiter and the rest to the other side
+    client.resolve_dispute_split(&escrow_id, &arbiter, &300);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&arbiter), 6 + 14);
+    assert_eq!(token_client.balance(&beneficiary), 300 + 30 + 24);
+    assert_eq!(token_client.balance(&depositor), 700 + 70 + 56);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Released);
+    assert!(client.get_dispute_fee(&escrow_id).is_some());
+}
+
+#[test]
+fn test_dispute_fee_winning_initiator_refunded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let token_admin = TokenAdminClient::new(&env, &token_address);
+    token_admin.mint(&depositor, &1100);
+    token_admin.mint(&beneficiary, &100);
+
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    client.set_dispute_fee(&escrow_id, &100);
+    client.set_arbiter_fee(&escrow_id, &arbiter, &4000);
+
+    let reason = soroban_sdk::String::from_str(&env, "Damages");
+    client.initiate_dispute(&escrow_id, &beneficiary, &reason);
+    client.post_dispute_fee(&escrow_id, &depositor);
+
+    let result = client.try_post_dispute_fee(&escrow_id, &depositor);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidState)));
+    let result = client.try_post_dispute_fee(&escrow_id, &beneficiary);
+    assert_eq!(result, Err(Ok(EscrowError::NotAuthorized)));
+
+    // Beneficiary wins: its fee comes back, the depositor's pays the arbiter
+    client.resolve_dispute(&escrow_id, &arbiter, &beneficiary);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&arbiter), 40);
+    assert_eq!(token_client.balance(&beneficiary), 1000 + 100 + 60);
+    assert_eq!(token_client.balance(&depositor), 0);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_delegated_dispute_fee_share_paid_to_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, _, token_address) = setup_test(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let panel = env.register(MockDisputeResolution, ());
+    let panel_client = MockDisputeResolutionClient::new(&env, &panel);
+    let token_admin = TokenAdminClient::new(&env, &token_address);
+    token_admin.mint(&depositor, &1100);
+    token_admin.mint(&beneficiary, &100);
+
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &panel,
+        &1000,
+        &token_address,
+    );
+    client.set_dispute_fee(&escrow_id, &100);
+    client.set_arbiter_fee(&escrow_id, &admin, &5000);
+    client.delegate_arbitration(&escrow_id, &depositor, &agreement_id(&env));
+
+    let reason = soroban_sdk::String::from_str(&env, "Deposit withheld");
+    client.initiate_dispute(&escrow_id, &depositor, &reason);
+    let result = client.try_execute_outcome(&escrow_id, &DisputeOutcome::FavorLandlord);
+    assert_eq!(result, Err(Ok(EscrowError::DisputeFeeNotPosted)));
+    client.post_dispute_fee(&escrow_id, &beneficiary);
+    panel_client.resolve(&agreement_id(&env), &DisputeOutcome::FavorLandlord);
+
+    // The panel contract cannot spend its share, so the admin receives it
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&panel), 0);
+    assert_eq!(token_client.balance(&admin), 50);
+    assert_eq!(token_client.balance(&beneficiary), 1000 + 100 + 50);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_dispute_fee_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1000);
+
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    assert_eq!(client.get_dispute_fee(&escrow_id), None);
+
+    let result = client.try_set_dispute_fee(&escrow_id, &-1);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidDisputeFee)));
+    let result = client.try_set_arbiter_fee(&escrow_id, &arbiter, &10_001);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidDisputeFee)));
+
+    // The parties cannot set the arbiter's share themselves
+    let result = client.try_set_arbiter_fee(&escrow_id, &depositor, &5000);
+    assert_eq!(result, Err(Ok(EscrowError::NotAuthorized)));
+
+    let reason = soroban_sdk::String::from_str(&env, "Damages");
+    client.initiate_dispute(&escrow_id, &depositor, &reason);
+
+    let result = client.try_set_dispute_fee(&escrow_id, &100);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidState)));
+    let result = client.try_set_arbiter_fee(&escrow_id, &arbiter, &1000);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidState)));
+    let result = client.try_resolve_dispute_split(&escrow_id, &arbiter, &1001);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidRelease)));
+}
+
+#[test]
+fn test_evidence_log_paginated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1000);
+
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    let reason = soroban_sdk::String::from_str(&env, "Damages");
+    client.initiate_dispute(&escrow_id, &beneficiary, &reason);
+
+    let photos = soroban_sdk::String::from_str(&env, "move-out photos");
+    for i in 0..3u8 {
+        let submitter = if i % 2 == 0 { &beneficiary } else { &depositor };
+        let index = client.submit_evidence(
+            &escrow_id,
+            submitter,
+            &BytesN::from_array(&env, &[i; 32]),
+            &Some(photos.clone()),
+        );
+        assert_eq!(index, i as u32);
+    }
+    client.submit_evidence(
+        &escrow_id,
+        &depositor,
+        &BytesN::from_array(&env, &[9; 32]),
+        &None,
+    );
+    assert_eq!(client.get_evidence_count(&escrow_id), 4);
+
+    let page = client.get_evidence(&escrow_id, &0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().submitter, beneficiary);
+    assert_eq!(page.get(1).unwrap().submitter, depositor);
+
+    let page = client.get_evidence(&escrow_id, &2, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(1).unwrap().label, None);
+    assert_eq!(
+        page.get(1).unwrap().content_hash,
+        BytesN::from_array(&env, &[9; 32])
+    );
+    assert_eq!(client.get_evidence(&escrow_id, &4, &10).len(), 0);
+
+    // The log is closed once the arbiter resolves the dispute
+    client.resolve_dispute(&escrow_id, &arbiter, &beneficiary);
+    let result = client.try_submit_evidence(
+        &escrow_id,
+        &depositor,
+        &BytesN::from_array(&env, &[1; 32]),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(EscrowError::InvalidState)));
+    assert_eq!(client.get_evidence_count(&escrow_id), 4);
+}
+
+#[test]
+fn test_evidence_rejected_from_arbiter_or_undisputed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1000);
+
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    let hash = BytesN::from_array(&env, &[1; 32]);
+
+    let result = client.try_submit_evidence(&escrow_id, &depositor, &hash, &None);
+    assert_eq!(result, Err(Ok(EscrowError::InvalidState)));
+
+    let reason = soroban_sdk::String::from_str(&env, "Damages");
+    client.initiate_dispute(&escrow_id, &depositor, &reason);
+
+    let result = client.try_submit_evidence(&escrow_id, &arbiter, &hash, &None);
+    assert_eq!(result, Err(Ok(EscrowError::NotAuthorized)));
+}
+
+#[test]
+fn test_evidence_capped_per_party() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &1000);
+
+    let escrow_id = client.create_and_fund(
+        &agreement_id(&env),
+        &depositor,
+        &beneficiary,
+        &arbiter,
+        &1000,
+        &token_address,
+    );
+    let reason = soroban_sdk::String::from_str(&env, "Damages");
+    client.initiate_dispute(&escrow_id, &beneficiary, &reason);
+
+    let hash = BytesN::from_array(&env, &[1; 32]);
+    for _ in 0..crate::dispute::MAX_EVIDENCE_PER_PARTY {
+        client.submit_evidence(&escrow_id, &beneficiary, &hash, &None);
+    }
+
+    let result = client.try_submit_evidence(&escrow_id, &beneficiary, &hash, &None);
+    assert_eq!(result, Err(Ok(EscrowError::EvidenceLimitReached)));
+
+    // The other party keeps its own allowance
+    let index = client.submit_evidence(&escrow_id, &depositor, &hash, &None);
+    assert_eq!(index, crate::dispute::MAX_EVIDENCE_PER_PARTY);
+}
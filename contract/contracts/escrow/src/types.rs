@@ -15,14 +15,19 @@ pub enum EscrowStatus {
     Refunded = 3,
     /// Under dispute, awaiting admin resolution
     Disputed = 4,
+    /// Withdrawn by the depositor before it was funded
+    Cancelled = 5,
 }
 
 /// Represents a security deposit escrow managed by 2-of-3 multi-sig.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[contracttype]
 pub struct Escrow {
-    /// Unique identifier for the escrow (hash of agreement_id)
+    /// Unique identifier for the escrow (hash of the creation parameters and
+    /// the ledger timestamp)
     pub id: BytesN<32>,
+    /// Agreement whose security deposit the escrow holds
+    pub agreement_id: String,
     /// The party depositing funds (tenant)
     pub depositor: Address,
     /// The party who benefits from the deposit (landlord)
@@ -53,6 +58,97 @@ pub struct ReleaseApproval {
     pub timestamp: u64,
 }
 
+/// Kind of change applied to an escrow amount.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum AmountChangeKind {
+    /// Depositor added funds
+    TopUp,
+    /// Both primary parties agreed on a new amount
+    Adjustment,
+}
+
+/// Records a change to the amount held by an escrow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct AmountChange {
+    /// Amount before the change
+    pub previous_amount: i128,
+    /// Amount after the change
+    pub new_amount: i128,
+    /// Party that initiated the change
+    pub changed_by: Address,
+    /// Whether this was a top-up or a mutual adjustment
+    pub kind: AmountChangeKind,
+    /// Timestamp of the change
+    pub timestamp: u64,
+}
+
+/// Outcome delivered by a delegated dispute_resolution contract.
+/// Mirrors `dispute_resolution::DisputeOutcome` so the callback decodes directly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum DisputeOutcome {
+    /// Release the deposit to the beneficiary (landlord)
+    FavorLandlord,
+    /// Return the deposit to the depositor (tenant)
+    FavorTenant,
+}
+
+/// Position held by an escrow in an external yield vault.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct VaultPosition {
+    /// The allowlisted vault contract holding the funds
+    pub vault: Address,
+    /// Escrow's share of this contract's total vault balance
+    pub shares: i128,
+    /// Amount originally deposited into the vault
+    pub principal: i128,
+    /// Timestamp of the vault deposit
+    pub deposited_at: u64,
+}
+
+/// Dispute fee terms agreed for an escrow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct DisputeFeeConfig {
+    /// Fee posted by each side of a dispute, set by the primary parties
+    pub fee: i128,
+    /// Share of a forfeited fee paid to the arbiter, or to the admin when
+    /// arbitration is delegated; set by the arbiter or admin (basis points)
+    pub arbiter_share_bps: u32,
+}
+
+/// Dispute fee posted for an open dispute.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct PostedDisputeFee {
+    /// Party that initiated the dispute and posted the fee
+    pub payer: Address,
+    /// Amount posted by the initiator
+    pub amount: i128,
+    /// Amount posted by the respondent, 0 until they post; resolution waits
+    /// for it
+    pub respondent_amount: i128,
+    /// Arbiter share in effect when the fee was posted (basis points)
+    pub arbiter_share_bps: u32,
+}
+
+/// Entry in the evidence log of a disputed escrow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct EvidenceEntry {
+    /// Primary party that submitted the evidence
+    pub submitter: Address,
+    /// Hash of the off-chain evidence content
+    pub content_hash: BytesN<32>,
+    /// Optional short description of the evidence
+    pub label: Option<String>,
+    /// Timestamp of submission
+    pub timestamp: u64,
+}
+
 /// Storage key variants for persistent storage.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -69,4 +165,26 @@ pub enum DataKey {
     ApprovalCount(BytesN<32>, Address),
     /// Per-signer-per-target flag: DataKey::SignerApproved(escrow_id, signer, release_to) => bool
     SignerApproved(BytesN<32>, Address, Address),
+    /// Contract admin who manages the vault allowlist
+    Admin,
+    /// Vault allowlist flag: DataKey::AllowedVault(vault) => bool
+    AllowedVault(Address),
+    /// Vault position of an escrow: DataKey::VaultPosition(escrow_id) => VaultPosition
+    VaultPosition(BytesN<32>),
+    /// Total shares issued by this contract per vault: DataKey::VaultShares(vault) => i128
+    VaultShares(Address),
+    /// Agreement whose dispute panel arbitrates an escrow: DataKey::Arbitration(escrow_id) => String
+    Arbitration(BytesN<32>),
+    /// Amount change history: DataKey::AmountHistory(escrow_id) => Vec<AmountChange>
+    AmountHistory(BytesN<32>),
+    /// Dispute fee terms: DataKey::DisputeFee(escrow_id) => DisputeFeeConfig
+    DisputeFee(BytesN<32>),
+    /// Fee posted for an open dispute: DataKey::PostedDisputeFee(escrow_id) => PostedDisputeFee
+    PostedDisputeFee(BytesN<32>),
+    /// Dispute evidence entry: DataKey::Evidence(escrow_id, index) => EvidenceEntry
+    Evidence(BytesN<32>, u32),
+    /// Number of evidence entries: DataKey::EvidenceCount(escrow_id) => u32
+    EvidenceCount(BytesN<32>),
+    /// Evidence entries per party: DataKey::PartyEvidenceCount(escrow_id, party) => u32
+    PartyEvidenceCount(BytesN<32>, Address),
 }
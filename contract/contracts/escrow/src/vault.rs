@@ -0,0 +1,205 @@
+//! Yield vault integration for the Escrow contract.
+//! Lets a depositor park funded escrows in an admin-allowlisted vault and
+//! attributes any accrued yield to the depositor when the escrow settles.
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::{contractclient, token, vec, Address, BytesN, Env, IntoVal, Symbol};
+
+use crate::access::AccessControl;
+use crate::errors::EscrowError;
+use crate::events;
+use crate::storage::EscrowStorage;
+use crate::types::{Escrow, EscrowStatus, VaultPosition};
+
+/// Interface a vault contract must expose to hold escrowed funds.
+///
+/// The vault holds a single underlying token, which must match the token of
+/// every escrow deposited into it.
+#[contractclient(name = "VaultClient")]
+pub trait VaultInterface {
+    /// Pull `amount` of the underlying token from `from` into the vault.
+    fn deposit(env: Env, from: Address, amount: i128);
+
+    /// Send `amount` of the underlying token from `to`'s balance back to `to`.
+    fn withdraw(env: Env, to: Address, amount: i128);
+
+    /// Current underlying balance held for `owner`, including accrued yield.
+    fn balance(env: Env, owner: Address) -> i128;
+}
+
+/// Vault deposit and redemption handling.
+pub struct VaultHandler;
+
+impl VaultHandler {
+    /// Move a funded escrow's deposit into an allowlisted vault.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Funded
+    /// - Caller must be depositor
+    /// - Vault must be allowlisted
+    /// - Escrow must not already hold a vault position
+    ///
+    /// EFFECTS:
+    /// - Record the escrow's share of the vault balance
+    ///
+    /// INTERACTIONS:
+    /// - Vault pulls the deposit from the escrow contract
+    pub fn deposit(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        vault: Address,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        // Verify caller is depositor
+        AccessControl::is_depositor(&escrow, &caller)?;
+
+        // Verify escrow is in Funded state
+        if escrow.status != EscrowStatus::Funded {
+            return Err(EscrowError::InvalidState);
+        }
+
+        if !EscrowStorage::is_vault_allowed(&env, &vault) {
+            return Err(EscrowError::VaultNotAllowed);
+        }
+
+        if EscrowStorage::get_vault_position(&env, &escrow_id).is_some() {
+            return Err(EscrowError::VaultAlreadyActive);
+        }
+
+        // Authorize the vault deposit
+        caller.require_auth();
+
+        // EFFECTS: Issue shares against the vault balance before this deposit
+        let vault_client = VaultClient::new(&env, &vault);
+        let total_assets = vault_client.balance(&env.current_contract_address());
+        let total_shares = EscrowStorage::get_vault_shares(&env, &vault);
+        let shares = if total_shares == 0 || total_assets == 0 {
+            escrow.amount
+        } else {
+            escrow.amount * total_shares / total_assets
+        };
+
+        let position = VaultPosition {
+            vault: vault.clone(),
+            shares,
+            principal: escrow.amount,
+            deposited_at: env.ledger().timestamp(),
+        };
+        EscrowStorage::set_vault_position(&env, &escrow_id, &position);
+        EscrowStorage::set_vault_shares(&env, &vault, total_shares + shares);
+
+        // INTERACTIONS: Allow the vault to pull exactly this deposit, then deposit
+        let contract = env.current_contract_address();
+        env.authorize_as_current_contract(vec![
+            &env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: escrow.token.clone(),
+                    fn_name: Symbol::new(&env, "transfer"),
+                    args: (contract.clone(), vault.clone(), escrow.amount).into_val(&env),
+                },
+                sub_invocations: vec![&env],
+            }),
+        ]);
+        vault_client.deposit(&contract, &escrow.amount);
+
+        events::vault_deposited(&env, &escrow, vault, shares);
+
+        Ok(())
+    }
+
+    /// Current value of an escrow's vault position, or None if it holds none.
+    pub fn position_value(env: &Env, escrow_id: &BytesN<32>) -> Option<i128> {
+        let position = EscrowStorage::get_vault_position(env, escrow_id)?;
+        let total_shares = EscrowStorage::get_vault_shares(env, &position.vault);
+        if total_shares == 0 {
+            return Some(0);
+        }
+        let total_assets =
+            VaultClient::new(env, &position.vault).balance(&env.current_contract_address());
+        Some(position.shares * total_assets / total_shares)
+    }
+
+    /// Pay out a settled escrow to `release_to`.
+    ///
+    /// Returns the yield paid to the depositor.
+    pub fn payout(env: &Env, escrow: &Escrow, release_to: &Address) -> i128 {
+        let beneficiary_amount = if *release_to == escrow.beneficiary {
+            escrow.amount
+        } else {
+            0
+        };
+        Self::payout_split(env, escrow, beneficiary_amount)
+    }
+
+    /// Pay out a settled escrow, sending `beneficiary_amount` to the
+    /// beneficiary and the rest of the principal to the depositor.
+    ///
+    /// If the deposit sits in a vault it is redeemed first; any top-ups made
+    /// after the vault deposit are held directly by this contract. Any yield
+    /// goes to the depositor. If the vault lost value, the beneficiary is paid
+    /// first from whatever is available.
+    ///
+    /// Returns the yield paid to the depositor.
+    pub fn payout_split(env: &Env, escrow: &Escrow, beneficiary_amount: i128) -> i128 {
+        let contract = env.current_contract_address();
+        let token_client = token::Client::new(env, &escrow.token);
+
+        let Some(position) = EscrowStorage::get_vault_position(env, &escrow.id) else {
+            Self::distribute(env, escrow, escrow.amount, beneficiary_amount);
+            return 0;
+        };
+
+        // EFFECTS: Burn the escrow's shares before redeeming
+        let redeemed = Self::position_value(env, &escrow.id).unwrap_or(0);
+        let total_shares = EscrowStorage::get_vault_shares(env, &position.vault);
+        EscrowStorage::set_vault_shares(env, &position.vault, total_shares - position.shares);
+        EscrowStorage::remove_vault_position(env, &escrow.id);
+
+        // INTERACTIONS: Redeem from the vault, then distribute
+        if redeemed > 0 {
+            VaultClient::new(env, &position.vault).withdraw(&contract, &redeemed);
+        }
+
+        let held = escrow.amount - position.principal;
+        let available = redeemed + held;
+        let principal = available.min(escrow.amount);
+        let accrued = available - principal;
+
+        Self::distribute(env, escrow, principal, beneficiary_amount);
+        if accrued > 0 {
+            token_client.transfer(&contract, &escrow.depositor, &accrued);
+        }
+
+        events::vault_redeemed(env, escrow, position.vault, redeemed, accrued);
+
+        accrued
+    }
+
+    /// Split `available` principal between beneficiary and depositor.
+    fn distribute(env: &Env, escrow: &Escrow, available: i128, beneficiary_amount: i128) {
+        let contract = env.current_contract_address();
+        let token_client = token::Client::new(env, &escrow.token);
+
+        let to_beneficiary = beneficiary_amount.min(available);
+        let to_depositor = available - to_beneficiary;
+
+        if to_beneficiary > 0 {
+            token_client.transfer(&contract, &escrow.beneficiary, &to_beneficiary);
+        }
+        if to_depositor > 0 {
+            token_client.transfer(&contract, &escrow.depositor, &to_depositor);
+        }
+    }
+
+    /// Amount of an escrow's funds held directly by this contract rather than
+    /// in a vault.
+    pub fn held_amount(env: &Env, escrow: &Escrow) -> i128 {
+        match EscrowStorage::get_vault_position(env, &escrow.id) {
+            Some(position) => escrow.amount - position.principal,
+            None => escrow.amount,
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Autopay mandates for pull-based recurring rent.
+//!
+//! A tenant registers a mandate for an agreement, granting the payment
+//! contract a token allowance recorded on the mandate. Once rent falls due,
+//! any keeper can execute it and the rent is pulled from the tenant with
+//! `transfer_from`. The same keeper run applies prepaid credit to periods
+//! that fell due on agreements without a mandate.
+use soroban_sdk::{token, Address, Env, String, Vec};
+
+use crate::errors::PaymentError;
+use crate::events;
+use crate::payment_impl::{
+    amount_due, credit_due, get_rent_balance, load_agreement, process_payment, FundsSource,
+};
+use crate::storage::DataKey;
+use crate::stream::is_streaming;
+use crate::types::{AgreementStatus, AutopayMandate, RentAgreement};
+
+/// Load the autopay mandate of an agreement
+pub fn get_autopay(env: &Env, agreement_id: &String) -> Option<AutopayMandate> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Autopay(agreement_id.clone()))
+}
+
+/// Register (or replace) the tenant's autopay mandate for an agreement and
+/// approve this contract to pull up to `allowance` until `expiration_ledger`
+pub fn register_autopay(
+    env: &Env,
+    tenant: Address,
+    agreement_id: String,
+    max_amount_per_period: i128,
+    allowance: i128,
+    expiration_ledger: u32,
+) -> Result<AutopayMandate, PaymentError> {
+    tenant.require_auth();
+
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    if agreement.status != AgreementStatus::Active {
+        return Err(PaymentError::AgreementNotActive);
+    }
+
+    if tenant != agreement.tenant {
+        return Err(PaymentError::NotTenant);
+    }
+
+    if max_amount_per_period <= 0 || allowance <= 0 {
+        return Err(PaymentError::InvalidAmount);
+    }
+
+    if expiration_ledger < env.ledger().sequence() {
+        return Err(PaymentError::AllowanceExpired);
+    }
+
+    if is_streaming(env, &agreement_id) {
+        return Err(PaymentError::StreamActive);
+    }
+
+    let mandate = AutopayMandate {
+        agreement_id: agreement_id.clone(),
+        tenant,
+        max_amount_per_period,
+        allowance,
+        allowance_expiration_ledger: expiration_ledger,
+        created_at: env.ledger().timestamp(),
+        last_executed_at: None,
+        payments_executed: 0,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Autopay(agreement_id), &mandate);
+
+    token::Client::new(env, &agreement.payment_token).approve(
+        &mandate.tenant,
+        &env.current_contract_address(),
+        &allowance,
+        &expiration_ledger,
+    );
+
+    events::autopay_registered(env, &mandate);
+
+    Ok(mandate)
+}
+
+/// Cancel the tenant's autopay mandate for an agreement and revoke the
+/// allowance granted with it
+pub fn cancel_autopay(
+    env: &Env,
+    tenant: Address,
+    agreement_id: String,
+) -> Result<(), PaymentError> {
+    tenant.require_auth();
+
+    let mandate = get_autopay(env, &agreement_id).ok_or(PaymentError::AutopayNotFound)?;
+
+    if tenant != mandate.tenant {
+        return Err(PaymentError::NotTenant);
+    }
+
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Autopay(agreement_id));
+
+    token::Client::new(env, &agreement.payment_token).approve(
+        &mandate.tenant,
+        &env.current_contract_address(),
+        &0,
+        &env.ledger().sequence(),
+    );
+
+    events::autopay_cancelled(env, &mandate);
+
+    Ok(())
+}
+
+/// Execute every due mandate among `agreement_ids`.
+///
+/// Credit is applied first and only the shortfall of the oldest outstanding
+/// period is pulled. Agreements without a mandate have their credit applied
+/// to any periods that fell due, so prepaid rent settles on schedule.
+/// Agreements not yet due, whose shortfall exceeds the mandate's per-period
+/// cap, whose mandate allowance has expired, or whose tenant lacks the
+/// balance or allowance are skipped.
+/// The rent is pulled with a fallible call before it is applied, so a pull
+/// that fails skips that mandate instead of reverting the batch. Returns the
+/// IDs of the agreements that were paid.
+pub fn execute_due_payments(env: &Env, agreement_ids: Vec<String>) -> Vec<String> {
+    let mut executed = Vec::new(env);
+    let contract = env.current_contract_address();
+
+    for agreement_id in agreement_ids.iter() {
+        let Ok(agreement) = load_agreement(env, &agreement_id) else {
+            continue;
+        };
+        let Some(mut mandate) = get_autopay(env, &agreement_id) else {
+            if credit_due(env, &agreement)
+                && process_payment(
+                    env,
+                    &agreement,
+                    &agreement.tenant,
+                    0,
+                    FundsSource::Held,
+                    None,
+                )
+                .is_ok()
+            {
+                executed.push_back(agreement_id);
+            }
+            continue;
+        };
+        let Some(amount) = executable_amount(env, &mandate, &agreement) else {
+            continue;
+        };
+
+        let token_client = token::Client::new(env, &agreement.payment_token);
+        if amount > 0
+            && !matches!(
+                token_client.try_transfer_from(&contract, &mandate.tenant, &contract, &amount),
+                Ok(Ok(()))
+            )
+        {
+            continue;
+        }
+
+        let paid = process_payment(
+            env,
+            &agreement,
+            &mandate.tenant,
+            amount,
+            FundsSource::Held,
+            None,
+        );
+        if paid.is_err() {
+            // Nothing was applied; hand the pulled rent back
+            if amount > 0 {
+                token_client.transfer(&contract, &mandate.tenant, &amount);
+            }
+            continue;
+        }
+
+        mandate.last_executed_at = Some(env.ledger().timestamp());
+        mandate.payments_executed += 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Autopay(agreement_id.clone()), &mandate);
+        executed.push_back(agreement_id);
+    }
+
+    executed
+}
+
+/// Amount to pull if the mandate can be executed against the agreement now
+fn executable_amount(
+    env: &Env,
+    mandate: &AutopayMandate,
+    agreement: &RentAgreement,
+) -> Option<i128> {
+    if agreement.status != AgreementStatus::Active
+        || mandate.tenant != agreement.tenant
+        || env.ledger().sequence() > mandate.allowance_expiration_ledger
+        || env.ledger().timestamp() < agreement.next_payment_due
+        || is_streaming(env, &agreement.agreement_id)
+    {
+        return None;
+    }
+
+    let balance = get_rent_balance(env, &agreement.agreement_id);
+    let amount = amount_due(agreement, &balance).max(0);
+    if amount == 0 {
+        // Credit alone settles the period
+        return Some(0);
+    }
+    if amount > mandate.max_amount_per_period {
+        return None;
+    }
+
+    let token_client = token::Client::new(env, &agreement.payment_token);
+    let funded = token_client.allowance(&mandate.tenant, &env.current_contract_address()) >= amount
+        && token_client.balance(&mandate.tenant) >= amount;
+    funded.then_some(amount)
+}
@@ -21,4 +21,38 @@ pub enum PaymentError {
     PaymentNotDue = 18,
     /// Invalid amount provided
     InvalidAmount = 5,
+    /// Contract has already been initialized
+    AlreadyInitialized = 19,
+    /// Contract has not been initialized
+    NotInitialized = 20,
+    /// Fee basis points exceed 10000
+    InvalidFeeConfig = 21,
+    /// No autopay mandate exists for the agreement
+    AutopayNotFound = 22,
+    /// Payment reference was already recorded for the agreement
+    DuplicatePaymentReference = 23,
+    /// Payment reference must not be empty
+    InvalidPaymentReference = 24,
+    /// Refund exceeds what is left to refund on the payment
+    RefundExceedsPayment = 25,
+    /// No rent stream exists for the agreement
+    StreamNotFound = 26,
+    /// Agreement is settled by an active rent stream
+    StreamActive = 27,
+    /// Rent stream has been stopped
+    StreamNotActive = 28,
+    /// Caller is neither the tenant nor the landlord
+    NotAgreementParty = 29,
+    /// Asset is not allowlisted for rent payments
+    AssetNotAllowed = 30,
+    /// No swap adapter has been configured
+    SwapAdapterNotSet = 31,
+    /// Swap needed more input than allowed or returned too little output
+    SlippageExceeded = 32,
+    /// Allowance expiration ledger has already passed
+    AllowanceExpired = 33,
+    /// Landlord leg was paid to the rent obligation contract and cannot be refunded
+    PayeeCannotRefund = 34,
+    /// Agreement has credit or a part-paid period that must be settled first
+    RentBalanceOutstanding = 35,
 }
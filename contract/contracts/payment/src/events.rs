@@ -0,0 +1,385 @@
+//! Event definitions for the Payment contract.
+use soroban_sdk::{contractevent, Address, Bytes, Env, String};
+
+use crate::types::{
+    AutopayMandate, FeeConfig, PaymentRecord, PaymentRefund, RentAgreement, RentStream,
+};
+
+/// Event emitted when the contract is initialized
+/// Topics: ["initialized", admin: Address]
+#[contractevent(topics = ["initialized"])]
+pub struct PaymentInitialized {
+    #[topic]
+    pub admin: Address,
+    pub chioma_contract: Address,
+    pub fee_bps: u32,
+    pub fee_collector: Address,
+}
+
+/// Event emitted when the platform fee configuration changes
+/// Topics: ["fee_updated", admin: Address]
+#[contractevent(topics = ["fee_updated"])]
+pub struct FeeConfigUpdated {
+    #[topic]
+    pub admin: Address,
+    pub old_fee_bps: u32,
+    pub new_fee_bps: u32,
+    pub old_collector: Address,
+    pub new_collector: Address,
+}
+
+/// Event emitted when a landlord's fee override is set or removed
+/// Topics: ["fee_override", admin: Address, landlord: Address]
+#[contractevent(topics = ["fee_override"])]
+pub struct LandlordFeeUpdated {
+    #[topic]
+    pub admin: Address,
+    #[topic]
+    pub landlord: Address,
+    pub fee_bps: Option<u32>,
+}
+
+/// Event emitted when the rent obligation contract is configured
+/// Topics: ["oblig_set", admin: Address]
+#[contractevent(topics = ["oblig_set"])]
+pub struct ObligationContractSet {
+    #[topic]
+    pub admin: Address,
+    pub obligation_contract: Address,
+}
+
+/// Event emitted when the swap adapter is configured
+/// Topics: ["swap_set", admin: Address]
+#[contractevent(topics = ["swap_set"])]
+pub struct SwapAdapterSet {
+    #[topic]
+    pub admin: Address,
+    pub adapter: Address,
+}
+
+/// Event emitted when an asset is added to or removed from the allowlist
+/// Topics: ["asset_allow", admin: Address, asset: Address]
+#[contractevent(topics = ["asset_allow"])]
+pub struct AssetAllowlistUpdated {
+    #[topic]
+    pub admin: Address,
+    #[topic]
+    pub asset: Address,
+    pub allowed: bool,
+}
+
+/// Event emitted when a payment in another asset is swapped into the
+/// agreement's payment token
+/// Topics: ["rent_swap", agreement_id: String]
+#[contractevent(topics = ["rent_swap"])]
+pub struct RentSwapped {
+    #[topic]
+    pub agreement_id: String,
+    pub tenant: Address,
+    pub token_in: Address,
+    pub amount_in: i128,
+    pub token_out: Address,
+    pub amount_out: i128,
+}
+
+/// Event emitted when rent is paid
+/// Topics: ["rent_paid", agreement_id: String]
+#[contractevent(topics = ["rent_paid"])]
+pub struct RentPaid {
+    #[topic]
+    pub agreement_id: String,
+    pub payment_number: u32,
+    pub tenant: Address,
+    pub amount: i128,
+    pub platform_amount: i128,
+    pub agent_amount: i128,
+    pub landlord_amount: i128,
+    pub credit_used: i128,
+    pub credit_added: i128,
+    pub reference: Option<Bytes>,
+    pub timestamp: u64,
+}
+
+/// Event emitted when a landlord refunds a rent payment
+/// Topics: ["refunded", agreement_id: String]
+#[contractevent(topics = ["refunded"])]
+pub struct PaymentRefunded {
+    #[topic]
+    pub agreement_id: String,
+    pub payment_number: u32,
+    pub payer: Address,
+    pub amount: i128,
+    pub from_credit: i128,
+    pub platform_amount: i128,
+    pub landlord_amount: i128,
+    pub agent_amount: i128,
+    pub periods_reopened: u32,
+    pub total_refunded: i128,
+}
+
+/// Event emitted when a tenant registers an autopay mandate
+/// Topics: ["autopay_on", agreement_id: String]
+#[contractevent(topics = ["autopay_on"])]
+pub struct AutopayRegistered {
+    #[topic]
+    pub agreement_id: String,
+    pub tenant: Address,
+    pub max_amount_per_period: i128,
+}
+
+/// Event emitted when a tenant cancels an autopay mandate
+/// Topics: ["autopay_off", agreement_id: String]
+#[contractevent(topics = ["autopay_off"])]
+pub struct AutopayCancelled {
+    #[topic]
+    pub agreement_id: String,
+    pub tenant: Address,
+    pub payments_executed: u32,
+}
+
+/// Event emitted when a tenant opens a rent stream
+/// Topics: ["stream_on", agreement_id: String]
+#[contractevent(topics = ["stream_on"])]
+pub struct StreamStarted {
+    #[topic]
+    pub agreement_id: String,
+    pub tenant: Address,
+    pub monthly_rent: i128,
+    pub start_time: u64,
+    pub deposit: i128,
+}
+
+/// Event emitted when a tenant adds to a rent stream's deposit
+/// Topics: ["stream_add", agreement_id: String]
+#[contractevent(topics = ["stream_add"])]
+pub struct StreamToppedUp {
+    #[topic]
+    pub agreement_id: String,
+    pub tenant: Address,
+    pub amount: i128,
+    pub total_deposited: i128,
+}
+
+/// Event emitted when accrued streaming rent is paid out
+/// Topics: ["stream_paid", agreement_id: String]
+#[contractevent(topics = ["stream_paid"])]
+pub struct StreamWithdrawn {
+    #[topic]
+    pub agreement_id: String,
+    pub amount: i128,
+    pub platform_amount: i128,
+    pub agent_amount: i128,
+    pub landlord_amount: i128,
+    pub total_withdrawn: i128,
+}
+
+/// Event emitted when a rent stream is stopped and settled
+/// Topics: ["stream_off", agreement_id: String]
+#[contractevent(topics = ["stream_off"])]
+pub struct StreamStopped {
+    #[topic]
+    pub agreement_id: String,
+    pub stopped_by: Address,
+    pub total_paid: i128,
+    pub refunded: i128,
+}
+
+/// Helper function to emit contract initialized event
+pub(crate) fn contract_initialized(
+    env: &Env,
+    admin: Address,
+    chioma_contract: Address,
+    config: &FeeConfig,
+) {
+    PaymentInitialized {
+        admin,
+        chioma_contract,
+        fee_bps: config.fee_bps,
+        fee_collector: config.collector.clone(),
+    }
+    .publish(env);
+}
+
+/// Helper function to emit fee config updated event
+pub(crate) fn fee_config_updated(
+    env: &Env,
+    admin: Address,
+    old_config: &FeeConfig,
+    new_config: &FeeConfig,
+) {
+    FeeConfigUpdated {
+        admin,
+        old_fee_bps: old_config.fee_bps,
+        new_fee_bps: new_config.fee_bps,
+        old_collector: old_config.collector.clone(),
+        new_collector: new_config.collector.clone(),
+    }
+    .publish(env);
+}
+
+/// Helper function to emit landlord fee updated event
+pub(crate) fn landlord_fee_updated(
+    env: &Env,
+    admin: Address,
+    landlord: Address,
+    fee_bps: Option<u32>,
+) {
+    LandlordFeeUpdated {
+        admin,
+        landlord,
+        fee_bps,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit obligation contract set event
+pub(crate) fn obligation_contract_set(env: &Env, admin: Address, obligation_contract: Address) {
+    ObligationContractSet {
+        admin,
+        obligation_contract,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit swap adapter set event
+pub(crate) fn swap_adapter_set(env: &Env, admin: Address, adapter: Address) {
+    SwapAdapterSet { admin, adapter }.publish(env);
+}
+
+/// Helper function to emit asset allowlist updated event
+pub(crate) fn asset_allowlist_updated(env: &Env, admin: Address, asset: Address, allowed: bool) {
+    AssetAllowlistUpdated {
+        admin,
+        asset,
+        allowed,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit rent swapped event
+pub(crate) fn rent_swapped(
+    env: &Env,
+    agreement: &RentAgreement,
+    token_in: Address,
+    amount_in: i128,
+    amount_out: i128,
+) {
+    RentSwapped {
+        agreement_id: agreement.agreement_id.clone(),
+        tenant: agreement.tenant.clone(),
+        token_in,
+        amount_in,
+        token_out: agreement.payment_token.clone(),
+        amount_out,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit rent paid event
+pub(crate) fn rent_paid(env: &Env, record: &PaymentRecord) {
+    RentPaid {
+        agreement_id: record.agreement_id.clone(),
+        payment_number: record.payment_number,
+        tenant: record.tenant.clone(),
+        amount: record.amount,
+        platform_amount: record.platform_amount,
+        agent_amount: record.agent_amount,
+        landlord_amount: record.landlord_amount,
+        credit_used: record.credit_used,
+        credit_added: record.credit_added,
+        reference: record.reference.clone(),
+        timestamp: record.timestamp,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit autopay registered event
+pub(crate) fn autopay_registered(env: &Env, mandate: &AutopayMandate) {
+    AutopayRegistered {
+        agreement_id: mandate.agreement_id.clone(),
+        tenant: mandate.tenant.clone(),
+        max_amount_per_period: mandate.max_amount_per_period,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit autopay cancelled event
+pub(crate) fn autopay_cancelled(env: &Env, mandate: &AutopayMandate) {
+    AutopayCancelled {
+        agreement_id: mandate.agreement_id.clone(),
+        tenant: mandate.tenant.clone(),
+        payments_executed: mandate.payments_executed,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit payment refunded event
+pub(crate) fn payment_refunded(env: &Env, record: &PaymentRecord, refund: &PaymentRefund) {
+    PaymentRefunded {
+        agreement_id: record.agreement_id.clone(),
+        payment_number: record.payment_number,
+        payer: record.tenant.clone(),
+        amount: refund.amount,
+        from_credit: refund.from_credit,
+        platform_amount: refund.platform_amount,
+        landlord_amount: refund.landlord_amount,
+        agent_amount: refund.agent_amount,
+        periods_reopened: refund.periods_reopened,
+        total_refunded: record.refunded_amount(),
+    }
+    .publish(env);
+}
+
+/// Helper function to emit stream started event
+pub(crate) fn stream_started(env: &Env, stream: &RentStream) {
+    StreamStarted {
+        agreement_id: stream.agreement_id.clone(),
+        tenant: stream.tenant.clone(),
+        monthly_rent: stream.monthly_rent,
+        start_time: stream.start_time,
+        deposit: stream.deposited,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit stream topped up event
+pub(crate) fn stream_topped_up(env: &Env, stream: &RentStream, amount: i128) {
+    StreamToppedUp {
+        agreement_id: stream.agreement_id.clone(),
+        tenant: stream.tenant.clone(),
+        amount,
+        total_deposited: stream.deposited,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit stream withdrawn event
+pub(crate) fn stream_withdrawn(
+    env: &Env,
+    stream: &RentStream,
+    amount: i128,
+    platform_amount: i128,
+    agent_amount: i128,
+    landlord_amount: i128,
+) {
+    StreamWithdrawn {
+        agreement_id: stream.agreement_id.clone(),
+        amount,
+        platform_amount,
+        agent_amount,
+        landlord_amount,
+        total_withdrawn: stream.withdrawn,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit stream stopped event
+pub(crate) fn stream_stopped(env: &Env, stream: &RentStream, stopped_by: Address, refunded: i128) {
+    StreamStopped {
+        agreement_id: stream.agreement_id.clone(),
+        stopped_by,
+        total_paid: stream.withdrawn,
+        refunded,
+    }
+    .publish(env);
+}
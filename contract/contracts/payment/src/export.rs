@@ -0,0 +1,145 @@
+//! Host-side ledger export for accounting tools.
+//!
+//! Turns decoded `PaymentRecord`s into CSV-ready rows and aggregates them into
+//! the same `PaymentStatement` the contract returns. Available to host code
+//! with the `export` feature; not part of the contract build.
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+use soroban_sdk::Bytes;
+
+use crate::statement::{accumulate, empty_statement, is_late};
+use crate::types::{PaymentRecord, PaymentStatement};
+
+/// Column names of the rows produced by [`LedgerRow::to_csv_record`]
+pub const LEDGER_CSV_HEADER: [&str; 13] = [
+    "agreement_id",
+    "payment_number",
+    "timestamp",
+    "tenant",
+    "amount",
+    "landlord_amount",
+    "agent_amount",
+    "platform_amount",
+    "credit_used",
+    "credit_added",
+    "refunded_amount",
+    "late",
+    "reference",
+];
+
+/// Column names of the row produced by [`statement_csv_record`]
+pub const STATEMENT_CSV_HEADER: [&str; 9] = [
+    "from_time",
+    "to_time",
+    "payment_count",
+    "late_payment_count",
+    "total_amount",
+    "landlord_total",
+    "agent_total",
+    "platform_total",
+    "refunded_total",
+];
+
+/// A single payment flattened for export
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LedgerRow {
+    pub agreement_id: String,
+    pub payment_number: u32,
+    pub timestamp: u64,
+    pub tenant: String,
+    pub amount: i128,
+    pub landlord_amount: i128,
+    pub agent_amount: i128,
+    pub platform_amount: i128,
+    pub credit_used: i128,
+    pub credit_added: i128,
+    pub refunded_amount: i128,
+    pub late: bool,
+    /// Client payment reference, hex encoded
+    pub reference: Option<String>,
+}
+
+impl LedgerRow {
+    pub fn from_record(record: &PaymentRecord) -> Self {
+        LedgerRow {
+            agreement_id: record.agreement_id.to_string(),
+            payment_number: record.payment_number,
+            timestamp: record.timestamp,
+            tenant: record.tenant.to_string().to_string(),
+            amount: record.amount,
+            landlord_amount: record.landlord_amount,
+            agent_amount: record.agent_amount,
+            platform_amount: record.platform_amount,
+            credit_used: record.credit_used,
+            credit_added: record.credit_added,
+            refunded_amount: record.refunded_amount(),
+            late: is_late(record),
+            reference: record.reference.as_ref().map(to_hex),
+        }
+    }
+
+    /// Fields in [`LEDGER_CSV_HEADER`] order
+    pub fn to_csv_record(&self) -> [String; 13] {
+        [
+            self.agreement_id.clone(),
+            self.payment_number.to_string(),
+            self.timestamp.to_string(),
+            self.tenant.clone(),
+            self.amount.to_string(),
+            self.landlord_amount.to_string(),
+            self.agent_amount.to_string(),
+            self.platform_amount.to_string(),
+            self.credit_used.to_string(),
+            self.credit_added.to_string(),
+            self.refunded_amount.to_string(),
+            self.late.to_string(),
+            self.reference.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+/// Rows for the records made within `[from_time, to_time]`, oldest first
+pub fn ledger_rows(records: &[PaymentRecord], from_time: u64, to_time: u64) -> Vec<LedgerRow> {
+    let mut rows: Vec<LedgerRow> = records
+        .iter()
+        .filter(|record| record.timestamp >= from_time && record.timestamp <= to_time)
+        .map(LedgerRow::from_record)
+        .collect();
+    rows.sort_by_key(|row| (row.timestamp, row.agreement_id.clone(), row.payment_number));
+    rows
+}
+
+/// Aggregate decoded records exactly as the contract's agreement statements do
+pub fn statement(records: &[PaymentRecord], from_time: u64, to_time: u64) -> PaymentStatement {
+    let mut statement = empty_statement(from_time, to_time);
+    for record in records {
+        accumulate(&mut statement, record, None);
+    }
+    statement
+}
+
+/// Statement fields in [`STATEMENT_CSV_HEADER`] order
+pub fn statement_csv_record(statement: &PaymentStatement) -> [String; 9] {
+    [
+        statement.from_time.to_string(),
+        statement.to_time.to_string(),
+        statement.payment_count.to_string(),
+        statement.late_payment_count.to_string(),
+        statement.total_amount.to_string(),
+        statement.landlord_total.to_string(),
+        statement.agent_total.to_string(),
+        statement.platform_total.to_string(),
+        statement.refunded_total.to_string(),
+    ]
+}
+
+fn to_hex(bytes: &Bytes) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut hex = String::with_capacity(bytes.len() as usize * 2);
+    for byte in bytes.iter() {
+        hex.push(DIGITS[(byte >> 4) as usize] as char);
+        hex.push(DIGITS[(byte & 0x0f) as usize] as char);
+    }
+    hex
+}
@@ -6,11 +6,22 @@
 //! Handles rent payment processing with automatic commission splitting
 //! and payment record management.
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, Env, String, Vec};
 
+#[cfg(any(test, feature = "export"))]
+extern crate std;
+
+pub mod autopay;
 pub mod errors;
+pub mod events;
+#[cfg(any(test, feature = "export"))]
+pub mod export;
 pub mod payment_impl;
+pub mod refund;
+pub mod statement;
 pub mod storage;
+pub mod stream;
+pub mod swap;
 pub mod types;
 
 #[cfg(test)]
@@ -18,32 +29,159 @@ mod tests;
 
 // Re-export public APIs
 pub use errors::PaymentError;
-pub use payment_impl::{calculate_payment_split, create_payment_record};
+pub use payment_impl::{calculate_payment_split, calculate_three_way_split, create_payment_record};
 pub use storage::DataKey;
-pub use types::{PaymentRecord, PaymentSplit};
+pub use types::{
+    AutopayMandate, FeeConfig, PaymentRecord, PaymentRefund, PaymentSplit, PaymentStatement,
+    PeriodAllocation, RentBalance, RentStream, StatementCursor, StatementParty,
+};
 
 use crate::errors::PaymentError as Error;
+use crate::payment_impl::FundsSource;
 use crate::storage::DataKey as StorageKey;
-use crate::types::{AgreementStatus, RentAgreement};
+use crate::types::AgreementStatus;
+
+/// Maximum number of payment records returned by `list_payments`
+pub const MAX_PAGE_SIZE: u32 = 50;
 
 #[contract]
 pub struct PaymentContract;
 
 #[contractimpl]
 impl PaymentContract {
-    /// Sets the platform fee collector address
-    pub fn set_platform_fee_collector(env: Env, collector: Address) {
-        collector.require_auth();
+    /// Initialize the contract with an admin, the chioma contract holding the
+    /// agreements of record and the platform fee configuration
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        chioma_contract: Address,
+        fee_config: FeeConfig,
+    ) -> Result<(), Error> {
+        if env.storage().instance().has(&StorageKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
+        if fee_config.fee_bps > 10_000 {
+            return Err(Error::InvalidFeeConfig);
+        }
+
+        env.storage().instance().set(&StorageKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&StorageKey::ChiomaContract, &chioma_contract);
+        env.storage()
+            .instance()
+            .set(&StorageKey::FeeConfig, &fee_config);
+
+        events::contract_initialized(&env, admin, chioma_contract, &fee_config);
+
+        Ok(())
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        payment_impl::get_admin(&env)
+    }
+
+    /// Get the chioma contract holding the agreements of record
+    pub fn get_chioma_contract(env: Env) -> Result<Address, Error> {
+        payment_impl::get_chioma_contract(&env)
+    }
+
+    /// Route the landlord leg of rent to holders of obligations minted by
+    /// `obligation_contract` and report each landlord leg paid to it (admin
+    /// only)
+    pub fn set_obligation_contract(env: Env, obligation_contract: Address) -> Result<(), Error> {
+        let admin = payment_impl::get_admin(&env)?;
+        admin.require_auth();
+
         env.storage()
             .instance()
-            .set(&StorageKey::PlatformFeeCollector, &collector);
+            .set(&StorageKey::ObligationContract, &obligation_contract);
+
+        events::obligation_contract_set(&env, admin, obligation_contract);
+
+        Ok(())
+    }
+
+    /// Get the rent obligation contract, if one has been configured
+    pub fn get_obligation_contract(env: Env) -> Option<Address> {
+        payment_impl::get_obligation_contract(&env)
+    }
+
+    /// Get the platform fee configuration
+    pub fn get_fee_config(env: Env) -> Result<FeeConfig, Error> {
+        payment_impl::get_fee_config(&env)
+    }
+
+    /// Replace the platform fee configuration (admin only)
+    pub fn update_fee_config(env: Env, new_config: FeeConfig) -> Result<(), Error> {
+        let admin = payment_impl::get_admin(&env)?;
+        admin.require_auth();
+
+        if new_config.fee_bps > 10_000 {
+            return Err(Error::InvalidFeeConfig);
+        }
+
+        let old_config = payment_impl::get_fee_config(&env)?;
+        env.storage()
+            .instance()
+            .set(&StorageKey::FeeConfig, &new_config);
+
+        events::fee_config_updated(&env, admin, &old_config, &new_config);
+
+        Ok(())
+    }
+
+    /// Sets the platform fee collector address (admin only)
+    pub fn set_platform_fee_collector(env: Env, collector: Address) -> Result<(), Error> {
+        let config = payment_impl::get_fee_config(&env)?;
+        Self::update_fee_config(
+            env,
+            FeeConfig {
+                collector,
+                ..config
+            },
+        )
     }
 
-    /// Get a payment record by ID
-    pub fn get_payment(env: Env, payment_id: String) -> Result<PaymentRecord, Error> {
+    /// Set or remove a landlord's platform fee override (admin only)
+    pub fn set_landlord_fee(
+        env: Env,
+        landlord: Address,
+        fee_bps: Option<u32>,
+    ) -> Result<(), Error> {
+        let admin = payment_impl::get_admin(&env)?;
+        admin.require_auth();
+
+        let key = StorageKey::LandlordFee(landlord.clone());
+        match fee_bps {
+            Some(bps) if bps > 10_000 => return Err(Error::InvalidFeeConfig),
+            Some(bps) => env.storage().persistent().set(&key, &bps),
+            None => env.storage().persistent().remove(&key),
+        }
+
+        events::landlord_fee_updated(&env, admin, landlord, fee_bps);
+
+        Ok(())
+    }
+
+    /// Get the platform fee in basis points charged on a landlord's rent
+    pub fn get_platform_fee_bps(env: Env, landlord: Address) -> Result<u32, Error> {
+        payment_impl::get_platform_fee_bps(&env, &landlord)
+    }
+
+    /// Get a payment record by agreement ID and payment number
+    pub fn get_payment(
+        env: Env,
+        agreement_id: String,
+        payment_number: u32,
+    ) -> Result<PaymentRecord, Error> {
         env.storage()
             .persistent()
-            .get(&StorageKey::Payment(payment_id))
+            .get(&StorageKey::PaymentRecord(agreement_id, payment_number))
             .ok_or(Error::PaymentNotFound)
     }
 
@@ -55,124 +193,352 @@ impl PaymentContract {
             .unwrap_or(0)
     }
 
+    /// Get the number of payments recorded for a specific agreement
+    pub fn get_agreement_payment_count(env: Env, agreement_id: String) -> u32 {
+        payment_impl::get_agreement_payment_count(&env, &agreement_id)
+    }
+
     /// Get total amount paid for a specific agreement
     pub fn get_total_paid(env: Env, agreement_id: String) -> Result<i128, Error> {
-        let payment_count: u32 = env
-            .storage()
-            .instance()
-            .get(&StorageKey::PaymentCount)
-            .unwrap_or(0);
+        Ok(payment_impl::get_agreement_total_paid(&env, &agreement_id))
+    }
 
-        let mut total: i128 = 0;
+    /// List payment records for an agreement in payment-number order.
+    /// Returns up to `limit` records (capped at `MAX_PAGE_SIZE`) with a
+    /// payment number greater than `cursor`; pass 0 to start from the first
+    /// payment and the last returned payment number to fetch the next page.
+    pub fn list_payments(
+        env: Env,
+        agreement_id: String,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<PaymentRecord> {
+        let count = payment_impl::get_agreement_payment_count(&env, &agreement_id);
+        let mut payments = Vec::new(&env);
+        if cursor >= count {
+            return payments;
+        }
+        let end = cursor.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
 
-        for i in 0..payment_count {
-            let payment_id = Self::u32_to_string(&env, i);
-            if let Some(payment) = env
-                .storage()
-                .persistent()
-                .get::<StorageKey, PaymentRecord>(&StorageKey::Payment(payment_id))
+        for payment_number in (cursor + 1)..=end {
+            if let Some(record) =
+                env.storage()
+                    .persistent()
+                    .get::<_, PaymentRecord>(&StorageKey::PaymentRecord(
+                        agreement_id.clone(),
+                        payment_number,
+                    ))
             {
-                if payment.agreement_id == agreement_id {
-                    total += payment.amount;
-                }
+                payments.push_back(record);
             }
         }
-
-        Ok(total)
-    }
-
-    fn u32_to_string(env: &Env, num: u32) -> String {
-        match num {
-            0 => String::from_str(env, "0"),
-            1 => String::from_str(env, "1"),
-            2 => String::from_str(env, "2"),
-            3 => String::from_str(env, "3"),
-            4 => String::from_str(env, "4"),
-            5 => String::from_str(env, "5"),
-            6 => String::from_str(env, "6"),
-            7 => String::from_str(env, "7"),
-            8 => String::from_str(env, "8"),
-            9 => String::from_str(env, "9"),
-            10 => String::from_str(env, "10"),
-            _ => String::from_str(env, "unknown"),
-        }
+        payments
     }
 
-    /// Process rent payment with 90/10 landlord/platform split
+    /// Process rent payment of any positive amount. Funds are applied to the
+    /// oldest outstanding period first and any excess is kept as credit for
+    /// future periods. Of the applied funds, the platform fee is taken first,
+    /// then the agent commission, and the landlord receives the rest.
+    /// `payment_reference` is a client-chosen idempotency key; a reference
+    /// already recorded for the agreement is rejected, so a retried payment
+    /// is never charged twice.
     /// Follows checks-effects-interactions pattern for reentrancy safety
     pub fn pay_rent(
         env: Env,
         from: Address,
         agreement_id: String,
         payment_amount: i128,
-    ) -> Result<(), Error> {
-        use soroban_sdk::token;
+        payment_reference: Bytes,
+    ) -> Result<PaymentRecord, Error> {
+        from.require_auth();
+
+        let agreement = payment_impl::check_rent_payment(
+            &env,
+            &from,
+            &agreement_id,
+            payment_amount,
+            &payment_reference,
+        )?;
 
-        // Authorization
+        payment_impl::process_payment(
+            &env,
+            &agreement,
+            &from,
+            payment_amount,
+            FundsSource::Transfer,
+            Some(payment_reference),
+        )
+    }
+
+    /// Pay rent in any allowlisted asset. The configured swap adapter converts
+    /// at most `max_amount_in` of `pay_token` into exactly `payment_amount`
+    /// of the agreement's payment token; any unused input is returned.
+    pub fn pay_rent_with_asset(
+        env: Env,
+        from: Address,
+        agreement_id: String,
+        payment_amount: i128,
+        payment_reference: Bytes,
+        pay_token: Address,
+        max_amount_in: i128,
+    ) -> Result<PaymentRecord, Error> {
         from.require_auth();
 
-        // Load agreement
-        let mut agreement: RentAgreement = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::Agreement(agreement_id.clone()))
-            .ok_or(Error::AgreementNotFound)?;
+        let agreement = payment_impl::check_rent_payment(
+            &env,
+            &from,
+            &agreement_id,
+            payment_amount,
+            &payment_reference,
+        )?;
 
-        // Validation
-        if agreement.status != AgreementStatus::Active {
-            return Err(Error::AgreementNotActive);
-        }
+        let source = if pay_token == agreement.payment_token {
+            FundsSource::Transfer
+        } else {
+            swap::swap_to_payment_token(
+                &env,
+                &agreement,
+                &from,
+                &pay_token,
+                payment_amount,
+                max_amount_in,
+            )?;
+            FundsSource::Held
+        };
 
-        if from != agreement.tenant {
-            return Err(Error::NotTenant);
-        }
+        payment_impl::process_payment(
+            &env,
+            &agreement,
+            &from,
+            payment_amount,
+            source,
+            Some(payment_reference),
+        )
+    }
+
+    /// Set the swap adapter used to convert payments in other assets
+    /// (admin only)
+    pub fn set_swap_adapter(env: Env, adapter: Address) -> Result<(), Error> {
+        let admin = payment_impl::get_admin(&env)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&StorageKey::SwapAdapter, &adapter);
+
+        events::swap_adapter_set(&env, admin, adapter);
 
-        if payment_amount <= 0 {
-            return Err(Error::InvalidPaymentAmount);
+        Ok(())
+    }
+
+    /// Get the swap adapter, if one has been configured
+    pub fn get_swap_adapter(env: Env) -> Option<Address> {
+        swap::get_swap_adapter(&env)
+    }
+
+    /// Allow or disallow an asset for rent payments through the swap adapter
+    /// (admin only)
+    pub fn set_asset_allowed(env: Env, asset: Address, allowed: bool) -> Result<(), Error> {
+        let admin = payment_impl::get_admin(&env)?;
+        admin.require_auth();
+
+        let key = StorageKey::AllowedAsset(asset.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
         }
 
-        if payment_amount != agreement.monthly_rent {
-            return Err(Error::InvalidPaymentAmount);
+        events::asset_allowlist_updated(&env, admin, asset, allowed);
+
+        Ok(())
+    }
+
+    /// Whether an asset may be used to pay rent through the swap adapter
+    pub fn is_asset_allowed(env: Env, asset: Address) -> bool {
+        swap::is_asset_allowed(&env, &asset)
+    }
+
+    /// Get the payment recorded under a client payment reference
+    pub fn get_payment_by_reference(
+        env: Env,
+        agreement_id: String,
+        payment_reference: Bytes,
+    ) -> Result<PaymentRecord, Error> {
+        let payment_number =
+            payment_impl::get_payment_number_by_reference(&env, &agreement_id, &payment_reference)
+                .ok_or(Error::PaymentNotFound)?;
+        Self::get_payment(env, agreement_id, payment_number)
+    }
+
+    /// Apply an agreement's credit balance to periods that have fallen due.
+    /// Callable by anyone; returns the receipt of the credit applied.
+    pub fn apply_credit(env: Env, agreement_id: String) -> Result<PaymentRecord, Error> {
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
+
+        if agreement.status != AgreementStatus::Active {
+            return Err(Error::AgreementNotActive);
         }
 
-        let current_time = env.ledger().timestamp();
-        if current_time < agreement.next_payment_due {
+        if !payment_impl::credit_due(&env, &agreement) {
             return Err(Error::PaymentNotDue);
         }
 
-        // Calculate 90/10 split
-        let landlord_amount = (payment_amount * 90) / 100;
-        let platform_amount = payment_amount - landlord_amount;
+        payment_impl::process_payment(
+            &env,
+            &agreement,
+            &agreement.tenant,
+            0,
+            FundsSource::Held,
+            None,
+        )
+    }
 
-        let platform_collector: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::PlatformFeeCollector)
-            .ok_or(Error::PaymentFailed)?;
-
-        // Effects: Update state BEFORE external calls
-        let payment_month = agreement.payment_history.len();
-        agreement.payment_history.set(
-            payment_month,
-            PaymentSplit {
-                landlord_amount,
-                platform_amount,
-                token: agreement.payment_token.clone(),
-                payment_date: current_time,
-            },
-        );
-        agreement.next_payment_due = current_time + 2_592_000; // 30 days
+    /// Refund part or all of a rent payment to its payer (landlord only).
+    /// The platform fee is clawed back in proportion to the refund, with the
+    /// collector's authorization unless automatic clawback is configured.
+    pub fn refund_payment(
+        env: Env,
+        agreement_id: String,
+        payment_number: u32,
+        amount: i128,
+    ) -> Result<PaymentRecord, Error> {
+        refund::refund_payment(&env, agreement_id, payment_number, amount)
+    }
 
-        env.storage()
-            .persistent()
-            .set(&StorageKey::Agreement(agreement_id.clone()), &agreement);
+    /// Get the credit and partial-period balance of an agreement
+    pub fn get_rent_balance(env: Env, agreement_id: String) -> RentBalance {
+        payment_impl::get_rent_balance(&env, &agreement_id)
+    }
 
-        // Interactions: External calls AFTER state updates
-        let token_client = token::Client::new(&env, &agreement.payment_token);
-        token_client.transfer(&from, &agreement.landlord, &landlord_amount);
-        token_client.transfer(&from, &platform_collector, &platform_amount);
+    /// Get the due date of an agreement's oldest period not covered by
+    /// payments and prepaid credit. Unlike chioma's `next_payment_due`, this
+    /// counts credit not yet applied, so rent is only overdue once it passes.
+    pub fn get_paid_through(env: Env, agreement_id: String) -> Result<u64, Error> {
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
+        let balance = payment_impl::get_rent_balance(&env, &agreement_id);
+        Ok(payment_impl::paid_through(&agreement, &balance))
+    }
 
-        Ok(())
+    /// Register an autopay mandate for an agreement, approving this contract
+    /// to pull up to `allowance` of the rent token until `expiration_ledger`.
+    pub fn register_autopay(
+        env: Env,
+        tenant: Address,
+        agreement_id: String,
+        max_amount_per_period: i128,
+        allowance: i128,
+        expiration_ledger: u32,
+    ) -> Result<AutopayMandate, Error> {
+        autopay::register_autopay(
+            &env,
+            tenant,
+            agreement_id,
+            max_amount_per_period,
+            allowance,
+            expiration_ledger,
+        )
+    }
+
+    /// Cancel the tenant's autopay mandate for an agreement and revoke its
+    /// allowance
+    pub fn cancel_autopay(env: Env, tenant: Address, agreement_id: String) -> Result<(), Error> {
+        autopay::cancel_autopay(&env, tenant, agreement_id)
+    }
+
+    /// Get the autopay mandate of an agreement, if any
+    pub fn get_autopay(env: Env, agreement_id: String) -> Option<AutopayMandate> {
+        autopay::get_autopay(&env, &agreement_id)
+    }
+
+    /// Execute due autopay mandates for the given agreements, and apply
+    /// credit to the due periods of those without one. Callable by any
+    /// keeper; returns the IDs of the agreements that were paid.
+    pub fn execute_due_payments(env: Env, agreement_ids: Vec<String>) -> Vec<String> {
+        autopay::execute_due_payments(&env, agreement_ids)
+    }
+
+    /// Open a rent stream funded by the tenant's deposit. While it runs, rent
+    /// accrues per second instead of being paid in monthly installments.
+    pub fn start_stream(
+        env: Env,
+        tenant: Address,
+        agreement_id: String,
+        deposit: i128,
+    ) -> Result<RentStream, Error> {
+        stream::start_stream(&env, tenant, agreement_id, deposit)
+    }
+
+    /// Add to the tenant's deposit of an active rent stream
+    pub fn top_up_stream(
+        env: Env,
+        tenant: Address,
+        agreement_id: String,
+        amount: i128,
+    ) -> Result<RentStream, Error> {
+        stream::top_up_stream(&env, tenant, agreement_id, amount)
+    }
+
+    /// Withdraw the streamed rent periods accrued in full so far (landlord
+    /// only). Rent accrued towards the current period is carried until it
+    /// completes. Returns the gross amount paid out across all legs.
+    pub fn withdraw_stream(env: Env, agreement_id: String) -> Result<i128, Error> {
+        stream::withdraw_stream(&env, agreement_id)
+    }
+
+    /// Stop a rent stream (tenant or landlord), paying out the accrued rent
+    /// and returning the unused deposit to the tenant
+    pub fn stop_stream(
+        env: Env,
+        caller: Address,
+        agreement_id: String,
+    ) -> Result<RentStream, Error> {
+        stream::stop_stream(&env, caller, agreement_id)
+    }
+
+    /// Get the rent stream of an agreement, if any
+    pub fn get_stream(env: Env, agreement_id: String) -> Option<RentStream> {
+        stream::get_stream(&env, &agreement_id)
+    }
+
+    /// Streamed rent a withdrawal would pay out now: the periods accrued in
+    /// full and not yet withdrawn
+    pub fn get_stream_withdrawable(env: Env, agreement_id: String) -> Result<i128, Error> {
+        let stream = stream::get_stream(&env, &agreement_id).ok_or(Error::StreamNotFound)?;
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
+        Ok(stream::withdrawable_amount(&env, &stream, &agreement))
+    }
+
+    /// Aggregate an agreement's payments made within `[from_time, to_time]`.
+    /// Covers up to `limit` payments (capped at `MAX_PAGE_SIZE`) after
+    /// `cursor`; pass the default cursor to start and, while `has_more` is
+    /// set, the returned `next_cursor` for the next page. The pages' totals
+    /// add up to the full statement.
+    pub fn get_agreement_statement(
+        env: Env,
+        agreement_id: String,
+        from_time: u64,
+        to_time: u64,
+        cursor: StatementCursor,
+        limit: u32,
+    ) -> PaymentStatement {
+        statement::agreement_statement(&env, &agreement_id, from_time, to_time, cursor, limit)
+    }
+
+    /// Aggregate the payments made within `[from_time, to_time]` across
+    /// every agreement where `party` is the landlord, tenant or agent.
+    /// Paginated like `get_agreement_statement`. A landlord's total only
+    /// counts rent paid to them, not to an obligation holder or lender.
+    pub fn get_party_statement(
+        env: Env,
+        role: StatementParty,
+        party: Address,
+        from_time: u64,
+        to_time: u64,
+        cursor: StatementCursor,
+        limit: u32,
+    ) -> PaymentStatement {
+        statement::party_statement(&env, role, party, from_time, to_time, cursor, limit)
     }
 
     /// Get payment details for a specific month
@@ -181,13 +547,7 @@ impl PaymentContract {
         agreement_id: String,
         month: u32,
     ) -> Result<PaymentSplit, Error> {
-        let agreement: RentAgreement = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::Agreement(agreement_id))
-            .ok_or(Error::AgreementNotFound)?;
-
-        agreement
+        payment_impl::load_agreement(&env, &agreement_id)?
             .payment_history
             .get(month)
             .ok_or(Error::PaymentNotFound)
@@ -1,32 +1,304 @@
 //! Payment processing implementation.
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::{token, vec, Address, Bytes, Env, IntoVal, String, Symbol, Vec};
 
 use crate::errors::PaymentError;
+use crate::events;
+use crate::statement::index_agreement;
 use crate::storage::DataKey;
-use crate::types::{AgreementStatus, PaymentRecord, RentAgreement};
+use crate::stream::is_streaming;
+use crate::types::{
+    AgreementStatus, FeeConfig, PaymentRecord, PaymentSplit, PeriodAllocation, RentAgreement,
+    RentBalance, RentPayee, StatementParty,
+};
 
-/// Create an immutable payment record
+/// Interval between rent periods (30 days), matching chioma
+pub const PAYMENT_INTERVAL: u64 = 2_592_000;
+
+/// Create an immutable payment record, splitting the landlord leg between
+/// the payee and the owner at the payee's cap
 pub fn create_payment_record(
-    _env: &Env,
+    env: &Env,
     agreement_id: &String,
     amount: i128,
-    landlord_amount: i128,
+    platform_amount: i128,
     agent_amount: i128,
+    landlord_amount: i128,
     tenant: &Address,
+    landlord_payee: &mut RentPayee,
     payment_number: u32,
     timestamp: u64,
 ) -> Result<PaymentRecord, PaymentError> {
+    let (_, excess_amount) = landlord_payee.split(landlord_amount);
     Ok(PaymentRecord {
         agreement_id: agreement_id.clone(),
         payment_number,
         amount,
-        landlord_amount,
+        platform_amount,
         agent_amount,
+        landlord_amount,
         timestamp,
         tenant: tenant.clone(),
+        landlord_payee: landlord_payee.payee.clone(),
+        excess_payee: landlord_payee.owner.clone(),
+        excess_amount,
+        allocations: Vec::new(env),
+        credit_used: 0,
+        credit_added: 0,
+        reference: None,
+        refunds: Vec::new(env),
     })
 }
 
+/// Payment number recorded under a client payment reference, if any
+pub fn get_payment_number_by_reference(
+    env: &Env,
+    agreement_id: &String,
+    reference: &Bytes,
+) -> Option<u32> {
+    env.storage().persistent().get(&DataKey::PaymentReference(
+        agreement_id.clone(),
+        reference.clone(),
+    ))
+}
+
+/// Load the contract admin
+pub fn get_admin(env: &Env) -> Result<Address, PaymentError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(PaymentError::NotInitialized)
+}
+
+/// Load the platform fee configuration
+pub fn get_fee_config(env: &Env) -> Result<FeeConfig, PaymentError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeConfig)
+        .ok_or(PaymentError::NotInitialized)
+}
+
+/// Platform fee in basis points for a landlord, honouring any override
+pub fn get_platform_fee_bps(env: &Env, landlord: &Address) -> Result<u32, PaymentError> {
+    let config = get_fee_config(env)?;
+    Ok(env
+        .storage()
+        .persistent()
+        .get(&DataKey::LandlordFee(landlord.clone()))
+        .unwrap_or(config.fee_bps))
+}
+
+/// Load the chioma contract address
+pub fn get_chioma_contract(env: &Env) -> Result<Address, PaymentError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ChiomaContract)
+        .ok_or(PaymentError::NotInitialized)
+}
+
+/// Fetch an agreement of record from the chioma contract
+pub fn load_agreement(env: &Env, agreement_id: &String) -> Result<RentAgreement, PaymentError> {
+    let chioma = get_chioma_contract(env)?;
+    let agreement: Option<RentAgreement> = env.invoke_contract(
+        &chioma,
+        &Symbol::new(env, "get_agreement"),
+        vec![env, agreement_id.into_val(env)],
+    );
+    agreement.ok_or(PaymentError::AgreementNotFound)
+}
+
+/// Rent obligation contract, if one has been configured
+pub fn get_obligation_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::ObligationContract)
+}
+
+/// Where the landlord leg of an agreement's rent is paid: as the rent
+/// obligation contract reports (its holder, or a lienholder being repaid up
+/// to the principal outstanding) if one was minted, otherwise the landlord
+pub fn get_landlord_payee(env: &Env, agreement: &RentAgreement) -> RentPayee {
+    let landlord = || RentPayee {
+        payee: agreement.landlord.clone(),
+        cap: None,
+        owner: agreement.landlord.clone(),
+    };
+    let Some(obligations) = get_obligation_contract(env) else {
+        return landlord();
+    };
+    let payee: Option<RentPayee> = env.invoke_contract(
+        &obligations,
+        &Symbol::new(env, "get_rent_payee"),
+        vec![env, agreement.agreement_id.into_val(env)],
+    );
+    payee.unwrap_or_else(landlord)
+}
+
+/// Report a landlord leg to the rent obligation contract, if one is
+/// configured, so rent is credited to the address actually paid. A negative
+/// amount reports rent clawed back by a refund.
+pub fn record_obligation_rent(env: &Env, agreement_id: &String, payee: &Address, amount: i128) {
+    let Some(obligations) = get_obligation_contract(env) else {
+        return;
+    };
+    if amount == 0 {
+        return;
+    }
+    env.invoke_contract::<()>(
+        &obligations,
+        &Symbol::new(env, "record_rent_paid"),
+        vec![
+            env,
+            agreement_id.into_val(env),
+            payee.into_val(env),
+            amount.into_val(env),
+        ],
+    );
+}
+
+/// Reopen the most recently paid periods of an agreement in the chioma
+/// contract after their rent was refunded
+pub fn reverse_payment(env: &Env, agreement_id: &String, periods: u32) -> Result<(), PaymentError> {
+    let chioma = get_chioma_contract(env)?;
+    env.invoke_contract::<()>(
+        &chioma,
+        &Symbol::new(env, "reverse_payment"),
+        vec![env, agreement_id.into_val(env), periods.into_val(env)],
+    );
+    Ok(())
+}
+
+/// Report a payment to the chioma contract so the agreement of record
+/// reflects it
+pub fn report_payment(
+    env: &Env,
+    agreement_id: &String,
+    amount: i128,
+    split: PaymentSplit,
+) -> Result<(), PaymentError> {
+    let chioma = get_chioma_contract(env)?;
+    env.invoke_contract::<()>(
+        &chioma,
+        &Symbol::new(env, "record_payment"),
+        vec![
+            env,
+            agreement_id.into_val(env),
+            amount.into_val(env),
+            split.into_val(env),
+        ],
+    );
+    Ok(())
+}
+
+/// Number of payments recorded for an agreement
+pub fn get_agreement_payment_count(env: &Env, agreement_id: &String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgreementPaymentCount(agreement_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Running total of rent paid for an agreement
+pub fn get_agreement_total_paid(env: &Env, agreement_id: &String) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgreementTotalPaid(agreement_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Persist a payment record and update the per-agreement indexes.
+/// The record's payment number must be the next one for its agreement.
+pub fn store_payment_record(env: &Env, record: &PaymentRecord) {
+    let agreement_id = &record.agreement_id;
+
+    env.storage().persistent().set(
+        &DataKey::PaymentRecord(agreement_id.clone(), record.payment_number),
+        record,
+    );
+    env.storage().persistent().set(
+        &DataKey::AgreementPaymentCount(agreement_id.clone()),
+        &record.payment_number,
+    );
+    env.storage().persistent().set(
+        &DataKey::AgreementTotalPaid(agreement_id.clone()),
+        &(get_agreement_total_paid(env, agreement_id) + record.amount),
+    );
+
+    let payment_count: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::PaymentCount)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::PaymentCount, &(payment_count + 1));
+}
+
+/// Index an agreement under each of its parties for statements, on its
+/// first recorded payment
+pub fn index_agreement_parties(env: &Env, agreement: &RentAgreement) {
+    let agreement_id = &agreement.agreement_id;
+    index_agreement(
+        env,
+        StatementParty::Landlord,
+        agreement.landlord.clone(),
+        agreement_id,
+    );
+    index_agreement(
+        env,
+        StatementParty::Tenant,
+        agreement.tenant.clone(),
+        agreement_id,
+    );
+    if let Some(agent) = &agreement.agent {
+        index_agreement(env, StatementParty::Agent, agent.clone(), agreement_id);
+    }
+}
+
+/// Pay the platform, agent and landlord legs of a payment record out of
+/// funds held by the contract, and report the landlord leg to the rent
+/// obligation contract. The landlord leg goes to the record's payee, with
+/// any excess over its cap to the obligation owner.
+pub fn pay_legs(env: &Env, agreement: &RentAgreement, record: &PaymentRecord, collector: &Address) {
+    let token_client = token::Client::new(env, &agreement.payment_token);
+    let contract = env.current_contract_address();
+    let pay = |to: &Address, leg: i128| {
+        if leg > 0 {
+            token_client.transfer(&contract, to, &leg);
+        }
+    };
+    pay(&record.landlord_payee, record.payee_amount());
+    pay(&record.excess_payee, record.excess_amount);
+    pay(collector, record.platform_amount);
+    if let Some(agent) = &agreement.agent {
+        pay(agent, record.agent_amount);
+    }
+
+    record_obligation_rent(
+        env,
+        &record.agreement_id,
+        &record.landlord_payee,
+        record.payee_amount(),
+    );
+    record_obligation_rent(
+        env,
+        &record.agreement_id,
+        &record.excess_payee,
+        record.excess_amount,
+    );
+}
+
+/// Split a rent payment into (platform, agent, landlord) legs.
+/// The platform fee is taken first and the agent commission is charged on
+/// what remains; both rates are in basis points.
+pub fn calculate_three_way_split(
+    amount: &i128,
+    platform_fee_bps: &u32,
+    commission_rate: &u32,
+) -> (i128, i128, i128) {
+    let platform_amount = (amount * (*platform_fee_bps as i128)) / 10000;
+    let (landlord_amount, agent_amount) =
+        calculate_payment_split(&(amount - platform_amount), commission_rate);
+    (platform_amount, agent_amount, landlord_amount)
+}
+
 /// Calculate payment split between landlord and agent
 pub fn calculate_payment_split(amount: &i128, commission_rate: &u32) -> (i128, i128) {
     // commission_rate is in basis points (1 basis point = 0.01%)
@@ -35,93 +307,224 @@ pub fn calculate_payment_split(amount: &i128, commission_rate: &u32) -> (i128, i
     (landlord_amount, agent_amount)
 }
 
-/// Process rent payment with automatic commission splitting
-/// This is the alternate implementation used by RentalContract
-#[allow(deprecated)]
-#[allow(dead_code)]
-pub fn pay_rent_with_agent(
-    env: Env,
-    agreement_id: String,
-    token: Address,
-    amount: i128,
-) -> Result<(), PaymentError> {
-    use soroban_sdk::token::Client as TokenClient;
-
-    // Load agreement
-    let mut agreement: RentAgreement = env
-        .storage()
+/// Credit and partial-period balance of an agreement
+pub fn get_rent_balance(env: &Env, agreement_id: &String) -> RentBalance {
+    env.storage()
         .persistent()
-        .get(&DataKey::Agreement(agreement_id.clone()))
-        .ok_or(PaymentError::InvalidAmount)?;
+        .get(&DataKey::RentBalance(agreement_id.clone()))
+        .unwrap_or_default()
+}
+
+/// Amount still needed to settle the oldest outstanding period after
+/// applying the agreement's credit; zero or negative if credit covers it
+pub fn amount_due(agreement: &RentAgreement, balance: &RentBalance) -> i128 {
+    agreement.monthly_rent - balance.period_paid - balance.credit
+}
+
+/// Whether the agreement has credit that can settle a period already due
+pub fn credit_due(env: &Env, agreement: &RentAgreement) -> bool {
+    agreement.status == AgreementStatus::Active
+        && env.ledger().timestamp() >= agreement.next_payment_due
+        && get_rent_balance(env, &agreement.agreement_id).credit > 0
+}
+
+/// Due date of the oldest period not covered by what has been paid towards
+/// it plus the credit balance. Rent is only overdue once this has passed.
+pub fn paid_through(agreement: &RentAgreement, balance: &RentBalance) -> u64 {
+    let covered = (balance.period_paid + balance.credit) / agreement.monthly_rent;
+    agreement.next_payment_due + covered as u64 * PAYMENT_INTERVAL
+}
+
+/// Validate a tenant's rent payment and return the agreement it pays
+pub fn check_rent_payment(
+    env: &Env,
+    from: &Address,
+    agreement_id: &String,
+    amount: i128,
+    reference: &Bytes,
+) -> Result<RentAgreement, PaymentError> {
+    let agreement = load_agreement(env, agreement_id)?;
 
-    // Validate agreement is active
     if agreement.status != AgreementStatus::Active {
         return Err(PaymentError::AgreementNotActive);
     }
 
-    // Validate amount is strictly positive to prevent logical errors
+    if *from != agreement.tenant {
+        return Err(PaymentError::NotTenant);
+    }
+
     if amount <= 0 {
-        return Err(PaymentError::InvalidAmount);
+        return Err(PaymentError::InvalidPaymentAmount);
     }
 
-    // Validate amount matches monthly rent exactly
-    if amount != agreement.monthly_rent {
-        return Err(PaymentError::InvalidAmount);
+    if is_streaming(env, agreement_id) {
+        return Err(PaymentError::StreamActive);
     }
 
-    // Authorize tenant
-    agreement.tenant.require_auth();
+    if reference.is_empty() {
+        return Err(PaymentError::InvalidPaymentReference);
+    }
 
-    // Calculate payment split
-    let (landlord_amount, agent_amount) =
-        calculate_payment_split(&amount, &agreement.agent_commission_rate);
+    if get_payment_number_by_reference(env, agreement_id, reference).is_some() {
+        return Err(PaymentError::DuplicatePaymentReference);
+    }
+
+    Ok(agreement)
+}
+
+/// How a payment's funds reach this contract
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FundsSource {
+    /// `from` transfers the payment and must have authorized the call
+    Transfer,
+    /// Already held by this contract, e.g. the output of a swap
+    Held,
+}
+
+/// Apply a payment of `amount` plus any credit balance to the agreement's
+/// due periods, oldest first, and distribute the applied funds.
+///
+/// The payment is collected into this contract. Whatever is not needed by
+/// periods already due stays there as credit for future periods. Each period
+/// settled in full is reported to chioma.
+///
+/// `source` says how the payment reaches this contract.
+pub fn process_payment(
+    env: &Env,
+    agreement: &RentAgreement,
+    from: &Address,
+    amount: i128,
+    source: FundsSource,
+    reference: Option<Bytes>,
+) -> Result<PaymentRecord, PaymentError> {
+    let agreement_id = &agreement.agreement_id;
+    let current_time = env.ledger().timestamp();
+    let fee_config = get_fee_config(env)?;
+    let fee_bps = get_platform_fee_bps(env, &agreement.landlord)?;
+    let commission_rate = if agreement.agent.is_some() {
+        agreement.agent_commission_rate
+    } else {
+        0
+    };
+
+    // Allocate credit first, then the new payment, to due periods
+    let mut balance = get_rent_balance(env, agreement_id);
+    let credit_before = balance.credit;
+    let mut available = balance.credit + amount;
+    let mut credit_left = credit_before;
+    let mut period_due = agreement.next_payment_due;
+    let mut allocations = Vec::new(env);
+    let mut periods_completed = 0u32;
+
+    while available > 0 && period_due <= current_time {
+        let applied = (agreement.monthly_rent - balance.period_paid).min(available);
+        balance.period_paid += applied;
+        available -= applied;
 
-    // Execute atomic token transfers
-    let token_client = TokenClient::new(&env, &token);
+        // Credit is only ever carried while no period is due, so a period
+        // settled from credit alone was prepaid by its due date
+        let from_credit = credit_left.min(applied);
+        credit_left -= from_credit;
+        let settled_at = if from_credit == applied {
+            period_due
+        } else {
+            current_time
+        };
 
-    // Transfer to landlord
-    token_client.transfer(&agreement.tenant, &agreement.landlord, &landlord_amount);
+        let completed = balance.period_paid == agreement.monthly_rent;
+        allocations.push_back(PeriodAllocation {
+            period_due,
+            amount: applied,
+            completed,
+            settled_at,
+        });
 
-    // Transfer to agent if present
-    if let Some(agent_address) = &agreement.agent {
-        if agent_amount > 0 {
-            token_client.transfer(&agreement.tenant, agent_address, &agent_amount);
+        if completed {
+            balance.period_paid = 0;
+            period_due += PAYMENT_INTERVAL;
+            periods_completed += 1;
         }
     }
 
-    // Create payment record
-    let timestamp = env.ledger().timestamp();
-    let payment_record = create_payment_record(
-        &env,
-        &agreement_id,
+    let applied = credit_before + amount - available;
+    let credit_used = credit_before.min(applied);
+    balance.credit = available;
+
+    // Calculate platform, agent and landlord legs of the applied funds
+    let (platform_amount, agent_amount, landlord_amount) =
+        calculate_three_way_split(&applied, &fee_bps, &commission_rate);
+
+    // Resolve who receives the landlord leg before anything is reported
+    let mut landlord_payee = get_landlord_payee(env, agreement);
+
+    // Effects: Update state BEFORE external calls
+    let mut payment_record = create_payment_record(
+        env,
+        agreement_id,
         amount,
-        landlord_amount,
+        platform_amount,
         agent_amount,
-        &agreement.tenant,
-        agreement.payment_count + 1,
-        timestamp,
+        landlord_amount,
+        from,
+        &mut landlord_payee,
+        get_agreement_payment_count(env, agreement_id) + 1,
+        current_time,
     )?;
-
-    // Update agreement totals
-    agreement.total_rent_paid += amount;
-    agreement.payment_count += 1;
-
-    // Persist updated agreement
+    payment_record.allocations = allocations;
+    payment_record.credit_used = credit_used;
+    payment_record.credit_added = amount - (applied - credit_used);
+    payment_record.reference = reference;
+    store_payment_record(env, &payment_record);
+    if payment_record.payment_number == 1 {
+        index_agreement_parties(env, agreement);
+    }
+    if let Some(reference) = &payment_record.reference {
+        env.storage().persistent().set(
+            &DataKey::PaymentReference(agreement_id.clone(), reference.clone()),
+            &payment_record.payment_number,
+        );
+    }
     env.storage()
         .persistent()
-        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+        .set(&DataKey::RentBalance(agreement_id.clone()), &balance);
 
-    // Persist payment record
-    env.storage().persistent().set(
-        &DataKey::PaymentRecord(agreement_id.clone(), agreement.payment_count),
-        &payment_record,
-    );
+    // Interactions: External calls AFTER state updates
+    let token_client = token::Client::new(env, &agreement.payment_token);
+    let contract = env.current_contract_address();
+    if amount > 0 {
+        match source {
+            FundsSource::Transfer => token_client.transfer(from, &contract, &amount),
+            FundsSource::Held => {}
+        }
+    }
 
-    // Emit event
-    env.events().publish(
-        (String::from_str(&env, "rent_paid"), agreement_id),
-        (amount, landlord_amount, agent_amount, timestamp),
-    );
+    // Report each settled period to chioma, which advances the due date
+    if periods_completed > 0 {
+        let (period_platform, _, period_landlord) =
+            calculate_three_way_split(&agreement.monthly_rent, &fee_bps, &commission_rate);
+        for allocation in payment_record
+            .allocations
+            .iter()
+            .filter(|allocation| allocation.completed)
+        {
+            report_payment(
+                env,
+                agreement_id,
+                agreement.monthly_rent,
+                PaymentSplit {
+                    landlord_amount: period_landlord,
+                    platform_amount: period_platform,
+                    token: agreement.payment_token.clone(),
+                    payment_date: allocation.settled_at,
+                    payer: from.clone(),
+                },
+            )?;
+        }
+    }
 
-    Ok(())
+    pay_legs(env, agreement, &payment_record, &fee_config.collector);
+
+    events::rent_paid(env, &payment_record);
+
+    Ok(payment_record)
 }
@@ -0,0 +1,187 @@
+//! Landlord-initiated refunds of rent payments.
+//!
+//! A refund is drawn first from any credit the payment left with the
+//! contract and then from the settled legs. The platform gives back its fee
+//! in proportion to the refunded amount, either pulled automatically from an
+//! allowance the collector granted or with the collector's signature. The
+//! addresses that received the landlord leg return their share, and the
+//! landlord funds any agent commission on the refund. Rent refunded from the
+//! legs is owed again, so the periods it leaves short are reopened in chioma.
+use soroban_sdk::{token, Env, String};
+
+use crate::errors::PaymentError;
+use crate::events;
+use crate::payment_impl::{
+    get_agreement_total_paid, get_fee_config, get_obligation_contract, get_rent_balance,
+    load_agreement, record_obligation_rent, reverse_payment,
+};
+use crate::storage::DataKey;
+use crate::types::{PaymentRecord, PaymentRefund};
+
+/// Refund part or all of a recorded rent payment to its payer
+///
+/// CHECKS:
+/// - Payment must exist and the landlord of the agreement must authorize
+/// - Amount must be positive and within what is left to refund
+/// - Without automatic clawback, the fee collector must authorize
+/// - The landlord leg must not have gone to the rent obligation contract,
+///   and its payees other than the landlord must authorize
+///
+/// EFFECTS:
+/// - Record the refund on the payment and reduce the agreement total
+/// - Release the refunded part of any credit the payment left behind
+/// - Reopen the rent the settled legs give back
+///
+/// INTERACTIONS:
+/// - Refunded periods are reversed in chioma
+/// - Credit is returned from the contract, the rest from the landlord leg
+///   payees, landlord and collector
+pub fn refund_payment(
+    env: &Env,
+    agreement_id: String,
+    payment_number: u32,
+    amount: i128,
+) -> Result<PaymentRecord, PaymentError> {
+    let record_key = DataKey::PaymentRecord(agreement_id.clone(), payment_number);
+    let mut record: PaymentRecord = env
+        .storage()
+        .persistent()
+        .get(&record_key)
+        .ok_or(PaymentError::PaymentNotFound)?;
+
+    let agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
+
+    if amount <= 0 {
+        return Err(PaymentError::InvalidAmount);
+    }
+
+    let refunded = record.refunded_amount();
+
+    // Credit this payment left behind and that is still unspent goes first
+    let refunded_from_credit: i128 = record.refunds.iter().map(|r| r.from_credit).sum();
+    let mut balance = get_rent_balance(env, &agreement_id);
+    let from_credit = amount
+        .min(balance.credit)
+        .min(record.credit_added - refunded_from_credit);
+    let from_legs = amount - from_credit;
+
+    // The settled legs can only give back cash this payment applied to rent
+    let cash_applied = record.amount - record.credit_added;
+    if (refunded - refunded_from_credit) + from_legs > cash_applied {
+        return Err(PaymentError::RefundExceedsPayment);
+    }
+
+    let settled = record.platform_amount + record.agent_amount + record.landlord_amount;
+    let (platform_amount, agent_amount) = if from_legs > 0 {
+        (
+            from_legs * record.platform_amount / settled,
+            from_legs * record.agent_amount / settled,
+        )
+    } else {
+        (0, 0)
+    };
+    let landlord_amount = from_legs - platform_amount - agent_amount;
+
+    // The landlord leg is given back in the proportion it was paid out
+    let excess_amount = if record.landlord_amount > 0 {
+        landlord_amount * record.excess_amount / record.landlord_amount
+    } else {
+        0
+    };
+    let payee_amount = landlord_amount - excess_amount;
+
+    // Rent held by the obligation contract for its holders cannot be pulled back
+    let obligations = get_obligation_contract(env);
+    for (payee, amount) in [
+        (&record.landlord_payee, payee_amount),
+        (&record.excess_payee, excess_amount),
+    ] {
+        if amount <= 0 {
+            continue;
+        }
+        if obligations.as_ref() == Some(payee) {
+            return Err(PaymentError::PayeeCannotRefund);
+        }
+        if *payee != agreement.landlord {
+            payee.require_auth();
+        }
+    }
+
+    // Rent given back is owed again: first the partial period, then whole
+    // periods from the most recently paid
+    let periods_reopened = if from_legs > balance.period_paid {
+        let reopened = from_legs - balance.period_paid;
+        let periods = (reopened + agreement.monthly_rent - 1) / agreement.monthly_rent;
+        balance.period_paid = periods * agreement.monthly_rent - reopened;
+        periods as u32
+    } else {
+        balance.period_paid -= from_legs;
+        0
+    };
+
+    let fee_config = get_fee_config(env)?;
+    if platform_amount > 0 && !fee_config.auto_refund_fee {
+        fee_config.collector.require_auth();
+    }
+
+    // EFFECTS: Mark the payment refunded and adjust totals
+    let refund = PaymentRefund {
+        amount,
+        from_credit,
+        platform_amount,
+        landlord_amount,
+        excess_amount,
+        agent_amount,
+        periods_reopened,
+        timestamp: env.ledger().timestamp(),
+    };
+    record.refunds.push_back(refund.clone());
+    env.storage().persistent().set(&record_key, &record);
+    env.storage().persistent().set(
+        &DataKey::AgreementTotalPaid(agreement_id.clone()),
+        &(get_agreement_total_paid(env, &agreement_id) - amount),
+    );
+    balance.credit -= from_credit;
+    env.storage()
+        .persistent()
+        .set(&DataKey::RentBalance(agreement_id.clone()), &balance);
+
+    // INTERACTIONS: Reopen refunded periods, then return funds to the payer
+    if periods_reopened > 0 {
+        reverse_payment(env, &agreement_id, periods_reopened)?;
+    }
+
+    let token_client = token::Client::new(env, &agreement.payment_token);
+    let contract = env.current_contract_address();
+    if from_credit > 0 {
+        token_client.transfer(&contract, &record.tenant, &from_credit);
+    }
+    if payee_amount > 0 {
+        token_client.transfer(&record.landlord_payee, &record.tenant, &payee_amount);
+        record_obligation_rent(env, &agreement_id, &record.landlord_payee, -payee_amount);
+    }
+    if excess_amount > 0 {
+        token_client.transfer(&record.excess_payee, &record.tenant, &excess_amount);
+        record_obligation_rent(env, &agreement_id, &record.excess_payee, -excess_amount);
+    }
+    if agent_amount > 0 {
+        token_client.transfer(&agreement.landlord, &record.tenant, &agent_amount);
+    }
+    if platform_amount > 0 {
+        if fee_config.auto_refund_fee {
+            token_client.transfer_from(
+                &contract,
+                &fee_config.collector,
+                &record.tenant,
+                &platform_amount,
+            );
+        } else {
+            token_client.transfer(&fee_config.collector, &record.tenant, &platform_amount);
+        }
+    }
+
+    events::payment_refunded(env, &record, &refund);
+
+    Ok(record)
+}
@@ -0,0 +1,217 @@
+//! Period statements aggregating payment records over a time range.
+//!
+//! Statements are available per agreement and per landlord, tenant or agent.
+//! They are paginated: each call covers a bounded number of payments and
+//! returns the cursor to continue from, and the totals of the pages add up to
+//! the full statement. The aggregation itself works on decoded records so
+//! off-chain tooling can reproduce on-chain statements exactly.
+use soroban_sdk::{Address, Env, String};
+
+use crate::storage::DataKey;
+use crate::types::{PaymentRecord, PaymentStatement, StatementCursor, StatementParty};
+use crate::MAX_PAGE_SIZE;
+
+/// Time after a period falls due before paying it counts as late
+pub const LATE_PAYMENT_GRACE: u64 = 86_400;
+
+/// Empty statement covering `[from_time, to_time]`
+pub fn empty_statement(from_time: u64, to_time: u64) -> PaymentStatement {
+    PaymentStatement {
+        from_time,
+        to_time,
+        payment_count: 0,
+        late_payment_count: 0,
+        total_amount: 0,
+        landlord_total: 0,
+        agent_total: 0,
+        platform_total: 0,
+        refunded_total: 0,
+        has_more: false,
+        next_cursor: StatementCursor {
+            agreement_index: 0,
+            payment_number: 0,
+        },
+    }
+}
+
+/// Whether a payment settled any period later than the grace period allows
+pub fn is_late(record: &PaymentRecord) -> bool {
+    record
+        .allocations
+        .iter()
+        .any(|allocation| allocation.settled_at > allocation.period_due + LATE_PAYMENT_GRACE)
+}
+
+/// Add a record to a statement if it falls within the statement's range.
+/// With a `landlord`, only landlord legs paid to that address count towards
+/// the landlord total; legs paid to an obligation holder or lender do not.
+pub fn accumulate(
+    statement: &mut PaymentStatement,
+    record: &PaymentRecord,
+    landlord: Option<&Address>,
+) {
+    if record.timestamp < statement.from_time || record.timestamp > statement.to_time {
+        return;
+    }
+
+    statement.payment_count += 1;
+    if is_late(record) {
+        statement.late_payment_count += 1;
+    }
+    statement.total_amount += record.amount;
+    match landlord {
+        None => statement.landlord_total += record.landlord_amount,
+        Some(landlord) => {
+            if *landlord == record.landlord_payee {
+                statement.landlord_total += record.payee_amount();
+            }
+            if *landlord == record.excess_payee {
+                statement.landlord_total += record.excess_amount;
+            }
+        }
+    }
+    statement.agent_total += record.agent_amount;
+    statement.platform_total += record.platform_amount;
+    statement.refunded_total += record.refunded_amount();
+}
+
+/// Aggregate up to `limit` of an agreement's payments after `cursor`,
+/// counting those made within `[from_time, to_time]`
+pub fn agreement_statement(
+    env: &Env,
+    agreement_id: &String,
+    from_time: u64,
+    to_time: u64,
+    cursor: StatementCursor,
+    limit: u32,
+) -> PaymentStatement {
+    let mut statement = empty_statement(from_time, to_time);
+    let mut budget = limit.min(MAX_PAGE_SIZE);
+    let payment_number = add_payments(
+        env,
+        &mut statement,
+        agreement_id,
+        cursor.payment_number,
+        &mut budget,
+        None,
+    );
+    if payment_number < payment_count(env, agreement_id) {
+        statement.has_more = true;
+        statement.next_cursor = StatementCursor {
+            agreement_index: 0,
+            payment_number,
+        };
+    }
+    statement
+}
+
+/// Aggregate up to `limit` payments after `cursor` across the agreements a
+/// party is involved in, counting those made within `[from_time, to_time]`.
+/// A landlord's total only counts the landlord legs paid to them.
+pub fn party_statement(
+    env: &Env,
+    role: StatementParty,
+    party: Address,
+    from_time: u64,
+    to_time: u64,
+    cursor: StatementCursor,
+    limit: u32,
+) -> PaymentStatement {
+    let mut statement = empty_statement(from_time, to_time);
+    let landlord = (role == StatementParty::Landlord).then_some(&party);
+    let agreement_count = get_party_agreement_count(env, role, &party);
+    let mut budget = limit.min(MAX_PAGE_SIZE);
+    let mut position = cursor;
+
+    while position.agreement_index < agreement_count && budget > 0 {
+        let agreement_id = get_party_agreement(env, role, &party, position.agreement_index);
+        position.payment_number = add_payments(
+            env,
+            &mut statement,
+            &agreement_id,
+            position.payment_number,
+            &mut budget,
+            landlord,
+        );
+        if position.payment_number >= payment_count(env, &agreement_id) {
+            position = StatementCursor {
+                agreement_index: position.agreement_index + 1,
+                payment_number: 0,
+            };
+        }
+    }
+
+    if position.agreement_index < agreement_count {
+        statement.has_more = true;
+        statement.next_cursor = position;
+    }
+    statement
+}
+
+/// Number of agreements with payments in which `party` acts in `role`
+pub fn get_party_agreement_count(env: &Env, role: StatementParty, party: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PartyAgreementCount(role, party.clone()))
+        .unwrap_or(0)
+}
+
+/// Agreement at `index` of those in which `party` acts in `role`
+pub fn get_party_agreement(env: &Env, role: StatementParty, party: &Address, index: u32) -> String {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PartyAgreement(role, party.clone(), index))
+        .unwrap()
+}
+
+/// Index an agreement under a party so its payments appear on their
+/// statements. Called once per agreement and role, on its first payment.
+pub fn index_agreement(env: &Env, role: StatementParty, party: Address, agreement_id: &String) {
+    let count = get_party_agreement_count(env, role, &party);
+    env.storage().persistent().set(
+        &DataKey::PartyAgreement(role, party.clone(), count),
+        agreement_id,
+    );
+    env.storage()
+        .persistent()
+        .set(&DataKey::PartyAgreementCount(role, party), &(count + 1));
+}
+
+fn payment_count(env: &Env, agreement_id: &String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgreementPaymentCount(agreement_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Accumulate an agreement's payments after `after`, using up at most
+/// `budget` of them, and return the last payment number covered
+fn add_payments(
+    env: &Env,
+    statement: &mut PaymentStatement,
+    agreement_id: &String,
+    after: u32,
+    budget: &mut u32,
+    landlord: Option<&Address>,
+) -> u32 {
+    let count = payment_count(env, agreement_id);
+    if after >= count {
+        return after;
+    }
+    let end = after.saturating_add(*budget).min(count);
+
+    for payment_number in (after + 1)..=end {
+        if let Some(record) =
+            env.storage()
+                .persistent()
+                .get::<_, PaymentRecord>(&DataKey::PaymentRecord(
+                    agreement_id.clone(),
+                    payment_number,
+                ))
+        {
+            accumulate(statement, &record, landlord);
+        }
+    }
+    *budget -= end - after;
+    end
+}
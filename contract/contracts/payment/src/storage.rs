@@ -1,18 +1,45 @@
 //! Storage key definitions for the Payment contract.
-use soroban_sdk::{contracttype, String};
+use soroban_sdk::{contracttype, Address, Bytes, String};
+
+use crate::types::StatementParty;
 
 /// Storage key variants for persistent storage.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
-    /// Store payment by ID
-    Payment(String),
     /// Store payment record by agreement ID and payment number
     PaymentRecord(String, u32),
+    /// Number of payments recorded for an agreement
+    AgreementPaymentCount(String),
+    /// Running total of rent paid for an agreement
+    AgreementTotalPaid(String),
     /// Counter for total payments
     PaymentCount,
-    /// Platform fee collector address
-    PlatformFeeCollector,
-    /// Agreement storage (for reading agreement data)
-    Agreement(String),
+    /// Contract admin who governs the fee configuration
+    Admin,
+    /// Platform fee configuration
+    FeeConfig,
+    /// Per-landlord platform fee override in basis points
+    LandlordFee(Address),
+    /// Chioma contract holding the agreements of record
+    ChiomaContract,
+    /// Autopay mandate by agreement ID
+    Autopay(String),
+    /// Credit and partial-period balance by agreement ID
+    RentBalance(String),
+    /// Payment number recorded under a client payment reference
+    PaymentReference(String, Bytes),
+    /// Streaming rent by agreement ID
+    Stream(String),
+    /// Rent obligation contract whose token holders receive the landlord leg
+    ObligationContract,
+    /// Swap adapter converting payments made in other assets
+    SwapAdapter,
+    /// Asset allowlisted for payments through the swap adapter
+    AllowedAsset(Address),
+    /// Number of agreements with payments in which an address acts in a
+    /// given role
+    PartyAgreementCount(StatementParty, Address),
+    /// Agreement at an index of an address's agreements in a given role
+    PartyAgreement(StatementParty, Address, u32),
 }
@@ -0,0 +1,395 @@
+//! Streaming rent settled continuously instead of in monthly lump sums.
+//!
+//! The tenant deposits a balance and rent accrues to the landlord linearly
+//! per second at the agreement's monthly rate. The landlord withdraws each
+//! period once it has accrued in full; either party can stop the stream,
+//! which pays out the accrued rent and returns the unused deposit to the
+//! tenant.
+//!
+//! Each rent period paid out is recorded as a payment, split into legs the
+//! same way as a rent payment, and reported to chioma; payouts transfer
+//! exactly the recorded legs. When the stream stops, rent paid towards an
+//! unfinished period is recorded and carried as part-payment of that period.
+use soroban_sdk::{token, vec, Address, Env, String, Vec};
+
+use crate::errors::PaymentError;
+use crate::events;
+use crate::payment_impl::{
+    calculate_three_way_split, create_payment_record, get_agreement_payment_count, get_fee_config,
+    get_landlord_payee, get_platform_fee_bps, get_rent_balance, index_agreement_parties,
+    load_agreement, pay_legs, report_payment, store_payment_record, PAYMENT_INTERVAL,
+};
+use crate::storage::DataKey;
+use crate::types::{
+    AgreementStatus, PaymentRecord, PaymentSplit, PeriodAllocation, RentAgreement, RentPayee,
+    RentStream,
+};
+
+/// Load the rent stream of an agreement
+pub fn get_stream(env: &Env, agreement_id: &String) -> Option<RentStream> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Stream(agreement_id.clone()))
+}
+
+/// Whether an agreement is currently settled by an active rent stream
+pub fn is_streaming(env: &Env, agreement_id: &String) -> bool {
+    get_stream(env, agreement_id).is_some_and(|stream| stream.stopped_at.is_none())
+}
+
+/// Total rent accrued by a stream, capped at the tenant's deposit.
+/// Accrual stops when the stream is stopped or the agreement ends.
+pub fn accrued_amount(env: &Env, stream: &RentStream, agreement: &RentAgreement) -> i128 {
+    let end = env
+        .ledger()
+        .timestamp()
+        .min(stream.stopped_at.unwrap_or(u64::MAX))
+        .min(agreement.end_date);
+    if end <= stream.start_time {
+        return 0;
+    }
+
+    let elapsed = (end - stream.start_time) as i128;
+    (stream.monthly_rent * elapsed / PAYMENT_INTERVAL as i128).min(stream.deposited)
+}
+
+/// Open a rent stream funded by the tenant's deposit.
+/// Rent accrues from the start of the first unpaid period, which is the
+/// agreement's start date if no rent has been paid yet.
+pub fn start_stream(
+    env: &Env,
+    tenant: Address,
+    agreement_id: String,
+    deposit: i128,
+) -> Result<RentStream, PaymentError> {
+    tenant.require_auth();
+
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    if agreement.status != AgreementStatus::Active {
+        return Err(PaymentError::AgreementNotActive);
+    }
+
+    if tenant != agreement.tenant {
+        return Err(PaymentError::NotTenant);
+    }
+
+    if deposit <= 0 {
+        return Err(PaymentError::InvalidAmount);
+    }
+
+    if is_streaming(env, &agreement_id) {
+        return Err(PaymentError::StreamActive);
+    }
+
+    // The stream pays whole periods from a due date, so no period may be
+    // part-paid and no credit left waiting for one
+    let balance = get_rent_balance(env, &agreement_id);
+    if balance.credit > 0 || balance.period_paid > 0 {
+        return Err(PaymentError::RentBalanceOutstanding);
+    }
+
+    let stream = RentStream {
+        agreement_id: agreement_id.clone(),
+        tenant: tenant.clone(),
+        monthly_rent: agreement.monthly_rent,
+        start_time: agreement.next_payment_due,
+        deposited: deposit,
+        withdrawn: 0,
+        periods_recorded: 0,
+        stopped_at: None,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Stream(agreement_id), &stream);
+
+    token::Client::new(env, &agreement.payment_token).transfer(
+        &tenant,
+        env.current_contract_address(),
+        &deposit,
+    );
+
+    events::stream_started(env, &stream);
+
+    Ok(stream)
+}
+
+/// Add to the deposit of an active rent stream
+pub fn top_up_stream(
+    env: &Env,
+    tenant: Address,
+    agreement_id: String,
+    amount: i128,
+) -> Result<RentStream, PaymentError> {
+    tenant.require_auth();
+
+    let mut stream = active_stream(env, &agreement_id)?;
+
+    if tenant != stream.tenant {
+        return Err(PaymentError::NotTenant);
+    }
+
+    if amount <= 0 {
+        return Err(PaymentError::InvalidAmount);
+    }
+
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    stream.deposited += amount;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Stream(agreement_id), &stream);
+
+    token::Client::new(env, &agreement.payment_token).transfer(
+        &tenant,
+        env.current_contract_address(),
+        &amount,
+    );
+
+    events::stream_topped_up(env, &stream, amount);
+
+    Ok(stream)
+}
+
+/// Rent a withdrawal would pay out now: the periods accrued in full since
+/// the last withdrawal. Rent accrued towards the current period is carried
+/// until the period completes or the stream stops.
+pub fn withdrawable_amount(env: &Env, stream: &RentStream, agreement: &RentAgreement) -> i128 {
+    let accrued = accrued_amount(env, stream, agreement);
+    (accrued - accrued % stream.monthly_rent - stream.withdrawn).max(0)
+}
+
+/// Pay out the rent periods accrued in full since the last withdrawal
+/// (landlord only). Returns the gross amount withdrawn, before the platform
+/// and agent legs.
+pub fn withdraw_stream(env: &Env, agreement_id: String) -> Result<i128, PaymentError> {
+    let mut stream = active_stream(env, &agreement_id)?;
+    let agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
+
+    let amount = withdrawable_amount(env, &stream, &agreement);
+    if amount <= 0 {
+        return Err(PaymentError::PaymentNotDue);
+    }
+
+    // EFFECTS: Record the periods this payout completes
+    stream.withdrawn += amount;
+    let mut landlord_payee = get_landlord_payee(env, &agreement);
+    let records = record_periods(env, &mut stream, &agreement, &mut landlord_payee, false)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Stream(agreement_id), &stream);
+
+    // INTERACTIONS: Report completed periods, then pay out
+    report_periods(env, &agreement, &records)?;
+    settle(env, &stream, &agreement, &records, amount)?;
+
+    Ok(amount)
+}
+
+/// Stop an active rent stream (tenant or landlord).
+/// Accrued rent is paid out and the unused deposit returned to the tenant.
+pub fn stop_stream(
+    env: &Env,
+    caller: Address,
+    agreement_id: String,
+) -> Result<RentStream, PaymentError> {
+    caller.require_auth();
+
+    let mut stream = active_stream(env, &agreement_id)?;
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    if caller != agreement.tenant && caller != agreement.landlord {
+        return Err(PaymentError::NotAgreementParty);
+    }
+
+    // EFFECTS: Freeze accrual and record the stream's payouts in full
+    stream.stopped_at = Some(env.ledger().timestamp());
+    let accrued = accrued_amount(env, &stream, &agreement);
+    let owed = accrued - stream.withdrawn;
+    let refunded = stream.deposited - accrued;
+    stream.withdrawn = accrued;
+    let mut landlord_payee = get_landlord_payee(env, &agreement);
+    let records = record_periods(env, &mut stream, &agreement, &mut landlord_payee, true)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Stream(agreement_id), &stream);
+
+    // INTERACTIONS: Report completed periods, pay the landlord side, then
+    // return the rest
+    report_periods(env, &agreement, &records)?;
+    if owed > 0 {
+        settle(env, &stream, &agreement, &records, owed)?;
+    }
+    if refunded > 0 {
+        token::Client::new(env, &agreement.payment_token).transfer(
+            &env.current_contract_address(),
+            &stream.tenant,
+            &refunded,
+        );
+    }
+
+    events::stream_stopped(env, &stream, caller, refunded);
+
+    Ok(stream)
+}
+
+/// Load a stream that has not been stopped
+fn active_stream(env: &Env, agreement_id: &String) -> Result<RentStream, PaymentError> {
+    let stream = get_stream(env, agreement_id).ok_or(PaymentError::StreamNotFound)?;
+    if stream.stopped_at.is_some() {
+        return Err(PaymentError::StreamNotActive);
+    }
+    Ok(stream)
+}
+
+/// Record a payment for each rent period the stream has paid out in full
+/// since the last one recorded, returning the new records.
+/// When the stream is `closing`, rent paid towards the unfinished period is
+/// also recorded and carried as that period's part-payment.
+fn record_periods(
+    env: &Env,
+    stream: &mut RentStream,
+    agreement: &RentAgreement,
+    landlord_payee: &mut RentPayee,
+    closing: bool,
+) -> Result<Vec<PaymentRecord>, PaymentError> {
+    let mut records = Vec::new(env);
+    let periods_paid = (stream.withdrawn / stream.monthly_rent) as u32;
+    while stream.periods_recorded < periods_paid {
+        let period_due = stream.start_time + stream.periods_recorded as u64 * PAYMENT_INTERVAL;
+        records.push_back(record_payment(
+            env,
+            stream,
+            agreement,
+            landlord_payee,
+            PeriodAllocation {
+                period_due,
+                amount: stream.monthly_rent,
+                completed: true,
+                settled_at: period_due + PAYMENT_INTERVAL,
+            },
+        )?);
+        stream.periods_recorded += 1;
+    }
+
+    let part_paid = stream.withdrawn - stream.periods_recorded as i128 * stream.monthly_rent;
+    if closing && part_paid > 0 {
+        records.push_back(record_payment(
+            env,
+            stream,
+            agreement,
+            landlord_payee,
+            PeriodAllocation {
+                period_due: stream.start_time + stream.periods_recorded as u64 * PAYMENT_INTERVAL,
+                amount: part_paid,
+                completed: false,
+                settled_at: env.ledger().timestamp(),
+            },
+        )?);
+        let mut balance = get_rent_balance(env, &stream.agreement_id);
+        balance.period_paid = part_paid;
+        env.storage()
+            .persistent()
+            .set(&DataKey::RentBalance(stream.agreement_id.clone()), &balance);
+    }
+
+    Ok(records)
+}
+
+/// Store the payment record of rent the stream applied to one period.
+/// The period's amount is split into legs the same way as a rent payment.
+fn record_payment(
+    env: &Env,
+    stream: &RentStream,
+    agreement: &RentAgreement,
+    landlord_payee: &mut RentPayee,
+    allocation: PeriodAllocation,
+) -> Result<PaymentRecord, PaymentError> {
+    let fee_bps = get_platform_fee_bps(env, &agreement.landlord)?;
+    let commission_rate = if agreement.agent.is_some() {
+        agreement.agent_commission_rate
+    } else {
+        0
+    };
+    let (platform_amount, agent_amount, landlord_amount) =
+        calculate_three_way_split(&allocation.amount, &fee_bps, &commission_rate);
+
+    let mut record = create_payment_record(
+        env,
+        &stream.agreement_id,
+        allocation.amount,
+        platform_amount,
+        agent_amount,
+        landlord_amount,
+        &stream.tenant,
+        landlord_payee,
+        get_agreement_payment_count(env, &stream.agreement_id) + 1,
+        env.ledger().timestamp(),
+    )?;
+    record.allocations = vec![env, allocation];
+    store_payment_record(env, &record);
+    if record.payment_number == 1 {
+        index_agreement_parties(env, agreement);
+    }
+
+    events::rent_paid(env, &record);
+
+    Ok(record)
+}
+
+/// Report the rent periods the stream completed to chioma
+fn report_periods(
+    env: &Env,
+    agreement: &RentAgreement,
+    records: &Vec<PaymentRecord>,
+) -> Result<(), PaymentError> {
+    for record in records.iter() {
+        for allocation in record.allocations.iter().filter(|a| a.completed) {
+            report_payment(
+                env,
+                &record.agreement_id,
+                record.amount,
+                PaymentSplit {
+                    landlord_amount: record.landlord_amount,
+                    platform_amount: record.platform_amount,
+                    token: agreement.payment_token.clone(),
+                    payment_date: allocation.settled_at,
+                    payer: record.tenant.clone(),
+                },
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Pay each leg of the recorded periods, so the transfers match the
+/// records exactly. `amount` is the gross total of the records.
+fn settle(
+    env: &Env,
+    stream: &RentStream,
+    agreement: &RentAgreement,
+    records: &Vec<PaymentRecord>,
+    amount: i128,
+) -> Result<(), PaymentError> {
+    let fee_config = get_fee_config(env)?;
+    let (mut platform_total, mut agent_total, mut landlord_total) = (0, 0, 0);
+    for record in records.iter() {
+        pay_legs(env, agreement, &record, &fee_config.collector);
+        platform_total += record.platform_amount;
+        agent_total += record.agent_amount;
+        landlord_total += record.landlord_amount;
+    }
+
+    events::stream_withdrawn(
+        env,
+        stream,
+        amount,
+        platform_total,
+        agent_total,
+        landlord_total,
+    );
+
+    Ok(())
+}
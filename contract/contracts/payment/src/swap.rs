@@ -0,0 +1,111 @@
+//! Multi-asset rent settlement through a pluggable swap adapter.
+//!
+//! Tenants may pay in any allowlisted asset. The payment contract takes the
+//! tenant's maximum input, has the adapter swap it for exactly the rent due
+//! in the agreement's payment token and returns whatever input was unused,
+//! so the landlord always receives the agreed token.
+use soroban_sdk::{contractclient, token, Address, Env};
+
+use crate::errors::PaymentError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::RentAgreement;
+
+/// Interface a swap adapter contract must expose.
+///
+/// Adapters wrap an AMM or aggregator behind an exact-output swap.
+#[contractclient(name = "SwapAdapterClient")]
+pub trait SwapAdapterInterface {
+    /// Swap at most `max_amount_in` of `token_in`, pulled from `from` with
+    /// `transfer_from` against an allowance, for exactly `amount_out` of
+    /// `token_out` sent to `to`. Returns the input actually spent.
+    fn swap_exact_out(
+        env: Env,
+        from: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_out: i128,
+        max_amount_in: i128,
+        to: Address,
+    ) -> i128;
+}
+
+/// Configured swap adapter, if any
+pub fn get_swap_adapter(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::SwapAdapter)
+}
+
+/// Whether an asset is allowlisted for payments through the swap adapter
+pub fn is_asset_allowed(env: &Env, asset: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::AllowedAsset(asset.clone()))
+}
+
+/// Convert at most `max_amount_in` of the tenant's `pay_token` into exactly
+/// `amount_out` of the agreement's payment token, held by this contract.
+///
+/// CHECKS:
+/// - Asset must be allowlisted and an adapter configured
+/// - Maximum input must be positive
+///
+/// INTERACTIONS:
+/// - Take the maximum input from the tenant
+/// - Approve the adapter for at most that input and swap it
+/// - Verify the output received and return unused input to the tenant
+pub fn swap_to_payment_token(
+    env: &Env,
+    agreement: &RentAgreement,
+    from: &Address,
+    pay_token: &Address,
+    amount_out: i128,
+    max_amount_in: i128,
+) -> Result<i128, PaymentError> {
+    if !is_asset_allowed(env, pay_token) {
+        return Err(PaymentError::AssetNotAllowed);
+    }
+
+    let adapter = get_swap_adapter(env).ok_or(PaymentError::SwapAdapterNotSet)?;
+
+    if max_amount_in <= 0 {
+        return Err(PaymentError::InvalidAmount);
+    }
+
+    let contract = env.current_contract_address();
+    let token_in = token::Client::new(env, pay_token);
+    let token_out = token::Client::new(env, &agreement.payment_token);
+
+    token_in.transfer(from, &contract, &max_amount_in);
+
+    // Allow the adapter to pull no more than the tenant's maximum input,
+    // for this ledger only
+    let expiration_ledger = env.ledger().sequence();
+    token_in.approve(&contract, &adapter, &max_amount_in, &expiration_ledger);
+
+    let in_before = token_in.balance(&contract);
+    let out_before = token_out.balance(&contract);
+    SwapAdapterClient::new(env, &adapter).swap_exact_out(
+        &contract,
+        pay_token,
+        &agreement.payment_token,
+        &amount_out,
+        &max_amount_in,
+        &contract,
+    );
+
+    token_in.approve(&contract, &adapter, &0, &expiration_ledger);
+
+    // Trust balances rather than the adapter's reported figures
+    let amount_in = in_before - token_in.balance(&contract);
+    if amount_in > max_amount_in || token_out.balance(&contract) - out_before < amount_out {
+        return Err(PaymentError::SlippageExceeded);
+    }
+
+    if amount_in < max_amount_in {
+        token_in.transfer(&contract, from, &(max_amount_in - amount_in));
+    }
+
+    events::rent_swapped(env, agreement, pay_token.clone(), amount_in, amount_out);
+
+    Ok(amount_in)
+}
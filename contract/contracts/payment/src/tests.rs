@@ -1,9 +1,124 @@
 //! Tests for the Payment contract.
 
+use crate::errors::PaymentError;
 use crate::payment_impl::*;
 use crate::types::*;
+use crate::{PaymentContract, PaymentContractClient};
+use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::Client as TokenClient;
 use soroban_sdk::token::StellarAssetClient as TokenAdminClient;
-use soroban_sdk::{testutils::Address as _, Address, Env, Map, String};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, Address, Bytes, Env, IntoVal, Map, String,
+};
+
+/// Mock chioma contract holding agreements and recording reported payments.
+#[contract]
+pub struct MockChiomaContract;
+
+#[contractimpl]
+impl MockChiomaContract {
+    pub fn add_agreement(env: Env, agreement: RentAgreement) {
+        env.storage()
+            .persistent()
+            .set(&agreement.agreement_id.clone(), &agreement);
+    }
+
+    pub fn get_agreement(env: Env, agreement_id: String) -> Option<RentAgreement> {
+        env.storage().persistent().get(&agreement_id)
+    }
+
+    pub fn record_payment(env: Env, agreement_id: String, amount: i128, split: PaymentSplit) {
+        let mut agreement: RentAgreement = env.storage().persistent().get(&agreement_id).unwrap();
+        agreement
+            .payment_history
+            .set(agreement.payment_count, split.clone());
+        agreement.payment_count += 1;
+        agreement.total_rent_paid += amount;
+        agreement.next_payment_due += 2_592_000;
+        env.storage().persistent().set(&agreement_id, &agreement);
+    }
+
+    pub fn reverse_payment(env: Env, agreement_id: String, periods: u32) {
+        let mut agreement: RentAgreement = env.storage().persistent().get(&agreement_id).unwrap();
+        for _ in 0..periods {
+            agreement.payment_count -= 1;
+            agreement.payment_history.remove(agreement.payment_count);
+            agreement.total_rent_paid -= agreement.monthly_rent;
+            agreement.next_payment_due -= 2_592_000;
+        }
+        env.storage().persistent().set(&agreement_id, &agreement);
+    }
+}
+
+/// Mock rent obligation contract tracking rent payees.
+#[contract]
+pub struct MockObligationContract;
+
+#[contractimpl]
+impl MockObligationContract {
+    pub fn set_owner(env: Env, agreement_id: String, owner: Address) {
+        let payee = RentPayee {
+            payee: owner.clone(),
+            cap: None,
+            owner,
+        };
+        env.storage().persistent().set(&agreement_id, &payee);
+    }
+
+    pub fn set_lien(env: Env, agreement_id: String, lender: Address, owner: Address, cap: i128) {
+        let payee = RentPayee {
+            payee: lender,
+            cap: Some(cap),
+            owner,
+        };
+        env.storage().persistent().set(&agreement_id, &payee);
+    }
+
+    pub fn get_rent_payee(env: Env, agreement_id: String) -> Option<RentPayee> {
+        env.storage().persistent().get(&agreement_id)
+    }
+
+    pub fn record_rent_paid(_env: Env, _agreement_id: String, _payee: Address, _amount: i128) {}
+}
+
+/// Mock AMM swap adapter quoting a fixed price of `price_in` input units per
+/// output unit.
+#[contract]
+pub struct MockSwapAdapter;
+
+#[contractimpl]
+impl MockSwapAdapter {
+    pub fn set_price(env: Env, price_in: i128) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("price"), &price_in);
+    }
+
+    pub fn swap_exact_out(
+        env: Env,
+        from: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_out: i128,
+        max_amount_in: i128,
+        to: Address,
+    ) -> i128 {
+        let price_in: i128 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("price"))
+            .unwrap();
+        let amount_in = amount_out * price_in;
+        if amount_in > max_amount_in {
+            panic!("slippage");
+        }
+
+        let pool = env.current_contract_address();
+        TokenClient::new(&env, &token_in).transfer_from(&pool, &from, &pool, &amount_in);
+        TokenClient::new(&env, &token_out).transfer(&pool, &to, &amount_out);
+        amount_in
+    }
+}
 
 // Helper function to create a test agreement
 fn create_test_agreement(
@@ -29,7 +144,7 @@ fn create_test_agreement(
         payment_count: 0,
         security_deposit: 0,
         start_date: 0,
-        end_date: 0,
+        end_date: 12 * 2_592_000,
         signed_at: None,
         payment_token,
         next_payment_due: 0,
@@ -37,6 +152,10 @@ fn create_test_agreement(
     }
 }
 
+fn payment_ref(env: &Env, reference: &str) -> Bytes {
+    Bytes::from_slice(env, reference.as_bytes())
+}
+
 fn create_token(env: &Env, admin: &Address) -> Address {
     env.register_stellar_asset_contract_v2(admin.clone())
         .address()
@@ -77,20 +196,50 @@ fn test_calculate_payment_split_2_5_percent() {
 fn test_create_payment_record() {
     let env = Env::default();
     let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "AGR_001");
+    let mut payee = RentPayee {
+        payee: landlord.clone(),
+        cap: None,
+        owner: landlord.clone(),
+    };
 
-    let record =
-        create_payment_record(&env, &agreement_id, 1000, 950, 50, &tenant, 1, 12345).unwrap();
+    let record = create_payment_record(
+        &env,
+        &agreement_id,
+        1000,
+        100,
+        45,
+        855,
+        &tenant,
+        &mut payee,
+        1,
+        12345,
+    )
+    .unwrap();
 
     assert_eq!(record.agreement_id, agreement_id);
     assert_eq!(record.amount, 1000);
-    assert_eq!(record.landlord_amount, 950);
-    assert_eq!(record.agent_amount, 50);
+    assert_eq!(record.platform_amount, 100);
+    assert_eq!(record.agent_amount, 45);
+    assert_eq!(record.landlord_amount, 855);
     assert_eq!(record.payment_number, 1);
+    assert_eq!(record.landlord_payee, landlord);
+    assert_eq!(record.excess_amount, 0);
     assert_eq!(record.timestamp, 12345);
     assert_eq!(record.tenant, tenant);
 }
 
+#[test]
+fn test_calculate_three_way_split() {
+    // 10% platform fee first, then 5% agent commission on the remainder
+    let (platform, agent, landlord) = calculate_three_way_split(&1000, &1000, &500);
+    assert_eq!(platform, 100);
+    assert_eq!(agent, 45);
+    assert_eq!(landlord, 855);
+    assert_eq!(platform + agent + landlord, 1000);
+}
+
 #[test]
 fn test_create_test_agreement() {
     let env = Env::default();
@@ -146,3 +295,1153 @@ fn test_agreement_with_agent() {
     assert_eq!(agreement.agent, Some(agent));
     assert_eq!(agreement.agent_commission_rate, 500);
 }
+
+// Helper to register the contract with an active agreement and a funded tenant
+fn setup_payment_contract<'a>(
+    env: &'a Env,
+    id: &str,
+    monthly_rent: i128,
+) -> (PaymentContractClient<'a>, Address, Address, Address) {
+    setup_payment_contract_with_agent(env, id, monthly_rent, None, 0)
+}
+
+fn setup_payment_contract_with_agent<'a>(
+    env: &'a Env,
+    id: &str,
+    monthly_rent: i128,
+    agent: Option<Address>,
+    commission_rate: u32,
+) -> (PaymentContractClient<'a>, Address, Address, Address) {
+    let contract_id = env.register(PaymentContract, ());
+    let client = PaymentContractClient::new(env, &contract_id);
+
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = create_token(env, &token_admin);
+    TokenAdminClient::new(env, &token).mint(&tenant, &(monthly_rent * 100));
+
+    let agreement = create_test_agreement(
+        env,
+        id,
+        &tenant,
+        &landlord,
+        agent,
+        monthly_rent,
+        commission_rate,
+        AgreementStatus::Active,
+        token.clone(),
+    );
+    let chioma = env.register(MockChiomaContract, ());
+    MockChiomaContractClient::new(env, &chioma).add_agreement(&agreement);
+
+    client.initialize(
+        &Address::generate(env),
+        &chioma,
+        &FeeConfig {
+            fee_bps: 1000,
+            collector: Address::generate(env),
+            auto_refund_fee: false,
+        },
+    );
+
+    (client, tenant, landlord, token)
+}
+
+fn pay_months(env: &Env, client: &PaymentContractClient, tenant: &Address, id: &str, months: u32) {
+    let agreement_id = String::from_str(env, id);
+    for month in 0..months {
+        let reference = Bytes::from_array(env, &month.to_be_bytes());
+        client.pay_rent(tenant, &agreement_id, &1000, &reference);
+        env.ledger().with_mut(|li| li.timestamp += 2_592_000);
+    }
+}
+
+#[test]
+fn test_total_paid_beyond_ten_payments() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, _landlord, _token) = setup_payment_contract(&env, "agreement_3", 1000);
+    pay_months(&env, &client, &tenant, "agreement_3", 12);
+
+    let agreement_id = String::from_str(&env, "agreement_3");
+    assert_eq!(client.get_total_paid(&agreement_id), 12_000);
+    assert_eq!(client.get_agreement_payment_count(&agreement_id), 12);
+    assert_eq!(client.get_payment_count(), 12);
+
+    let payment = client.get_payment(&agreement_id, &12);
+    assert_eq!(payment.payment_number, 12);
+    assert_eq!(payment.amount, 1000);
+    assert_eq!(payment.tenant, tenant);
+
+    let unknown = String::from_str(&env, "unknown");
+    assert_eq!(client.get_total_paid(&unknown), 0);
+    assert!(client.try_get_payment(&agreement_id, &13).is_err());
+}
+
+#[test]
+fn test_list_payments_pages_by_cursor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, _landlord, _token) = setup_payment_contract(&env, "agreement_4", 1000);
+    pay_months(&env, &client, &tenant, "agreement_4", 5);
+
+    let agreement_id = String::from_str(&env, "agreement_4");
+    let page = client.list_payments(&agreement_id, &0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().payment_number, 1);
+    assert_eq!(page.get(1).unwrap().payment_number, 2);
+
+    let cursor = page.get(1).unwrap().payment_number;
+    let page = client.list_payments(&agreement_id, &cursor, &10);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(2).unwrap().payment_number, 5);
+
+    assert_eq!(client.list_payments(&agreement_id, &5, &10).len(), 0);
+    assert_eq!(client.list_payments(&agreement_id, &u32::MAX, &10).len(), 0);
+}
+
+#[test]
+fn test_pay_rent_uses_configured_fee_and_override() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, token) = setup_payment_contract(&env, "agreement_5", 1000);
+    let collector = client.get_fee_config().collector;
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "agreement_5");
+
+    client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "inv-1"));
+    assert_eq!(token_client.balance(&landlord), 900);
+    assert_eq!(token_client.balance(&collector), 100);
+
+    client.set_landlord_fee(&landlord, &Some(250));
+    assert_eq!(client.get_platform_fee_bps(&landlord), 250);
+
+    env.ledger().with_mut(|li| li.timestamp += 2_592_000);
+    client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "inv-2"));
+    assert_eq!(token_client.balance(&landlord), 900 + 975);
+    assert_eq!(token_client.balance(&collector), 100 + 25);
+
+    client.set_landlord_fee(&landlord, &None);
+    assert_eq!(client.get_platform_fee_bps(&landlord), 1000);
+}
+
+#[test]
+fn test_fee_config_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _tenant, landlord, _token) = setup_payment_contract(&env, "agreement_6", 1000);
+    let admin = client.get_admin();
+
+    let result = client.try_initialize(
+        &admin,
+        &admin,
+        &FeeConfig {
+            fee_bps: 0,
+            collector: admin.clone(),
+            auto_refund_fee: false,
+        },
+    );
+    assert_eq!(result, Err(Ok(PaymentError::AlreadyInitialized)));
+
+    let result = client.try_update_fee_config(&FeeConfig {
+        fee_bps: 10_001,
+        collector: admin.clone(),
+        auto_refund_fee: false,
+    });
+    assert_eq!(result, Err(Ok(PaymentError::InvalidFeeConfig)));
+
+    let result = client.try_set_landlord_fee(&landlord, &Some(10_001));
+    assert_eq!(result, Err(Ok(PaymentError::InvalidFeeConfig)));
+
+    let new_collector = Address::generate(&env);
+    client.set_platform_fee_collector(&new_collector);
+    let config = client.get_fee_config();
+    assert_eq!(config.collector, new_collector);
+    assert_eq!(config.fee_bps, 1000);
+}
+
+#[test]
+#[should_panic]
+fn test_fee_collector_cannot_be_set_by_collector() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _tenant, _landlord, _token) = setup_payment_contract(&env, "agreement_7", 1000);
+    let attacker = Address::generate(&env);
+
+    client
+        .mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_platform_fee_collector",
+                args: (attacker.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_platform_fee_collector(&attacker);
+}
+
+#[test]
+fn test_pay_rent_pays_platform_agent_and_landlord() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let agent = Address::generate(&env);
+    let (client, tenant, landlord, token) =
+        setup_payment_contract_with_agent(&env, "agreement_8", 1000, Some(agent.clone()), 500);
+    let collector = client.get_fee_config().collector;
+    let agreement_id = String::from_str(&env, "agreement_8");
+
+    client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "inv-3"));
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&collector), 100);
+    assert_eq!(token_client.balance(&agent), 45);
+    assert_eq!(token_client.balance(&landlord), 855);
+
+    let record = client.get_payment(&agreement_id, &1);
+    assert_eq!(record.platform_amount, 100);
+    assert_eq!(record.agent_amount, 45);
+    assert_eq!(record.landlord_amount, 855);
+}
+
+#[test]
+fn test_pay_rent_reports_payment_to_chioma() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, _landlord, _token) = setup_payment_contract(&env, "agreement_9", 1000);
+    let chioma = MockChiomaContractClient::new(&env, &client.get_chioma_contract());
+    let agreement_id = String::from_str(&env, "agreement_9");
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "inv-4"));
+
+    let agreement = chioma.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.total_rent_paid, 1000);
+    assert_eq!(agreement.payment_count, 1);
+    assert_eq!(agreement.next_payment_due, 2_592_000);
+
+    let split = client.get_payment_split(&agreement_id, &0);
+    assert_eq!(split.landlord_amount, 900);
+    assert_eq!(split.platform_amount, 100);
+    assert_eq!(split.payer, tenant);
+
+    let unknown = String::from_str(&env, "unknown");
+    let result = client.try_pay_rent(&tenant, &unknown, &1000, &payment_ref(&env, "inv-5"));
+    assert_eq!(result, Err(Ok(PaymentError::AgreementNotFound)));
+}
+
+#[test]
+fn test_pay_rent_rejects_duplicate_payment_reference() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, _landlord, token) = setup_payment_contract(&env, "agreement_ref", 1000);
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "agreement_ref");
+    let reference = payment_ref(&env, "INV-2024-001");
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let record = client.pay_rent(&tenant, &agreement_id, &600, &reference);
+    assert_eq!(record.reference, Some(reference.clone()));
+
+    // A retried submission with the same reference is rejected and not charged
+    let balance_before = token_client.balance(&tenant);
+    let result = client.try_pay_rent(&tenant, &agreement_id, &600, &reference);
+    assert_eq!(result, Err(Ok(PaymentError::DuplicatePaymentReference)));
+    assert_eq!(token_client.balance(&tenant), balance_before);
+    assert_eq!(client.get_agreement_payment_count(&agreement_id), 1);
+
+    let stored = client.get_payment_by_reference(&agreement_id, &reference);
+    assert_eq!(stored, record);
+
+    let result = client.try_pay_rent(&tenant, &agreement_id, &400, &Bytes::new(&env));
+    assert_eq!(result, Err(Ok(PaymentError::InvalidPaymentReference)));
+
+    client.pay_rent(
+        &tenant,
+        &agreement_id,
+        &400,
+        &payment_ref(&env, "INV-2024-002"),
+    );
+    assert_eq!(client.get_total_paid(&agreement_id), 1000);
+}
+
+#[test]
+fn test_refund_double_payment_from_credit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, token) = setup_payment_contract(&env, "agreement_dup", 1000);
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "agreement_dup");
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "rent-1"));
+    client.pay_rent(
+        &tenant,
+        &agreement_id,
+        &1000,
+        &payment_ref(&env, "rent-1-again"),
+    );
+    let tenant_before = token_client.balance(&tenant);
+    let landlord_before = token_client.balance(&landlord);
+
+    let record = client.refund_payment(&agreement_id, &2, &1000);
+    let refund = record.refunds.get(0).unwrap();
+    assert_eq!(refund.amount, 1000);
+    assert_eq!(refund.from_credit, 1000);
+    assert_eq!(refund.platform_amount, 0);
+
+    // The mistaken payment never reached the landlord, so only credit is returned
+    assert_eq!(token_client.balance(&tenant), tenant_before + 1000);
+    assert_eq!(token_client.balance(&landlord), landlord_before);
+    assert_eq!(client.get_rent_balance(&agreement_id).credit, 0);
+    assert_eq!(client.get_total_paid(&agreement_id), 1000);
+
+    let result = client.try_refund_payment(&agreement_id, &2, &1);
+    assert_eq!(result, Err(Ok(PaymentError::RefundExceedsPayment)));
+}
+
+#[test]
+fn test_refund_claws_back_platform_fee_proportionally() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, token) = setup_payment_contract(&env, "agreement_rf", 1000);
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "agreement_rf");
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "rent-1"));
+
+    // Collector opts into automatic clawback by granting an allowance
+    let config = client.get_fee_config();
+    client.update_fee_config(&FeeConfig {
+        auto_refund_fee: true,
+        ..config.clone()
+    });
+    token_client.approve(&config.collector, &client.address, &1000, &1000);
+
+    let tenant_before = token_client.balance(&tenant);
+    let record = client.refund_payment(&agreement_id, &1, &500);
+    let refund = record.refunds.get(0).unwrap();
+    assert_eq!(refund.amount, 500);
+    assert_eq!(refund.platform_amount, 50);
+    assert_eq!(refund.landlord_amount, 450);
+    assert_eq!(refund.periods_reopened, 1);
+    assert_eq!(token_client.balance(&tenant), tenant_before + 500);
+    assert_eq!(token_client.balance(&landlord), 900 - 450);
+    assert_eq!(token_client.balance(&config.collector), 100 - 50);
+    assert_eq!(client.get_total_paid(&agreement_id), 500);
+    assert_eq!(client.get_payment(&agreement_id, &1), record);
+
+    // Half of the period's rent is owed again
+    let chioma = MockChiomaContractClient::new(&env, &client.get_chioma_contract());
+    let agreement = chioma.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.payment_count, 0);
+    assert_eq!(agreement.total_rent_paid, 0);
+    assert_eq!(agreement.next_payment_due, 0);
+    assert_eq!(client.get_rent_balance(&agreement_id).period_paid, 500);
+
+    let result = client.try_refund_payment(&agreement_id, &1, &501);
+    assert_eq!(result, Err(Ok(PaymentError::RefundExceedsPayment)));
+    let result = client.try_refund_payment(&agreement_id, &1, &0);
+    assert_eq!(result, Err(Ok(PaymentError::InvalidAmount)));
+}
+
+#[test]
+fn test_stream_accrues_per_second_and_settles_on_stop() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // One unit of rent per second
+    let (client, tenant, landlord, token) = setup_payment_contract(&env, "stream_1", 2_592_000);
+    let token_client = TokenClient::new(&env, &token);
+    let chioma = MockChiomaContractClient::new(&env, &client.get_chioma_contract());
+    let agreement_id = String::from_str(&env, "stream_1");
+    let tenant_start = token_client.balance(&tenant);
+
+    // Accrual starts at the agreement's start date, not when the stream opens
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let stream = client.start_stream(&tenant, &agreement_id, &4_000_000);
+    assert_eq!(stream.start_time, 0);
+
+    let result = client.try_pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "r-1"));
+    assert_eq!(result, Err(Ok(PaymentError::StreamActive)));
+
+    // Rent accrued towards an unfinished period is carried, not paid out
+    env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+    assert_eq!(client.get_stream_withdrawable(&agreement_id), 0);
+    let result = client.try_withdraw_stream(&agreement_id);
+    assert_eq!(result, Err(Ok(PaymentError::PaymentNotDue)));
+
+    env.ledger().with_mut(|li| li.timestamp = 2_600_000);
+    assert_eq!(client.get_stream_withdrawable(&agreement_id), 2_592_000);
+    assert_eq!(client.withdraw_stream(&agreement_id), 2_592_000);
+    assert_eq!(token_client.balance(&landlord), 2_332_800);
+    assert_eq!(client.get_stream_withdrawable(&agreement_id), 0);
+    assert_eq!(client.get_payment_count(), 1);
+
+    env.ledger().with_mut(|li| li.timestamp = 3_092_000);
+    let stranger = Address::generate(&env);
+    let result = client.try_stop_stream(&stranger, &agreement_id);
+    assert_eq!(result, Err(Ok(PaymentError::NotAgreementParty)));
+
+    let stream = client.stop_stream(&tenant, &agreement_id);
+    assert_eq!(stream.stopped_at, Some(3_092_000));
+    assert_eq!(stream.withdrawn, 3_092_000);
+    assert_eq!(stream.periods_recorded, 1);
+    assert_eq!(token_client.balance(&landlord), 2_782_800);
+    assert_eq!(token_client.balance(&tenant), tenant_start - 3_092_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+
+    // The completed period is recorded and reported as paid when it completed
+    let record = client.get_payment(&agreement_id, &1);
+    assert_eq!(record.amount, 2_592_000);
+    assert_eq!(record.landlord_payee, landlord);
+    assert!(record.allocations.get(0).unwrap().completed);
+    let agreement = chioma.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.payment_count, 1);
+    assert_eq!(agreement.next_payment_due, 2_592_000);
+    assert_eq!(
+        agreement.payment_history.get(0).unwrap().payment_date,
+        2_592_000
+    );
+
+    // The unfinished period is carried as a part-payment
+    let record = client.get_payment(&agreement_id, &2);
+    assert_eq!(record.amount, 500_000);
+    assert!(!record.allocations.get(0).unwrap().completed);
+    assert_eq!(client.get_rent_balance(&agreement_id).period_paid, 500_000);
+    assert_eq!(client.get_total_paid(&agreement_id), 3_092_000);
+
+    // Transfers match the legs the records and statement report
+    let statement = client.get_agreement_statement(
+        &agreement_id,
+        &0,
+        &u64::MAX,
+        &StatementCursor::default(),
+        &10,
+    );
+    assert_eq!(statement.landlord_total, token_client.balance(&landlord));
+    assert_eq!(statement.platform_total, 3_092_000 - 2_782_800);
+
+    let result = client.try_withdraw_stream(&agreement_id);
+    assert_eq!(result, Err(Ok(PaymentError::StreamNotActive)));
+}
+
+#[test]
+fn test_stream_rejected_with_outstanding_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, _, _) = setup_payment_contract(&env, "stream_3", 1000);
+    let agreement_id = String::from_str(&env, "stream_3");
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.pay_rent(&tenant, &agreement_id, &400, &payment_ref(&env, "r-1"));
+
+    let result = client.try_start_stream(&tenant, &agreement_id, &1000);
+    assert_eq!(result, Err(Ok(PaymentError::RentBalanceOutstanding)));
+}
+
+#[test]
+fn test_stream_accrual_capped_at_deposit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, token) = setup_payment_contract(&env, "stream_2", 2_592_000);
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "stream_2");
+
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    client.start_stream(&tenant, &agreement_id, &100_000);
+
+    // No period completes before the deposit runs out, so nothing is
+    // withdrawable; stopping pays out what accrued up to the deposit
+    env.ledger().with_mut(|li| li.timestamp = 200_000);
+    assert_eq!(client.get_stream_withdrawable(&agreement_id), 0);
+
+    client.top_up_stream(&tenant, &agreement_id, &50_000);
+    let stream = client.stop_stream(&landlord, &agreement_id);
+    assert_eq!(stream.withdrawn, 150_000);
+    assert_eq!(token_client.balance(&landlord), 135_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_rent_routed_to_obligation_holder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, token) = setup_payment_contract(&env, "agreement_ob", 1000);
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "agreement_ob");
+
+    let obligations = env.register(MockObligationContract, ());
+    client.set_obligation_contract(&obligations);
+    assert_eq!(client.get_obligation_contract(), Some(obligations.clone()));
+
+    // Without a minted obligation the landlord is still paid
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "r-1"));
+    assert_eq!(token_client.balance(&landlord), 900);
+
+    let financier = Address::generate(&env);
+    let obligation_client = MockObligationContractClient::new(&env, &obligations);
+    obligation_client.set_owner(&agreement_id, &financier);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_592_100);
+    client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "r-2"));
+    assert_eq!(token_client.balance(&financier), 900);
+    assert_eq!(token_client.balance(&landlord), 900);
+    assert_eq!(
+        token_client.balance(&client.get_fee_config().collector),
+        200
+    );
+}
+
+#[test]
+fn test_rent_redirected_to_lender_up_to_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, token) =
+        setup_payment_contract_with_agent(&env, "agreement_lc", 1000, None, 0);
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "agreement_lc");
+
+    let obligations = env.register(MockObligationContract, ());
+    client.set_obligation_contract(&obligations);
+    let lender = Address::generate(&env);
+    MockObligationContractClient::new(&env, &obligations).set_lien(
+        &agreement_id,
+        &lender,
+        &landlord,
+        &500,
+    );
+
+    // Only the outstanding principal goes to the lender, the owner gets the rest
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let record = client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "r-1"));
+    assert_eq!(record.landlord_payee, lender);
+    assert_eq!(record.landlord_amount, 900);
+    assert_eq!(record.excess_payee, landlord);
+    assert_eq!(record.excess_amount, 400);
+    assert_eq!(token_client.balance(&lender), 500);
+    assert_eq!(token_client.balance(&landlord), 400);
+
+    let config = client.get_fee_config();
+    client.update_fee_config(&FeeConfig {
+        auto_refund_fee: true,
+        ..config.clone()
+    });
+    token_client.approve(&config.collector, &client.address, &1000, &1000);
+
+    // A refund is returned by each payee in the proportion they were paid
+    let record = client.refund_payment(&agreement_id, &1, &1000);
+    assert_eq!(record.refunds.get(0).unwrap().excess_amount, 400);
+    assert_eq!(token_client.balance(&lender), 0);
+    assert_eq!(token_client.balance(&landlord), 0);
+    assert_eq!(token_client.balance(&config.collector), 0);
+}
+
+#[test]
+fn test_refund_pulled_from_obligation_holder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, token) =
+        setup_payment_contract_with_agent(&env, "agreement_or", 1000, None, 0);
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "agreement_or");
+
+    let obligations = env.register(MockObligationContract, ());
+    client.set_obligation_contract(&obligations);
+    let financier = Address::generate(&env);
+    MockObligationContractClient::new(&env, &obligations).set_owner(&agreement_id, &financier);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let record = client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "r-1"));
+    assert_eq!(record.landlord_payee, financier);
+
+    let config = client.get_fee_config();
+    client.update_fee_config(&FeeConfig {
+        auto_refund_fee: true,
+        ..config.clone()
+    });
+    token_client.approve(&config.collector, &client.address, &1000, &1000);
+
+    // The holder who was paid returns the landlord leg, not the landlord
+    let record = client.refund_payment(&agreement_id, &1, &1000);
+    assert_eq!(record.refunds.get(0).unwrap().periods_reopened, 1);
+    assert_eq!(token_client.balance(&financier), 0);
+    assert_eq!(token_client.balance(&landlord), 0);
+    assert_eq!(token_client.balance(&config.collector), 0);
+
+    // Rent held by the obligation contract itself cannot be clawed back
+    MockObligationContractClient::new(&env, &obligations).set_owner(&agreement_id, &obligations);
+    client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "r-2"));
+    let result = client.try_refund_payment(&agreement_id, &2, &1000);
+    assert_eq!(result, Err(Ok(PaymentError::PayeeCannotRefund)));
+}
+
+#[test]
+fn test_pay_rent_in_allowlisted_asset_through_swap_adapter() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, token) = setup_payment_contract(&env, "agreement_fx", 1000);
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "agreement_fx");
+
+    // Tenant holds another asset; the AMM holds liquidity in the rent token
+    let other_admin = Address::generate(&env);
+    let other = create_token(&env, &other_admin);
+    let other_client = TokenClient::new(&env, &other);
+    TokenAdminClient::new(&env, &other).mint(&tenant, &10_000);
+
+    let amm = env.register(MockSwapAdapter, ());
+    MockSwapAdapterClient::new(&env, &amm).set_price(&2);
+    TokenAdminClient::new(&env, &token).mint(&amm, &100_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let result = client.try_pay_rent_with_asset(
+        &tenant,
+        &agreement_id,
+        &1000,
+        &payment_ref(&env, "fx-1"),
+        &other,
+        &2500,
+    );
+    assert_eq!(result, Err(Ok(PaymentError::AssetNotAllowed)));
+
+    client.set_asset_allowed(&other, &true);
+    let result = client.try_pay_rent_with_asset(
+        &tenant,
+        &agreement_id,
+        &1000,
+        &payment_ref(&env, "fx-1"),
+        &other,
+        &2500,
+    );
+    assert_eq!(result, Err(Ok(PaymentError::SwapAdapterNotSet)));
+
+    client.set_swap_adapter(&amm);
+    let rent_before = token_client.balance(&tenant);
+    client.pay_rent_with_asset(
+        &tenant,
+        &agreement_id,
+        &1000,
+        &payment_ref(&env, "fx-1"),
+        &other,
+        &2500,
+    );
+
+    // Unused input is returned and the landlord receives the agreed token
+    assert_eq!(other_client.balance(&tenant), 8000);
+    assert_eq!(other_client.balance(&amm), 2000);
+    assert_eq!(token_client.balance(&tenant), rent_before);
+    assert_eq!(token_client.balance(&landlord), 900);
+    assert_eq!(other_client.balance(&client.address), 0);
+    assert_eq!(other_client.allowance(&client.address, &amm), 0);
+
+    // A quote above the maximum input reverts the whole payment
+    env.ledger().with_mut(|li| li.timestamp = 2_592_100);
+    let result = client.try_pay_rent_with_asset(
+        &tenant,
+        &agreement_id,
+        &1000,
+        &payment_ref(&env, "fx-2"),
+        &other,
+        &1999,
+    );
+    assert!(result.is_err());
+    assert_eq!(other_client.balance(&tenant), 8000);
+    assert_eq!(client.get_agreement_payment_count(&agreement_id), 1);
+}
+
+#[test]
+fn test_statements_aggregate_payments_by_period_and_party() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let agent = Address::generate(&env);
+    let (client, tenant, landlord, _token) =
+        setup_payment_contract_with_agent(&env, "agreement_st", 1000, Some(agent.clone()), 500);
+    let agreement_id = String::from_str(&env, "agreement_st");
+
+    // First month on time, second month two days late, third month on time
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "st-1"));
+    env.ledger()
+        .with_mut(|li| li.timestamp = 2_592_000 + 172_800);
+    client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "st-2"));
+    env.ledger().with_mut(|li| li.timestamp = 5_184_000);
+    client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "st-3"));
+
+    let statement = client.get_agreement_statement(
+        &agreement_id,
+        &0,
+        &u64::MAX,
+        &StatementCursor::default(),
+        &10,
+    );
+    assert_eq!(statement.payment_count, 3);
+    assert_eq!(statement.late_payment_count, 1);
+    assert_eq!(statement.total_amount, 3000);
+    assert_eq!(statement.platform_total, 300);
+    assert_eq!(statement.agent_total, 135);
+    assert_eq!(statement.landlord_total, 2565);
+
+    // Only the first two months fall in the range
+    let statement = client.get_party_statement(
+        &StatementParty::Landlord,
+        &landlord,
+        &0,
+        &(5_184_000 - 1),
+        &StatementCursor::default(),
+        &10,
+    );
+    assert_eq!(statement.payment_count, 2);
+    assert_eq!(statement.landlord_total, 1710);
+
+    let statement = client.get_party_statement(
+        &StatementParty::Agent,
+        &agent,
+        &0,
+        &u64::MAX,
+        &StatementCursor::default(),
+        &10,
+    );
+    assert_eq!(statement.agent_total, 135);
+
+    let statement = client.get_party_statement(
+        &StatementParty::Agent,
+        &tenant,
+        &0,
+        &u64::MAX,
+        &StatementCursor::default(),
+        &10,
+    );
+    assert_eq!(statement.payment_count, 0);
+
+    // Host-side export reproduces the on-chain statement from decoded records
+    let records: std::vec::Vec<PaymentRecord> = client
+        .list_payments(&agreement_id, &0, &10)
+        .iter()
+        .collect();
+    assert_eq!(
+        crate::export::statement(&records, 0, u64::MAX),
+        client.get_agreement_statement(
+            &agreement_id,
+            &0,
+            &u64::MAX,
+            &StatementCursor::default(),
+            &10
+        )
+    );
+
+    let rows = crate::export::ledger_rows(&records, 0, u64::MAX);
+    assert_eq!(rows.len(), 3);
+    let fields = rows[1].to_csv_record();
+    assert_eq!(fields.len(), crate::export::LEDGER_CSV_HEADER.len());
+    assert_eq!(fields[0], "agreement_st");
+    assert_eq!(fields[1], "2");
+    assert_eq!(fields[5], "855");
+    assert_eq!(fields[11], "true");
+    assert_eq!(fields[12], "73742d32");
+}
+
+#[test]
+fn test_party_statement_paginates_and_credits_landlord_payee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, _token) = setup_payment_contract(&env, "agreement_pg", 1000);
+    let agreement_id = String::from_str(&env, "agreement_pg");
+    let obligations = env.register(MockObligationContract, ());
+    client.set_obligation_contract(&obligations);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    pay_months(&env, &client, &tenant, "agreement_pg", 2);
+
+    // The third month's landlord leg goes to the holder of the obligation
+    let holder = Address::generate(&env);
+    MockObligationContractClient::new(&env, &obligations).set_owner(&agreement_id, &holder);
+    client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "pg-3"));
+
+    let page = client.get_party_statement(
+        &StatementParty::Landlord,
+        &landlord,
+        &0,
+        &u64::MAX,
+        &StatementCursor::default(),
+        &2,
+    );
+    assert_eq!(page.payment_count, 2);
+    assert_eq!(page.landlord_total, 1800);
+    assert!(page.has_more);
+    let cursor = page.next_cursor;
+    assert_eq!(
+        cursor,
+        StatementCursor {
+            agreement_index: 0,
+            payment_number: 2,
+        }
+    );
+
+    let page = client.get_party_statement(
+        &StatementParty::Landlord,
+        &landlord,
+        &0,
+        &u64::MAX,
+        &cursor,
+        &2,
+    );
+    assert_eq!(page.payment_count, 1);
+    assert_eq!(page.total_amount, 1000);
+    assert_eq!(page.landlord_total, 0);
+    assert!(!page.has_more);
+
+    // The agreement statement still reports every landlord leg
+    let statement = client.get_agreement_statement(
+        &agreement_id,
+        &0,
+        &u64::MAX,
+        &StatementCursor::default(),
+        &10,
+    );
+    assert_eq!(statement.landlord_total, 2700);
+    assert!(!statement.has_more);
+}
+
+#[test]
+fn test_autopay_executed_by_keeper_via_allowance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, token) = setup_payment_contract(&env, "agreement_10", 1000);
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "agreement_10");
+
+    client.register_autopay(&tenant, &agreement_id, &1000, &2000, &1000);
+    let mandate = client.get_autopay(&agreement_id).unwrap();
+    assert_eq!(mandate.allowance, 2000);
+    assert_eq!(mandate.allowance_expiration_ledger, 1000);
+
+    let ids = soroban_sdk::vec![&env, agreement_id.clone()];
+    let executed = client.execute_due_payments(&ids);
+    assert_eq!(executed, ids);
+    assert_eq!(token_client.balance(&landlord), 900);
+    assert_eq!(token_client.allowance(&tenant, &client.address), 1000);
+
+    // Not due again until the next period
+    assert_eq!(client.execute_due_payments(&ids).len(), 0);
+
+    env.ledger().with_mut(|li| li.timestamp += 2_592_000);
+    assert_eq!(client.execute_due_payments(&ids), ids);
+    assert_eq!(token_client.balance(&landlord), 1800);
+
+    let mandate = client.get_autopay(&agreement_id).unwrap();
+    assert_eq!(mandate.payments_executed, 2);
+    assert_eq!(mandate.last_executed_at, Some(2_592_000));
+    assert_eq!(client.get_agreement_payment_count(&agreement_id), 2);
+
+    // Allowance is exhausted
+    env.ledger().with_mut(|li| li.timestamp += 2_592_000);
+    assert_eq!(client.execute_due_payments(&ids).len(), 0);
+}
+
+#[test]
+fn test_autopay_respects_cap_and_cancellation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, token) = setup_payment_contract(&env, "agreement_11", 1000);
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "agreement_11");
+    let ids = soroban_sdk::vec![&env, agreement_id.clone(), String::from_str(&env, "none")];
+
+    // Rent above the per-period cap is not pulled
+    client.register_autopay(&tenant, &agreement_id, &999, &10_000, &1000);
+    assert_eq!(client.execute_due_payments(&ids).len(), 0);
+
+    client.register_autopay(&tenant, &agreement_id, &1000, &10_000, &1000);
+    client.cancel_autopay(&tenant, &agreement_id);
+    assert_eq!(client.get_autopay(&agreement_id), None);
+    assert_eq!(token_client.allowance(&tenant, &client.address), 0);
+    assert_eq!(client.execute_due_payments(&ids).len(), 0);
+    assert_eq!(token_client.balance(&landlord), 0);
+
+    let result = client.try_cancel_autopay(&tenant, &agreement_id);
+    assert_eq!(result, Err(Ok(PaymentError::AutopayNotFound)));
+
+    let result = client.try_register_autopay(&landlord, &agreement_id, &1000, &10_000, &1000);
+    assert_eq!(result, Err(Ok(PaymentError::NotTenant)));
+}
+
+#[test]
+fn test_autopay_batch_skips_unfunded_or_expired_mandates() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, token) = setup_payment_contract(&env, "agreement_30", 1000);
+    let chioma = MockChiomaContractClient::new(&env, &client.get_chioma_contract());
+    let token_client = TokenClient::new(&env, &token);
+    let paid_id = String::from_str(&env, "agreement_30");
+
+    // A second tenant who approved enough but cannot cover the rent
+    let broke_tenant = Address::generate(&env);
+    TokenAdminClient::new(&env, &token).mint(&broke_tenant, &500);
+    chioma.add_agreement(&create_test_agreement(
+        &env,
+        "agreement_31",
+        &broke_tenant,
+        &landlord,
+        None,
+        1000,
+        0,
+        AgreementStatus::Active,
+        token.clone(),
+    ));
+    let broke_id = String::from_str(&env, "agreement_31");
+
+    env.ledger().with_mut(|li| li.sequence_number = 10);
+    let result = client.try_register_autopay(&tenant, &paid_id, &1000, &5000, &5);
+    assert_eq!(result, Err(Ok(PaymentError::AllowanceExpired)));
+
+    client.register_autopay(&broke_tenant, &broke_id, &1000, &5000, &1000);
+    client.register_autopay(&tenant, &paid_id, &1000, &5000, &1000);
+
+    let ids = soroban_sdk::vec![&env, broke_id.clone(), paid_id.clone()];
+    assert_eq!(
+        client.execute_due_payments(&ids),
+        soroban_sdk::vec![&env, paid_id.clone()]
+    );
+    assert_eq!(token_client.balance(&broke_tenant), 500);
+    assert_eq!(token_client.balance(&landlord), 900);
+
+    // Past the mandate's allowance expiry nothing is pulled
+    env.ledger().with_mut(|li| {
+        li.timestamp += 2_592_000;
+        li.sequence_number = 1001;
+    });
+    assert_eq!(client.execute_due_payments(&ids).len(), 0);
+    assert_eq!(token_client.balance(&landlord), 900);
+}
+
+#[test]
+fn test_partial_payments_settle_period_in_installments() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, token) = setup_payment_contract(&env, "agreement_12", 1000);
+    let chioma = MockChiomaContractClient::new(&env, &client.get_chioma_contract());
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "agreement_12");
+
+    let receipt = client.pay_rent(&tenant, &agreement_id, &400, &payment_ref(&env, "inv-6"));
+    let allocation = receipt.allocations.get(0).unwrap();
+    assert_eq!(allocation.period_due, 0);
+    assert_eq!(allocation.amount, 400);
+    assert!(!allocation.completed);
+    assert_eq!(client.get_rent_balance(&agreement_id).period_paid, 400);
+    assert_eq!(token_client.balance(&landlord), 360);
+    assert_eq!(
+        chioma.get_agreement(&agreement_id).unwrap().payment_count,
+        0
+    );
+
+    let receipt = client.pay_rent(&tenant, &agreement_id, &600, &payment_ref(&env, "inv-7"));
+    let allocation = receipt.allocations.get(0).unwrap();
+    assert_eq!(allocation.amount, 600);
+    assert!(allocation.completed);
+    assert_eq!(
+        client.get_rent_balance(&agreement_id),
+        RentBalance::default()
+    );
+    assert_eq!(token_client.balance(&landlord), 900);
+
+    let agreement = chioma.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.payment_count, 1);
+    assert_eq!(agreement.next_payment_due, 2_592_000);
+    assert_eq!(client.get_total_paid(&agreement_id), 1000);
+}
+
+#[test]
+fn test_prepayment_held_as_credit_and_consumed_later() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, token) = setup_payment_contract(&env, "agreement_13", 1000);
+    let chioma = MockChiomaContractClient::new(&env, &client.get_chioma_contract());
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "agreement_13");
+
+    // Pay the due period and prepay two more
+    let receipt = client.pay_rent(&tenant, &agreement_id, &3000, &payment_ref(&env, "inv-8"));
+    assert_eq!(receipt.allocations.len(), 1);
+    assert_eq!(receipt.credit_added, 2000);
+    assert_eq!(client.get_rent_balance(&agreement_id).credit, 2000);
+    assert_eq!(token_client.balance(&client.address), 2000);
+    assert_eq!(token_client.balance(&landlord), 900);
+
+    // Credit cannot be applied before the next period is due
+    let result = client.try_apply_credit(&agreement_id);
+    assert_eq!(result, Err(Ok(PaymentError::PaymentNotDue)));
+
+    env.ledger().with_mut(|li| li.timestamp = 2_592_000);
+    let receipt = client.apply_credit(&agreement_id);
+    assert_eq!(receipt.amount, 0);
+    assert_eq!(receipt.credit_used, 1000);
+    assert_eq!(client.get_rent_balance(&agreement_id).credit, 1000);
+    assert_eq!(token_client.balance(&landlord), 1800);
+
+    // A later payment uses the remaining credit before new funds
+    env.ledger().with_mut(|li| li.timestamp = 2 * 2_592_000);
+    let receipt = client.pay_rent(&tenant, &agreement_id, &500, &payment_ref(&env, "inv-9"));
+    assert_eq!(receipt.credit_used, 1000);
+    assert_eq!(receipt.credit_added, 500);
+    assert_eq!(receipt.allocations.get(0).unwrap().amount, 1000);
+    assert_eq!(client.get_rent_balance(&agreement_id).credit, 500);
+    assert_eq!(
+        chioma.get_agreement(&agreement_id).unwrap().payment_count,
+        3
+    );
+}
+
+#[test]
+fn test_prepaid_credit_counted_and_applied_as_periods_fall_due() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, token) = setup_payment_contract(&env, "agreement_34", 1000);
+    let chioma = MockChiomaContractClient::new(&env, &client.get_chioma_contract());
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "agreement_34");
+
+    // Three periods paid up front, plus half of a fourth
+    client.pay_rent(&tenant, &agreement_id, &3500, &payment_ref(&env, "inv-34"));
+    assert_eq!(client.get_paid_through(&agreement_id), 3 * 2_592_000);
+
+    // Once the second period falls due, chioma still has it outstanding but
+    // the credit covers it, so it is not overdue
+    let due = 2_592_000;
+    env.ledger().with_mut(|li| li.timestamp = due + 86_400);
+    assert_eq!(
+        chioma
+            .get_agreement(&agreement_id)
+            .unwrap()
+            .next_payment_due,
+        due
+    );
+    assert_eq!(client.get_paid_through(&agreement_id), 3 * due);
+
+    // A keeper run applies the credit without an autopay mandate
+    let executed = client.execute_due_payments(&soroban_sdk::vec![&env, agreement_id.clone()]);
+    assert_eq!(executed.len(), 1);
+    assert_eq!(
+        chioma
+            .get_agreement(&agreement_id)
+            .unwrap()
+            .next_payment_due,
+        2 * due
+    );
+    assert_eq!(client.get_rent_balance(&agreement_id).credit, 1500);
+    assert_eq!(token_client.balance(&landlord), 1800);
+    assert_eq!(client.get_paid_through(&agreement_id), 3 * due);
+
+    // Nothing further is applied until the next period falls due
+    let executed = client.execute_due_payments(&soroban_sdk::vec![&env, agreement_id.clone()]);
+    assert_eq!(executed.len(), 0);
+
+    // Once the credit runs out the uncovered period is overdue again
+    env.ledger().with_mut(|li| li.timestamp = 3 * due + 86_400);
+    client.execute_due_payments(&soroban_sdk::vec![&env, agreement_id.clone()]);
+    let balance = client.get_rent_balance(&agreement_id);
+    assert_eq!(balance.credit, 0);
+    assert_eq!(balance.period_paid, 500);
+    assert_eq!(client.get_paid_through(&agreement_id), 3 * due);
+}
+
+#[test]
+fn test_credit_applied_late_dates_period_at_due_date() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, _, _) = setup_payment_contract(&env, "agreement_32", 1000);
+    let chioma = MockChiomaContractClient::new(&env, &client.get_chioma_contract());
+    let agreement_id = String::from_str(&env, "agreement_32");
+
+    client.pay_rent(&tenant, &agreement_id, &2000, &payment_ref(&env, "inv-32"));
+
+    // Nobody applies the credit until well after the second period fell due
+    let due = 2_592_000;
+    env.ledger().with_mut(|li| li.timestamp = due + 10 * 86_400);
+    let receipt = client.apply_credit(&agreement_id);
+
+    let allocation = receipt.allocations.get(0).unwrap();
+    assert_eq!(allocation.period_due, due);
+    assert_eq!(allocation.settled_at, due);
+    assert!(!crate::statement::is_late(&receipt));
+
+    let history = chioma.get_agreement(&agreement_id).unwrap().payment_history;
+    assert_eq!(history.get(1).unwrap().payment_date, due);
+
+    let statement = client.get_agreement_statement(
+        &agreement_id,
+        &0,
+        &u64::MAX,
+        &StatementCursor::default(),
+        &10,
+    );
+    assert_eq!(statement.late_payment_count, 0);
+
+    // Fresh funds paid after the grace period are still late
+    env.ledger()
+        .with_mut(|li| li.timestamp = 2 * due + 10 * 86_400);
+    let receipt = client.pay_rent(&tenant, &agreement_id, &1000, &payment_ref(&env, "inv-33"));
+    assert_eq!(
+        receipt.allocations.get(0).unwrap().settled_at,
+        2 * due + 10 * 86_400
+    );
+    assert!(crate::statement::is_late(&receipt));
+}
+
+#[test]
+fn test_payment_applied_to_oldest_overdue_period_first() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, _landlord, _token) = setup_payment_contract(&env, "agreement_14", 1000);
+    let agreement_id = String::from_str(&env, "agreement_14");
+
+    // Two periods are overdue
+    env.ledger().with_mut(|li| li.timestamp = 2_592_000 + 10);
+    let receipt = client.pay_rent(&tenant, &agreement_id, &1500, &payment_ref(&env, "inv-10"));
+
+    assert_eq!(receipt.allocations.len(), 2);
+    let first = receipt.allocations.get(0).unwrap();
+    let second = receipt.allocations.get(1).unwrap();
+    assert_eq!(
+        (first.period_due, first.amount, first.completed),
+        (0, 1000, true)
+    );
+    assert_eq!(
+        (second.period_due, second.amount, second.completed),
+        (2_592_000, 500, false)
+    );
+    assert_eq!(receipt.credit_added, 0);
+
+    let result = client.try_pay_rent(&tenant, &agreement_id, &0, &payment_ref(&env, "inv-11"));
+    assert_eq!(result, Err(Ok(PaymentError::InvalidPaymentAmount)));
+}
@@ -1,5 +1,5 @@
 //! Data structures for the Payment contract.
-use soroban_sdk::{contracttype, Address, Map, String};
+use soroban_sdk::{contracttype, Address, Bytes, Map, String, Vec};
 
 /// Payment record for tracking individual payments
 #[contracttype]
@@ -8,13 +8,179 @@ pub struct PaymentRecord {
     pub agreement_id: String,
     pub payment_number: u32,
     pub amount: i128,
+    pub platform_amount: i128,
+    pub agent_amount: i128,
+    pub landlord_amount: i128,
+    pub timestamp: u64,
+    pub tenant: Address,
+    /// Address that received the landlord leg: the landlord, or the rent
+    /// obligation payee at the time of payment
+    pub landlord_payee: Address,
+    /// Obligation owner paid the part of the landlord leg above the payee's
+    /// cap; the same as `landlord_payee` when the payee had no cap
+    pub excess_payee: Address,
+    /// Part of `landlord_amount` paid to `excess_payee`
+    pub excess_amount: i128,
+    /// How the payment and any consumed credit were applied to rent periods
+    pub allocations: Vec<PeriodAllocation>,
+    /// Credit balance consumed by this payment
+    pub credit_used: i128,
+    /// Part of this payment added to the credit balance
+    pub credit_added: i128,
+    /// Client payment reference, if the payment was made with one
+    pub reference: Option<Bytes>,
+    /// Refunds made against this payment; empty if it was never refunded
+    pub refunds: Vec<PaymentRefund>,
+}
+
+/// A refund of part or all of a payment back to its payer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentRefund {
+    /// Amount returned to the payer
+    pub amount: i128,
+    /// Part returned from credit the payment left with the contract
+    pub from_credit: i128,
+    /// Part of the platform fee clawed back
+    pub platform_amount: i128,
+    /// Part funded by the payees of the landlord leg
     pub landlord_amount: i128,
+    /// Part of `landlord_amount` funded by the payment's `excess_payee`
+    pub excess_amount: i128,
+    /// Part of the agent commission returned, funded by the landlord
     pub agent_amount: i128,
+    /// Paid rent periods reopened because the refund left them short
+    pub periods_reopened: u32,
     pub timestamp: u64,
+}
+
+impl PaymentRecord {
+    /// Total amount refunded against this payment
+    pub fn refunded_amount(&self) -> i128 {
+        self.refunds.iter().map(|refund| refund.amount).sum()
+    }
+
+    /// Part of the landlord leg paid to `landlord_payee`
+    pub fn payee_amount(&self) -> i128 {
+        self.landlord_amount - self.excess_amount
+    }
+}
+
+/// Where the landlord leg of an agreement's rent is paid (mirrors the rent
+/// obligation contract's `RentPayee`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentPayee {
+    /// Address paid the landlord leg, up to `cap`
+    pub payee: Address,
+    /// Most `payee` is owed, or None if it takes the whole leg
+    pub cap: Option<i128>,
+    /// Obligation owner, paid whatever exceeds `cap`
+    pub owner: Address,
+}
+
+impl RentPayee {
+    /// Split a landlord leg into the payee's part and the excess over its
+    /// cap, reducing the cap by what the payee takes
+    pub fn split(&mut self, landlord_amount: i128) -> (i128, i128) {
+        let Some(cap) = self.cap else {
+            return (landlord_amount, 0);
+        };
+        let payee_amount = landlord_amount.min(cap.max(0));
+        self.cap = Some(cap - payee_amount);
+        (payee_amount, landlord_amount - payee_amount)
+    }
+}
+
+/// Rent streamed from a tenant deposit, accruing per second
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentStream {
+    pub agreement_id: String,
     pub tenant: Address,
+    /// Monthly rent the per-second rate is derived from
+    pub monthly_rent: i128,
+    /// Timestamp accrual starts from
+    pub start_time: u64,
+    /// Total deposited by the tenant
+    pub deposited: i128,
+    /// Accrued rent already paid out
+    pub withdrawn: i128,
+    /// Rent periods paid out in full, each recorded and reported to chioma
+    pub periods_recorded: u32,
+    /// Timestamp the stream was stopped, if it was
+    pub stopped_at: Option<u64>,
+}
+
+/// Role a party plays in the agreements covered by a statement
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StatementParty {
+    Landlord,
+    Tenant,
+    Agent,
+}
+
+/// Payments aggregated over a time range
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentStatement {
+    /// Start of the range, inclusive
+    pub from_time: u64,
+    /// End of the range, inclusive
+    pub to_time: u64,
+    pub payment_count: u32,
+    /// Payments that settled a period after its grace period
+    pub late_payment_count: u32,
+    /// Total paid in by tenants, including amounts held as credit
+    pub total_amount: i128,
+    pub landlord_total: i128,
+    pub agent_total: i128,
+    pub platform_total: i128,
+    /// Refunds made against the payments in the range
+    pub refunded_total: i128,
+    /// Whether payments remain after this page
+    pub has_more: bool,
+    /// Where the next page of the statement starts, if `has_more`
+    pub next_cursor: StatementCursor,
+}
+
+/// Position in a paginated statement: the payments after `payment_number`
+/// of the `agreement_index`-th agreement come next. Agreement statements
+/// cover a single agreement, so their `agreement_index` is always 0.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StatementCursor {
+    pub agreement_index: u32,
+    pub payment_number: u32,
+}
+
+/// Portion of a payment applied to a single rent period
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PeriodAllocation {
+    /// Due date of the rent period
+    pub period_due: u64,
+    /// Amount applied to the period
+    pub amount: i128,
+    /// Whether this allocation settled the period in full
+    pub completed: bool,
+    /// When the applied funds were available: the due date for a period
+    /// settled from prepaid credit, otherwise the payment time
+    pub settled_at: u64,
+}
+
+/// Rent balance carried between payments for an agreement
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RentBalance {
+    /// Prepaid credit held by the contract for future periods
+    pub credit: i128,
+    /// Amount already paid towards the oldest outstanding period
+    pub period_paid: i128,
 }
 
-/// Payment split information for rent payments
+/// Payment split information for rent payments (mirrors chioma's `PaymentSplit`)
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PaymentSplit {
@@ -22,6 +188,38 @@ pub struct PaymentSplit {
     pub platform_amount: i128,
     pub token: Address,
     pub payment_date: u64,
+    pub payer: Address,
+}
+
+/// Platform fee configuration
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    /// Platform fee in basis points (1 basis point = 0.01%)
+    pub fee_bps: u32,
+    /// Address receiving platform fees
+    pub collector: Address,
+    /// Claw back the platform fee on refunds from an allowance the collector
+    /// granted this contract, rather than requiring the collector's signature
+    pub auto_refund_fee: bool,
+}
+
+/// Standing autopay mandate registered by a tenant for an agreement
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutopayMandate {
+    pub agreement_id: String,
+    pub tenant: Address,
+    /// Maximum amount that may be pulled for a single rent period
+    pub max_amount_per_period: i128,
+    /// Allowance the tenant approved when registering the mandate
+    pub allowance: i128,
+    /// Ledger sequence after which the approved allowance expires
+    pub allowance_expiration_ledger: u32,
+    pub created_at: u64,
+    /// Timestamp of the last payment executed under this mandate
+    pub last_executed_at: Option<u64>,
+    pub payments_executed: u32,
 }
 
 /// Agreement status enum (needed for payment validation)
@@ -37,7 +235,7 @@ pub enum AgreementStatus {
     Disputed,
 }
 
-/// Rent agreement structure (needed for payment processing)
+/// Rent agreement structure (mirrors chioma's `RentAgreement`)
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RentAgreement {
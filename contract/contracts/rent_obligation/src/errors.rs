@@ -10,4 +10,25 @@ pub enum ObligationError {
     ObligationNotFound = 4,
     Unauthorized = 5,
     InvalidOwner = 6,
+    IndexOutOfBounds = 7,
+    TokenUriTooLong = 8,
+    AgreementNotFound = 9,
+    AgreementNotActive = 10,
+    NotAgreementLandlord = 11,
+    AlreadyFractionalized = 12,
+    NotFractionalized = 13,
+    InsufficientShares = 14,
+    InvalidShareAmount = 15,
+    InvalidPrice = 16,
+    SaleTokenNotAllowed = 17,
+    ListingNotFound = 18,
+    PriceMismatch = 19,
+    OfferNotFound = 20,
+    OfferExpired = 21,
+    InvalidFee = 22,
+    LienActive = 23,
+    LienNotFound = 24,
+    InvalidPrincipal = 25,
+    PaymentContractNotSet = 26,
+    LienOutstanding = 27,
 }
@@ -1,8 +1,8 @@
 use soroban_sdk::{contractevent, Address, Env, String};
 
 /// Event emitted when a rent obligation NFT is minted
-/// Topics: ["minted", landlord: Address]
-#[contractevent(topics = ["minted"])]
+/// Topics: ["mint", landlord: Address]
+#[contractevent(topics = ["mint"])]
 pub struct ObligationMinted {
     #[topic]
     pub landlord: Address,
@@ -11,8 +11,8 @@ pub struct ObligationMinted {
 }
 
 /// Event emitted when a rent obligation NFT is transferred
-/// Topics: ["transferred", from: Address, to: Address]
-#[contractevent(topics = ["transferred"])]
+/// Topics: ["transfer", from: Address, to: Address]
+#[contractevent(topics = ["transfer"])]
 pub struct ObligationTransferred {
     #[topic]
     pub from: Address,
@@ -21,6 +21,186 @@ pub struct ObligationTransferred {
     pub agreement_id: String,
 }
 
+/// Event emitted when an address is approved to transfer a single obligation
+/// Topics: ["approve", owner: Address, agreement_id: String]
+#[contractevent(topics = ["approve"])]
+pub struct ObligationApproved {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub agreement_id: String,
+    pub approved: Option<Address>,
+}
+
+/// Event emitted when an operator is approved for all of an owner's obligations
+/// Topics: ["approve_for_all", owner: Address]
+#[contractevent(topics = ["approve_for_all"])]
+pub struct ApprovalForAll {
+    #[topic]
+    pub owner: Address,
+    pub operator: Address,
+    pub approved: bool,
+}
+
+/// Event emitted when a rent obligation NFT is burned
+/// Topics: ["burn", owner: Address]
+#[contractevent(topics = ["burn"])]
+pub struct ObligationBurned {
+    #[topic]
+    pub owner: Address,
+    pub agreement_id: String,
+    pub burned_by: Address,
+}
+
+/// Event emitted when an obligation is split into shares
+/// Topics: ["fractionalize", owner: Address]
+#[contractevent(topics = ["fractionalize"])]
+pub struct ObligationFractionalized {
+    #[topic]
+    pub owner: Address,
+    pub agreement_id: String,
+    pub total_shares: i128,
+}
+
+/// Event emitted when obligation shares change hands
+/// Topics: ["share_transfer", from: Address, to: Address]
+#[contractevent(topics = ["share_transfer"])]
+pub struct SharesTransferred {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub agreement_id: String,
+    pub amount: i128,
+}
+
+/// Event emitted when a shareholder claims rent
+/// Topics: ["rent_claim", holder: Address]
+#[contractevent(topics = ["rent_claim"])]
+pub struct RentClaimed {
+    #[topic]
+    pub holder: Address,
+    pub agreement_id: String,
+    pub amount: i128,
+}
+
+/// Event emitted when all shares are recombined into single ownership
+/// Topics: ["recombine", owner: Address]
+#[contractevent(topics = ["recombine"])]
+pub struct ObligationRecombined {
+    #[topic]
+    pub owner: Address,
+    pub agreement_id: String,
+}
+
+/// Event emitted when the marketplace fee changes
+/// Topics: ["market_fee", admin: Address]
+#[contractevent(topics = ["market_fee"])]
+pub struct MarketFeeUpdated {
+    #[topic]
+    pub admin: Address,
+    pub collector: Address,
+    pub fee_bps: u32,
+}
+
+/// Event emitted when a token is added to or removed from the sale allowlist
+/// Topics: ["sale_token", token: Address]
+#[contractevent(topics = ["sale_token"])]
+pub struct SaleTokenAllowed {
+    #[topic]
+    pub token: Address,
+    pub allowed: bool,
+}
+
+/// Event emitted when the payment contract allowed to report rent changes
+/// Topics: ["payment_contract", admin: Address]
+#[contractevent(topics = ["payment_contract"])]
+pub struct PaymentContractUpdated {
+    #[topic]
+    pub admin: Address,
+    pub payment_contract: Address,
+}
+
+/// Event emitted when an obligation is listed for sale
+/// Topics: ["list", seller: Address]
+#[contractevent(topics = ["list"])]
+pub struct ObligationListed {
+    #[topic]
+    pub seller: Address,
+    pub agreement_id: String,
+    pub token: Address,
+    pub price: i128,
+}
+
+/// Event emitted when a listing is withdrawn
+/// Topics: ["delist", seller: Address]
+#[contractevent(topics = ["delist"])]
+pub struct ObligationDelisted {
+    #[topic]
+    pub seller: Address,
+    pub agreement_id: String,
+}
+
+/// Event emitted for every marketplace sale, by listing or by offer
+/// Topics: ["sale", seller: Address, buyer: Address]
+#[contractevent(topics = ["sale"])]
+pub struct ObligationSold {
+    #[topic]
+    pub seller: Address,
+    #[topic]
+    pub buyer: Address,
+    pub agreement_id: String,
+    pub token: Address,
+    pub price: i128,
+    pub platform_fee: i128,
+}
+
+/// Event emitted when a bidder escrows an offer
+/// Topics: ["offer", bidder: Address]
+#[contractevent(topics = ["offer"])]
+pub struct OfferMade {
+    #[topic]
+    pub bidder: Address,
+    pub agreement_id: String,
+    pub token: Address,
+    pub amount: i128,
+    pub expires_at: u64,
+}
+
+/// Event emitted when an offer is withdrawn and its escrow returned
+/// Topics: ["offer_cancel", bidder: Address]
+#[contractevent(topics = ["offer_cancel"])]
+pub struct OfferCancelled {
+    #[topic]
+    pub bidder: Address,
+    pub agreement_id: String,
+    pub amount: i128,
+}
+
+/// Event emitted when an obligation is pledged to a lender
+/// Topics: ["lien", owner: Address, lender: Address]
+#[contractevent(topics = ["lien"])]
+pub struct LienPlaced {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub lender: Address,
+    pub agreement_id: String,
+    pub principal: i128,
+    pub redirect_rent: bool,
+}
+
+/// Event emitted when a lender releases a lien
+/// Topics: ["lien_release", owner: Address, lender: Address]
+#[contractevent(topics = ["lien_release"])]
+pub struct LienReleased {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub lender: Address,
+    pub agreement_id: String,
+}
+
 /// Helper function to emit obligation minted event
 pub(crate) fn obligation_minted(
     env: &Env,
@@ -45,3 +225,223 @@ pub(crate) fn obligation_transferred(env: &Env, agreement_id: String, from: Addr
     }
     .publish(env);
 }
+
+/// Helper function to emit obligation approved event
+pub(crate) fn obligation_approved(
+    env: &Env,
+    owner: Address,
+    agreement_id: String,
+    approved: Option<Address>,
+) {
+    ObligationApproved {
+        owner,
+        agreement_id,
+        approved,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit approval for all event
+pub(crate) fn approval_for_all(env: &Env, owner: Address, operator: Address, approved: bool) {
+    ApprovalForAll {
+        owner,
+        operator,
+        approved,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit obligation burned event
+pub(crate) fn obligation_burned(
+    env: &Env,
+    agreement_id: String,
+    owner: Address,
+    burned_by: Address,
+) {
+    ObligationBurned {
+        owner,
+        agreement_id,
+        burned_by,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit obligation fractionalized event
+pub(crate) fn obligation_fractionalized(
+    env: &Env,
+    agreement_id: String,
+    owner: Address,
+    total_shares: i128,
+) {
+    ObligationFractionalized {
+        owner,
+        agreement_id,
+        total_shares,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit shares transferred event
+pub(crate) fn shares_transferred(
+    env: &Env,
+    agreement_id: String,
+    from: Address,
+    to: Address,
+    amount: i128,
+) {
+    SharesTransferred {
+        from,
+        to,
+        agreement_id,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit rent claimed event
+pub(crate) fn rent_claimed(env: &Env, agreement_id: String, holder: Address, amount: i128) {
+    RentClaimed {
+        holder,
+        agreement_id,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit obligation recombined event
+pub(crate) fn obligation_recombined(env: &Env, agreement_id: String, owner: Address) {
+    ObligationRecombined {
+        owner,
+        agreement_id,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit market fee updated event
+pub(crate) fn market_fee_updated(env: &Env, admin: Address, collector: Address, fee_bps: u32) {
+    MarketFeeUpdated {
+        admin,
+        collector,
+        fee_bps,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit sale token allowed event
+pub(crate) fn sale_token_allowed(env: &Env, token: Address, allowed: bool) {
+    SaleTokenAllowed { token, allowed }.publish(env);
+}
+
+/// Helper function to emit payment contract updated event
+pub(crate) fn payment_contract_updated(env: &Env, admin: Address, payment_contract: Address) {
+    PaymentContractUpdated {
+        admin,
+        payment_contract,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit obligation listed event
+pub(crate) fn obligation_listed(
+    env: &Env,
+    agreement_id: String,
+    seller: Address,
+    token: Address,
+    price: i128,
+) {
+    ObligationListed {
+        seller,
+        agreement_id,
+        token,
+        price,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit obligation delisted event
+pub(crate) fn obligation_delisted(env: &Env, agreement_id: String, seller: Address) {
+    ObligationDelisted {
+        seller,
+        agreement_id,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit obligation sold event
+pub(crate) fn obligation_sold(
+    env: &Env,
+    agreement_id: String,
+    seller: Address,
+    buyer: Address,
+    token: Address,
+    price: i128,
+    platform_fee: i128,
+) {
+    ObligationSold {
+        seller,
+        buyer,
+        agreement_id,
+        token,
+        price,
+        platform_fee,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit offer made event
+pub(crate) fn offer_made(
+    env: &Env,
+    agreement_id: String,
+    bidder: Address,
+    token: Address,
+    amount: i128,
+    expires_at: u64,
+) {
+    OfferMade {
+        bidder,
+        agreement_id,
+        token,
+        amount,
+        expires_at,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit offer cancelled event
+pub(crate) fn offer_cancelled(env: &Env, agreement_id: String, bidder: Address, amount: i128) {
+    OfferCancelled {
+        bidder,
+        agreement_id,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit lien placed event
+pub(crate) fn lien_placed(
+    env: &Env,
+    agreement_id: String,
+    owner: Address,
+    lender: Address,
+    principal: i128,
+    redirect_rent: bool,
+) {
+    LienPlaced {
+        owner,
+        lender,
+        agreement_id,
+        principal,
+        redirect_rent,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit lien released event
+pub(crate) fn lien_released(env: &Env, agreement_id: String, owner: Address, lender: Address) {
+    LienReleased {
+        owner,
+        lender,
+        agreement_id,
+    }
+    .publish(env);
+}
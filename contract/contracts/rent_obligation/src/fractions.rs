@@ -0,0 +1,334 @@
+//! Fractional ownership of rent obligations.
+//!
+//! An owner can split an obligation into a fixed number of transferable
+//! shares. The obligation is then held by this contract, so rent routed to
+//! its owner lands here and is claimable by shareholders pro rata. Only rent
+//! the payment contract reports paying to this contract is credited, and it
+//! is folded into an accumulated rent-per-share index so shares can change
+//! hands without losing what their previous holders earned. Claims never
+//! exceed the rent credited. A holder of every share can recombine them to
+//! take back single ownership, and may fractionalize again later. Each
+//! fractionalization starts a new series, so holders of earlier series keep
+//! what they earned before recombination.
+use soroban_sdk::{token, Address, Env, String};
+
+use crate::errors::ObligationError;
+use crate::events;
+use crate::nft;
+use crate::storage::DataKey;
+use crate::types::{FractionInfo, Shareholder};
+
+/// Fixed-point scale of the rent-per-share index
+const RENT_PER_SHARE_SCALE: i128 = 1_000_000_000_000;
+
+fn series_count(env: &Env, agreement_id: &String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FractionSeriesCount(agreement_id.clone()))
+        .unwrap_or(0)
+}
+
+fn get_series(env: &Env, agreement_id: &String, generation: u32) -> Option<FractionInfo> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Fractions(agreement_id.clone(), generation))
+}
+
+/// Latest share series of an obligation
+pub(crate) fn get_fractions(env: &Env, agreement_id: &String) -> Option<FractionInfo> {
+    let count = series_count(env, agreement_id);
+    if count == 0 {
+        return None;
+    }
+    get_series(env, agreement_id, count - 1)
+}
+
+fn set_fractions(env: &Env, fractions: &FractionInfo) {
+    let key = DataKey::Fractions(fractions.agreement_id.clone(), fractions.generation);
+    env.storage().persistent().set(&key, fractions);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+}
+
+fn get_series_shareholder(
+    env: &Env,
+    agreement_id: &String,
+    generation: u32,
+    holder: &Address,
+) -> Shareholder {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Shareholder(
+            agreement_id.clone(),
+            generation,
+            holder.clone(),
+        ))
+        .unwrap_or(Shareholder {
+            balance: 0,
+            rent_debt: 0,
+            unclaimed: 0,
+        })
+}
+
+/// A holder's position in the latest share series
+pub(crate) fn get_shareholder(env: &Env, agreement_id: &String, holder: &Address) -> Shareholder {
+    let generation = series_count(env, agreement_id).saturating_sub(1);
+    get_series_shareholder(env, agreement_id, generation, holder)
+}
+
+fn set_shareholder(
+    env: &Env,
+    fractions: &FractionInfo,
+    holder: &Address,
+    shareholder: &Shareholder,
+) {
+    let key = DataKey::Shareholder(
+        fractions.agreement_id.clone(),
+        fractions.generation,
+        holder.clone(),
+    );
+    env.storage().persistent().set(&key, shareholder);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+}
+
+/// Credit rent paid to this contract for a fractionalized obligation to the
+/// holders of its latest series. Rent arriving after recombination is not
+/// pooled.
+pub(crate) fn credit_rent(env: &Env, agreement_id: &String, amount: i128) {
+    let Some(mut fractions) = get_fractions(env, agreement_id) else {
+        return;
+    };
+    if fractions.recombined_at.is_some() || amount <= 0 {
+        return;
+    }
+
+    fractions.rent_per_share += amount * RENT_PER_SHARE_SCALE / fractions.total_shares;
+    fractions.rent_received += amount;
+    set_fractions(env, &fractions);
+}
+
+/// Move a holder's earnings up to the current index into `unclaimed`
+fn accrue(fractions: &FractionInfo, shareholder: &mut Shareholder) {
+    let earned = shareholder.balance * fractions.rent_per_share / RENT_PER_SHARE_SCALE;
+    shareholder.unclaimed += earned - shareholder.rent_debt;
+    shareholder.rent_debt = earned;
+}
+
+fn rebase(fractions: &FractionInfo, shareholder: &mut Shareholder) {
+    shareholder.rent_debt = shareholder.balance * fractions.rent_per_share / RENT_PER_SHARE_SCALE;
+}
+
+/// Split an obligation into `total_shares` shares held by its owner
+pub(crate) fn fractionalize(
+    env: &Env,
+    owner: Address,
+    agreement_id: String,
+    total_shares: i128,
+) -> Result<FractionInfo, ObligationError> {
+    owner.require_auth();
+
+    let obligation = nft::get_obligation(env, &agreement_id)?;
+    if obligation.owner != owner {
+        return Err(ObligationError::Unauthorized);
+    }
+
+    if total_shares <= 0 {
+        return Err(ObligationError::InvalidShareAmount);
+    }
+
+    // A recombined series stays on record for its holders' claims
+    if get_fractions(env, &agreement_id).is_some_and(|f| f.recombined_at.is_none()) {
+        return Err(ObligationError::AlreadyFractionalized);
+    }
+
+    let agreement = nft::load_agreement(env, &agreement_id)?;
+
+    let generation = series_count(env, &agreement_id);
+    let fractions = FractionInfo {
+        agreement_id: agreement_id.clone(),
+        generation,
+        total_shares,
+        token: agreement.payment_token,
+        fractionalized_at: env.ledger().timestamp(),
+        recombined_at: None,
+        rent_per_share: 0,
+        rent_received: 0,
+        rent_claimed: 0,
+    };
+    set_fractions(env, &fractions);
+    let count_key = DataKey::FractionSeriesCount(agreement_id.clone());
+    env.storage()
+        .persistent()
+        .set(&count_key, &(generation + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&count_key, 500000, 500000);
+    set_shareholder(
+        env,
+        &fractions,
+        &owner,
+        &Shareholder {
+            balance: total_shares,
+            rent_debt: 0,
+            unclaimed: 0,
+        },
+    );
+
+    // The contract holds the obligation so routed rent is pooled here
+    nft::transfer(env, obligation, &env.current_contract_address())?;
+
+    events::obligation_fractionalized(env, agreement_id, owner, total_shares);
+
+    Ok(fractions)
+}
+
+/// Move shares between holders, settling each side's earned rent first
+pub(crate) fn transfer_shares(
+    env: &Env,
+    from: Address,
+    to: Address,
+    agreement_id: String,
+    amount: i128,
+) -> Result<(), ObligationError> {
+    from.require_auth();
+
+    let fractions = get_fractions(env, &agreement_id).ok_or(ObligationError::NotFractionalized)?;
+    if fractions.recombined_at.is_some() {
+        return Err(ObligationError::NotFractionalized);
+    }
+
+    if amount <= 0 || from == to {
+        return Err(ObligationError::InvalidShareAmount);
+    }
+
+    let mut sender = get_shareholder(env, &agreement_id, &from);
+    if sender.balance < amount {
+        return Err(ObligationError::InsufficientShares);
+    }
+
+    let mut receiver = get_shareholder(env, &agreement_id, &to);
+    accrue(&fractions, &mut sender);
+    accrue(&fractions, &mut receiver);
+    sender.balance -= amount;
+    receiver.balance += amount;
+    rebase(&fractions, &mut sender);
+    rebase(&fractions, &mut receiver);
+    set_shareholder(env, &fractions, &from, &sender);
+    set_shareholder(env, &fractions, &to, &receiver);
+
+    events::shares_transferred(env, agreement_id, from, to, amount);
+
+    Ok(())
+}
+
+/// Rent a holder can claim from one series, capped at what its pool still
+/// holds
+fn series_claimable(fractions: &FractionInfo, shareholder: &mut Shareholder) -> i128 {
+    accrue(fractions, shareholder);
+    shareholder
+        .unclaimed
+        .min(fractions.rent_received - fractions.rent_claimed)
+}
+
+/// Rent a holder can claim right now across every series
+pub(crate) fn claimable(
+    env: &Env,
+    agreement_id: &String,
+    holder: &Address,
+) -> Result<i128, ObligationError> {
+    let count = series_count(env, agreement_id);
+    if count == 0 {
+        return Err(ObligationError::NotFractionalized);
+    }
+
+    let mut total = 0;
+    for generation in 0..count {
+        let Some(fractions) = get_series(env, agreement_id, generation) else {
+            continue;
+        };
+        let mut shareholder = get_series_shareholder(env, agreement_id, generation, holder);
+        total += series_claimable(&fractions, &mut shareholder);
+    }
+    Ok(total)
+}
+
+/// Pay out a holder's share of the rent routed to the obligation in every
+/// series they held shares of
+pub(crate) fn claim_rent(
+    env: &Env,
+    holder: Address,
+    agreement_id: String,
+) -> Result<i128, ObligationError> {
+    holder.require_auth();
+
+    let count = series_count(env, &agreement_id);
+    if count == 0 {
+        return Err(ObligationError::NotFractionalized);
+    }
+
+    let mut total = 0;
+    for generation in 0..count {
+        let Some(mut fractions) = get_series(env, &agreement_id, generation) else {
+            continue;
+        };
+
+        // Never pay out more than the rent that actually reached the pool
+        let mut shareholder = get_series_shareholder(env, &agreement_id, generation, &holder);
+        let amount = series_claimable(&fractions, &mut shareholder);
+        if amount <= 0 {
+            continue;
+        }
+        shareholder.unclaimed -= amount;
+        fractions.rent_claimed += amount;
+        set_shareholder(env, &fractions, &holder, &shareholder);
+        set_fractions(env, &fractions);
+
+        token::Client::new(env, &fractions.token).transfer(
+            &env.current_contract_address(),
+            &holder,
+            &amount,
+        );
+        total += amount;
+    }
+
+    if total > 0 {
+        events::rent_claimed(env, agreement_id, holder, total);
+    }
+
+    Ok(total)
+}
+
+/// Restore single ownership to a holder of every share. Rent earned up to
+/// now stays claimable by everyone who held shares.
+pub(crate) fn recombine(
+    env: &Env,
+    holder: Address,
+    agreement_id: String,
+) -> Result<(), ObligationError> {
+    holder.require_auth();
+
+    let mut fractions =
+        get_fractions(env, &agreement_id).ok_or(ObligationError::NotFractionalized)?;
+    if fractions.recombined_at.is_some() {
+        return Err(ObligationError::NotFractionalized);
+    }
+
+    let mut shareholder = get_shareholder(env, &agreement_id, &holder);
+    if shareholder.balance != fractions.total_shares {
+        return Err(ObligationError::InsufficientShares);
+    }
+
+    fractions.recombined_at = Some(env.ledger().timestamp());
+    set_fractions(env, &fractions);
+
+    accrue(&fractions, &mut shareholder);
+    shareholder.balance = 0;
+    rebase(&fractions, &mut shareholder);
+    set_shareholder(env, &fractions, &holder, &shareholder);
+
+    let obligation = nft::get_obligation(env, &agreement_id)?;
+    nft::transfer(env, obligation, &holder)?;
+
+    events::obligation_recombined(env, agreement_id, holder);
+
+    Ok(())
+}
@@ -1,9 +1,14 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
 
 mod errors;
 mod events;
+mod fractions;
+mod lien;
+mod marketplace;
+mod nft;
+mod settlement;
 mod storage;
 mod types;
 
@@ -12,22 +17,49 @@ mod tests;
 
 pub use errors::ObligationError;
 pub use storage::DataKey;
-pub use types::RentObligation;
+pub use types::{
+    FractionInfo, Lien, LienStatus, Listing, MarketFee, ObligationSettlement, Offer,
+    OwnerRentTotal, RentObligation, RentPayee, Shareholder,
+};
+
+use types::AgreementStatus;
+
+/// Collection name reported by `name`
+pub const NAME: &str = "Chioma Rent Obligation";
+/// Collection symbol reported by `symbol`
+pub const SYMBOL: &str = "CRO";
 
 #[contract]
 pub struct TokenizedRentObligationContract;
 
 #[contractimpl]
 impl TokenizedRentObligationContract {
-    /// Initialize the contract.
+    /// Initialize the contract. The admin must authorize.
+    ///
+    /// # Arguments
+    /// * `admin` - Administrator of the marketplace fee and sale token allowlist
+    /// * `chioma_contract` - Chioma contract holding the agreements of record
+    /// * `base_uri` - Prefix of every token URI; the agreement ID is appended
     ///
     /// # Errors
     /// * `AlreadyInitialized` - If the contract has already been initialized
-    pub fn initialize(env: Env) -> Result<(), ObligationError> {
+    /// * `TokenUriTooLong` - If the base URI exceeds the maximum URI length
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        chioma_contract: Address,
+        base_uri: String,
+    ) -> Result<(), ObligationError> {
         if env.storage().persistent().has(&DataKey::Initialized) {
             return Err(ObligationError::AlreadyInitialized);
         }
 
+        if base_uri.len() as usize > nft::MAX_TOKEN_URI_LEN {
+            return Err(ObligationError::TokenUriTooLong);
+        }
+
+        admin.require_auth();
+
         env.storage().persistent().set(&DataKey::Initialized, &true);
         env.storage()
             .persistent()
@@ -40,6 +72,23 @@ impl TokenizedRentObligationContract {
             .persistent()
             .extend_ttl(&DataKey::ObligationCount, 500000, 500000);
 
+        env.storage().persistent().set(&DataKey::Admin, &admin);
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::Admin, 500000, 500000);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::ChiomaContract, &chioma_contract);
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::ChiomaContract, 500000, 500000);
+
+        env.storage().persistent().set(&DataKey::BaseUri, &base_uri);
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::BaseUri, 500000, 500000);
+
         Ok(())
     }
 
@@ -51,7 +100,11 @@ impl TokenizedRentObligationContract {
     ///
     /// # Errors
     /// * `NotInitialized` - If contract hasn't been initialized
-    /// * `ObligationAlreadyExists` - If an obligation for this agreement already exists
+    /// * `AgreementNotFound` - If chioma has no agreement with this ID
+    /// * `AgreementNotActive` - If the agreement is not Active
+    /// * `NotAgreementLandlord` - If `landlord` is not the agreement's landlord
+    /// * `ObligationAlreadyExists` - If an obligation for this agreement
+    ///   already exists or was burned; its settlement stays on record
     pub fn mint_obligation(
         env: Env,
         agreement_id: String,
@@ -66,10 +119,26 @@ impl TokenizedRentObligationContract {
         let obligation_key = DataKey::Obligation(agreement_id.clone());
         let owner_key = DataKey::Owner(agreement_id.clone());
 
-        if env.storage().persistent().has(&obligation_key) {
+        if env.storage().persistent().has(&obligation_key)
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::Settlement(agreement_id.clone()))
+        {
             return Err(ObligationError::ObligationAlreadyExists);
         }
 
+        // Only the landlord of a live agreement of record may tokenize it
+        let agreement = nft::load_agreement(&env, &agreement_id)?;
+
+        if agreement.status != AgreementStatus::Active {
+            return Err(ObligationError::AgreementNotActive);
+        }
+
+        if agreement.landlord != landlord {
+            return Err(ObligationError::NotAgreementLandlord);
+        }
+
         let obligation = RentObligation {
             agreement_id: agreement_id.clone(),
             owner: landlord.clone(),
@@ -86,6 +155,8 @@ impl TokenizedRentObligationContract {
             .persistent()
             .extend_ttl(&owner_key, 500000, 500000);
 
+        nft::add_token(&env, &landlord, &agreement_id);
+
         let mut count: u32 = env
             .storage()
             .persistent()
@@ -115,6 +186,8 @@ impl TokenizedRentObligationContract {
     /// * `NotInitialized` - If contract hasn't been initialized
     /// * `ObligationNotFound` - If the obligation doesn't exist
     /// * `Unauthorized` - If the caller is not the current owner
+    /// * `InvalidOwner` - If the obligation is transferred to its owner
+    /// * `LienActive` - If the obligation is pledged to a lender
     pub fn transfer_obligation(
         env: Env,
         from: Address,
@@ -127,34 +200,529 @@ impl TokenizedRentObligationContract {
 
         from.require_auth();
 
-        let obligation_key = DataKey::Obligation(agreement_id.clone());
-        let owner_key = DataKey::Owner(agreement_id.clone());
-
-        let mut obligation: RentObligation = env
-            .storage()
-            .persistent()
-            .get(&obligation_key)
-            .ok_or(ObligationError::ObligationNotFound)?;
+        let obligation = nft::get_obligation(&env, &agreement_id)?;
 
         if obligation.owner != from {
             return Err(ObligationError::Unauthorized);
         }
 
-        obligation.owner = to.clone();
+        nft::transfer(&env, obligation, &to)
+    }
 
-        env.storage().persistent().set(&obligation_key, &obligation);
+    /// Burn a tokenized rent obligation and record its final settlement.
+    ///
+    /// The owner may burn at any time. Once the linked agreement is Completed,
+    /// Cancelled or Terminated, anyone may burn it.
+    ///
+    /// # Arguments
+    /// * `caller` - Address burning the obligation
+    /// * `agreement_id` - Agreement identifier for the obligation
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    /// * `AgreementNotFound` - If chioma has no agreement with this ID
+    /// * `AlreadyFractionalized` - If the obligation is split into shares
+    /// * `Unauthorized` - If a non-owner burns before the agreement has ended
+    /// * `LienActive` - If the obligation is pledged to a lender
+    pub fn burn_obligation(
+        env: Env,
+        caller: Address,
+        agreement_id: String,
+    ) -> Result<ObligationSettlement, ObligationError> {
+        if !env.storage().persistent().has(&DataKey::Initialized) {
+            return Err(ObligationError::NotInitialized);
+        }
+
+        caller.require_auth();
+
+        let obligation = nft::get_obligation(&env, &agreement_id)?;
+
+        // Shareholders must recombine before the obligation can be retired
+        if obligation.owner == env.current_contract_address() {
+            return Err(ObligationError::AlreadyFractionalized);
+        }
+
+        let agreement = nft::load_agreement(&env, &agreement_id)?;
+
+        let ended = matches!(
+            agreement.status,
+            AgreementStatus::Completed | AgreementStatus::Cancelled | AgreementStatus::Terminated
+        );
+        if caller != obligation.owner && !ended {
+            return Err(ObligationError::Unauthorized);
+        }
+
+        lien::ensure_unencumbered(&env, &agreement_id)?;
+
+        let summary = ObligationSettlement {
+            agreement_id: agreement_id.clone(),
+            final_owner: obligation.owner.clone(),
+            burned_by: caller.clone(),
+            minted_at: obligation.minted_at,
+            burned_at: env.ledger().timestamp(),
+            payee_count: settlement::payee_count(&env, &agreement_id),
+        };
+        let settlement_key = DataKey::Settlement(agreement_id.clone());
+        env.storage().persistent().set(&settlement_key, &summary);
         env.storage()
             .persistent()
-            .extend_ttl(&obligation_key, 500000, 500000);
+            .extend_ttl(&settlement_key, 500000, 500000);
 
-        env.storage().persistent().set(&owner_key, &to);
         env.storage()
             .persistent()
-            .extend_ttl(&owner_key, 500000, 500000);
+            .remove(&DataKey::Obligation(agreement_id.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Owner(agreement_id.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Approval(agreement_id.clone()));
+        marketplace::clear_listing(&env, &agreement_id);
+        nft::remove_token(&env, &obligation.owner, &agreement_id);
 
-        events::obligation_transferred(&env, agreement_id, from, to);
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ObligationCount)
+            .unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ObligationCount, &count.saturating_sub(1));
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::ObligationCount, 500000, 500000);
 
-        Ok(())
+        events::obligation_burned(&env, agreement_id, obligation.owner, caller);
+
+        Ok(summary)
+    }
+
+    /// Get the settlement recorded when an obligation was burned.
+    ///
+    /// # Arguments
+    /// * `agreement_id` - Agreement identifier for the obligation
+    ///
+    /// # Returns
+    /// The settlement summary, or None if the obligation was never burned
+    pub fn get_settlement(env: Env, agreement_id: String) -> Option<ObligationSettlement> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Settlement(agreement_id))
+    }
+
+    /// Landlord rent paid to each address while the obligation existed, in
+    /// the order they were first paid. Returns up to `limit` totals (capped
+    /// at `MAX_PAGE_SIZE`) after the first `start`; totals stay available
+    /// once the obligation is burned.
+    pub fn get_owner_totals(
+        env: Env,
+        agreement_id: String,
+        start: u32,
+        limit: u32,
+    ) -> Vec<OwnerRentTotal> {
+        settlement::owner_totals(&env, &agreement_id, start, limit)
+    }
+
+    /// Get the landlord rent paid to `payee` for an obligation.
+    pub fn get_rent_received(env: Env, agreement_id: String, payee: Address) -> i128 {
+        settlement::rent_received(&env, &agreement_id, &payee)
+    }
+
+    /// Set the payment contract that reports rent paid (admin only).
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    pub fn set_payment_contract(
+        env: Env,
+        payment_contract: Address,
+    ) -> Result<(), ObligationError> {
+        settlement::set_payment_contract(&env, payment_contract)
+    }
+
+    /// Get the payment contract that reports rent paid, if configured.
+    pub fn get_payment_contract(env: Env) -> Option<Address> {
+        settlement::get_payment_contract(&env)
+    }
+
+    /// Record the landlord leg of a rent payment (payment contract only).
+    ///
+    /// Called by the payment contract with the address it actually paid,
+    /// after the funds were transferred. A negative amount records rent
+    /// clawed back by a refund. Rent for untokenized agreements is ignored.
+    ///
+    /// # Arguments
+    /// * `agreement_id` - Agreement the rent was paid for
+    /// * `payee` - Address that received the landlord leg
+    /// * `amount` - Landlord leg paid, or clawed back if negative
+    ///
+    /// # Errors
+    /// * `PaymentContractNotSet` - If no payment contract is configured
+    pub fn record_rent_paid(
+        env: Env,
+        agreement_id: String,
+        payee: Address,
+        amount: i128,
+    ) -> Result<(), ObligationError> {
+        settlement::record_rent_paid(&env, agreement_id, payee, amount)
+    }
+
+    /// Split an obligation into a fixed number of transferable shares.
+    ///
+    /// The contract takes custody of the obligation, so rent routed to its
+    /// owner is pooled here and claimable pro rata by shareholders. A
+    /// recombined obligation can be fractionalized again, which starts a new
+    /// series of shares.
+    ///
+    /// # Arguments
+    /// * `owner` - Current owner, who receives every share
+    /// * `agreement_id` - Agreement identifier for the obligation
+    /// * `total_shares` - Number of shares to create
+    ///
+    /// # Errors
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    /// * `Unauthorized` - If the caller is not the current owner
+    /// * `InvalidShareAmount` - If `total_shares` is not positive
+    /// * `AlreadyFractionalized` - If the obligation is already split into shares
+    pub fn fractionalize(
+        env: Env,
+        owner: Address,
+        agreement_id: String,
+        total_shares: i128,
+    ) -> Result<FractionInfo, ObligationError> {
+        fractions::fractionalize(&env, owner, agreement_id, total_shares)
+    }
+
+    /// Transfer shares of a fractionalized obligation.
+    ///
+    /// # Errors
+    /// * `NotFractionalized` - If the obligation is not split into shares
+    /// * `InvalidShareAmount` - If `amount` is not positive or `from` equals `to`
+    /// * `InsufficientShares` - If `from` holds fewer than `amount` shares
+    pub fn transfer_shares(
+        env: Env,
+        from: Address,
+        to: Address,
+        agreement_id: String,
+        amount: i128,
+    ) -> Result<(), ObligationError> {
+        fractions::transfer_shares(&env, from, to, agreement_id, amount)
+    }
+
+    /// Get the number of shares of an obligation's latest series held by
+    /// `holder`.
+    pub fn share_balance(env: Env, agreement_id: String, holder: Address) -> i128 {
+        fractions::get_shareholder(&env, &agreement_id, &holder).balance
+    }
+
+    /// Get the latest share series of a fractionalized obligation.
+    pub fn get_fractions(env: Env, agreement_id: String) -> Option<FractionInfo> {
+        fractions::get_fractions(&env, &agreement_id)
+    }
+
+    /// Get the rent `holder` can currently claim from an obligation, across
+    /// every series they held shares of.
+    ///
+    /// # Errors
+    /// * `NotFractionalized` - If the obligation was never split into shares
+    pub fn claimable_rent(
+        env: Env,
+        agreement_id: String,
+        holder: Address,
+    ) -> Result<i128, ObligationError> {
+        fractions::claimable(&env, &agreement_id, &holder)
+    }
+
+    /// Claim `holder`'s pro rata share of the rent routed to an obligation
+    /// in every series they held shares of. Payouts never exceed the rent
+    /// the payment contract reported paying to each series' pool.
+    ///
+    /// # Returns
+    /// The amount paid out
+    ///
+    /// # Errors
+    /// * `NotFractionalized` - If the obligation was never split into shares
+    pub fn claim_rent(
+        env: Env,
+        holder: Address,
+        agreement_id: String,
+    ) -> Result<i128, ObligationError> {
+        fractions::claim_rent(&env, holder, agreement_id)
+    }
+
+    /// Recombine every share of an obligation into single ownership.
+    ///
+    /// # Errors
+    /// * `NotFractionalized` - If the obligation is not split into shares
+    /// * `InsufficientShares` - If `holder` does not hold every share
+    pub fn recombine(
+        env: Env,
+        holder: Address,
+        agreement_id: String,
+    ) -> Result<(), ObligationError> {
+        fractions::recombine(&env, holder, agreement_id)
+    }
+
+    /// Get the contract admin.
+    pub fn get_admin(env: Env) -> Result<Address, ObligationError> {
+        marketplace::get_admin(&env)
+    }
+
+    /// Set the platform fee taken from every marketplace sale (admin only).
+    ///
+    /// # Arguments
+    /// * `collector` - Address receiving the fee
+    /// * `fee_bps` - Fee in basis points of the sale price; 0 disables it
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `InvalidFee` - If `fee_bps` exceeds 10,000
+    pub fn set_market_fee(
+        env: Env,
+        collector: Address,
+        fee_bps: u32,
+    ) -> Result<(), ObligationError> {
+        marketplace::set_market_fee(&env, collector, fee_bps)
+    }
+
+    /// Get the marketplace fee, if one is configured.
+    pub fn get_market_fee(env: Env) -> Option<MarketFee> {
+        marketplace::get_market_fee(&env)
+    }
+
+    /// Allow or disallow a token for listings and offers (admin only).
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    pub fn set_sale_token_allowed(
+        env: Env,
+        token: Address,
+        allowed: bool,
+    ) -> Result<(), ObligationError> {
+        marketplace::set_sale_token_allowed(&env, token, allowed)
+    }
+
+    /// Check whether a token may be used to price listings and offers.
+    pub fn is_sale_token_allowed(env: Env, token: Address) -> bool {
+        marketplace::is_sale_token_allowed(&env, &token)
+    }
+
+    /// List an obligation for sale at a fixed price.
+    ///
+    /// The listing replaces any earlier one and is dropped whenever the
+    /// obligation changes hands.
+    ///
+    /// # Arguments
+    /// * `seller` - Current owner of the obligation
+    /// * `agreement_id` - Agreement identifier for the obligation
+    /// * `token` - Allowlisted token the price is paid in
+    /// * `price` - Sale price
+    ///
+    /// # Errors
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    /// * `Unauthorized` - If `seller` is not the current owner
+    /// * `InvalidPrice` - If `price` is not positive
+    /// * `SaleTokenNotAllowed` - If `token` is not allowlisted
+    /// * `LienActive` - If the obligation is pledged to a lender
+    pub fn list_obligation(
+        env: Env,
+        seller: Address,
+        agreement_id: String,
+        token: Address,
+        price: i128,
+    ) -> Result<Listing, ObligationError> {
+        marketplace::list(&env, seller, agreement_id, token, price)
+    }
+
+    /// Withdraw a listing.
+    ///
+    /// # Errors
+    /// * `ListingNotFound` - If the obligation is not listed
+    /// * `Unauthorized` - If `seller` did not create the listing
+    pub fn delist_obligation(
+        env: Env,
+        seller: Address,
+        agreement_id: String,
+    ) -> Result<(), ObligationError> {
+        marketplace::delist(&env, seller, agreement_id)
+    }
+
+    /// Get the active listing of an obligation.
+    pub fn get_listing(env: Env, agreement_id: String) -> Option<Listing> {
+        marketplace::get_listing(&env, &agreement_id)
+    }
+
+    /// Buy a listed obligation.
+    ///
+    /// The buyer pays the listing price, less any platform fee, to the
+    /// seller and receives ownership in the same transaction.
+    ///
+    /// # Arguments
+    /// * `buyer` - Address paying and receiving the obligation
+    /// * `agreement_id` - Agreement identifier for the obligation
+    /// * `price` - Price the buyer expects to pay; must match the listing
+    ///
+    /// # Errors
+    /// * `ListingNotFound` - If the obligation is not listed
+    /// * `PriceMismatch` - If `price` differs from the listing price
+    /// * `SaleTokenNotAllowed` - If the listing token is no longer allowlisted
+    /// * `InvalidOwner` - If the buyer is the seller
+    /// * `LienActive` - If the obligation is pledged to a lender
+    pub fn buy_obligation(
+        env: Env,
+        buyer: Address,
+        agreement_id: String,
+        price: i128,
+    ) -> Result<(), ObligationError> {
+        marketplace::buy(&env, buyer, agreement_id, price)
+    }
+
+    /// Make an offer for an obligation, escrowing `amount` until the offer
+    /// is accepted or cancelled. Replaces the bidder's earlier offer, if any.
+    ///
+    /// # Arguments
+    /// * `bidder` - Address making the offer
+    /// * `agreement_id` - Agreement identifier for the obligation
+    /// * `token` - Allowlisted token the offer is paid in
+    /// * `amount` - Offered price
+    /// * `expires_at` - Last timestamp at which the offer can be accepted
+    ///
+    /// # Errors
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    /// * `InvalidOwner` - If the bidder already owns the obligation
+    /// * `InvalidPrice` - If `amount` is not positive
+    /// * `SaleTokenNotAllowed` - If `token` is not allowlisted
+    /// * `OfferExpired` - If `expires_at` is not in the future
+    pub fn make_offer(
+        env: Env,
+        bidder: Address,
+        agreement_id: String,
+        token: Address,
+        amount: i128,
+        expires_at: u64,
+    ) -> Result<Offer, ObligationError> {
+        marketplace::make_offer(&env, bidder, agreement_id, token, amount, expires_at)
+    }
+
+    /// Cancel an offer and return its escrow. Expired offers are
+    /// reclaimed the same way.
+    ///
+    /// # Returns
+    /// The amount refunded
+    ///
+    /// # Errors
+    /// * `OfferNotFound` - If the bidder has no offer for the obligation
+    pub fn cancel_offer(
+        env: Env,
+        bidder: Address,
+        agreement_id: String,
+    ) -> Result<i128, ObligationError> {
+        marketplace::cancel_offer(&env, bidder, agreement_id)
+    }
+
+    /// Accept a bidder's offer, selling the obligation at the offered price.
+    ///
+    /// # Errors
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    /// * `Unauthorized` - If `owner` is not the current owner
+    /// * `OfferNotFound` - If the bidder has no offer for the obligation
+    /// * `OfferExpired` - If the offer has expired
+    /// * `LienActive` - If the obligation is pledged to a lender
+    pub fn accept_offer(
+        env: Env,
+        owner: Address,
+        agreement_id: String,
+        bidder: Address,
+    ) -> Result<(), ObligationError> {
+        marketplace::accept_offer(&env, owner, agreement_id, bidder)
+    }
+
+    /// Get a bidder's offer for an obligation.
+    pub fn get_offer(env: Env, agreement_id: String, bidder: Address) -> Option<Offer> {
+        marketplace::get_offer(&env, &agreement_id, &bidder)
+    }
+
+    /// Pledge an obligation to a lender as collateral.
+    ///
+    /// While the lien is active the obligation cannot be transferred, sold
+    /// or burned. With `redirect_rent`, `get_rent_payee` reports the lender
+    /// until the rent paid to the lender covers `principal`, capped at what
+    /// is still outstanding so that the owner is paid any excess.
+    ///
+    /// # Arguments
+    /// * `owner` - Current owner pledging the obligation
+    /// * `lender` - Lienholder; must also authorize
+    /// * `agreement_id` - Agreement identifier for the obligation
+    /// * `principal` - Amount owed to the lender
+    /// * `redirect_rent` - Whether rent is paid to the lender until repaid
+    ///
+    /// # Errors
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    /// * `Unauthorized` - If `owner` is not the current owner
+    /// * `InvalidOwner` - If the lender is the owner
+    /// * `InvalidPrincipal` - If `principal` is not positive
+    /// * `LienActive` - If the obligation is already pledged
+    pub fn place_lien(
+        env: Env,
+        owner: Address,
+        lender: Address,
+        agreement_id: String,
+        principal: i128,
+        redirect_rent: bool,
+    ) -> Result<Lien, ObligationError> {
+        lien::place_lien(&env, owner, lender, agreement_id, principal, redirect_rent)
+    }
+
+    /// Release a lien (lienholder only).
+    ///
+    /// # Errors
+    /// * `LienNotFound` - If the obligation is not pledged
+    /// * `Unauthorized` - If `lender` does not hold the lien
+    pub fn release_lien(
+        env: Env,
+        lender: Address,
+        agreement_id: String,
+    ) -> Result<(), ObligationError> {
+        lien::release_lien(&env, lender, agreement_id)
+    }
+
+    /// Clear a lien the redirected rent has repaid in full (owner only), for
+    /// when the lienholder does not release it.
+    ///
+    /// # Errors
+    /// * `LienNotFound` - If the obligation is not pledged
+    /// * `Unauthorized` - If `owner` did not pledge the obligation
+    /// * `LienOutstanding` - If the rent paid to the lender has not yet
+    ///   covered the principal
+    pub fn clear_lien(
+        env: Env,
+        owner: Address,
+        agreement_id: String,
+    ) -> Result<(), ObligationError> {
+        lien::clear_lien(&env, owner, agreement_id)
+    }
+
+    /// Get the active lien on an obligation.
+    pub fn get_lien(env: Env, agreement_id: String) -> Option<Lien> {
+        lien::get_lien(&env, &agreement_id)
+    }
+
+    /// Get the repayment progress of the active lien on an obligation.
+    ///
+    /// # Errors
+    /// * `LienNotFound` - If the obligation is not pledged
+    pub fn get_lien_status(env: Env, agreement_id: String) -> Result<LienStatus, ObligationError> {
+        lien::get_status(&env, &agreement_id)
+    }
+
+    /// Get where the landlord leg of an obligation's rent should be paid:
+    /// the lienholder, up to the principal outstanding, while a redirecting
+    /// lien is active, otherwise the current owner.
+    ///
+    /// # Returns
+    /// Payee, its cap and the owner, or None if the obligation doesn't exist
+    pub fn get_rent_payee(env: Env, agreement_id: String) -> Option<RentPayee> {
+        lien::rent_payee(&env, &agreement_id)
     }
 
     /// Get the current owner of a tokenized rent obligation.
@@ -203,4 +771,176 @@ impl TokenizedRentObligationContract {
             .get(&DataKey::ObligationCount)
             .unwrap_or(0)
     }
+
+    /// Get the chioma contract holding the agreements of record.
+    pub fn get_chioma_contract(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::ChiomaContract)
+    }
+
+    /// Collection name.
+    pub fn name(env: Env) -> String {
+        String::from_str(&env, NAME)
+    }
+
+    /// Collection symbol.
+    pub fn symbol(env: Env) -> String {
+        String::from_str(&env, SYMBOL)
+    }
+
+    /// Get the metadata URI of an obligation token.
+    ///
+    /// # Arguments
+    /// * `agreement_id` - Agreement identifier for the obligation
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    /// * `TokenUriTooLong` - If the URI would exceed the maximum URI length
+    pub fn token_uri(env: Env, agreement_id: String) -> Result<String, ObligationError> {
+        let base_uri: String = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BaseUri)
+            .ok_or(ObligationError::NotInitialized)?;
+        nft::get_obligation(&env, &agreement_id)?;
+        nft::token_uri(&env, &base_uri, &agreement_id)
+    }
+
+    /// Get the owner of an obligation token.
+    ///
+    /// # Errors
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    pub fn owner_of(env: Env, agreement_id: String) -> Result<Address, ObligationError> {
+        Self::get_obligation_owner(env, agreement_id).ok_or(ObligationError::ObligationNotFound)
+    }
+
+    /// Get the number of obligation tokens held by `owner`.
+    pub fn balance(env: Env, owner: Address) -> u32 {
+        nft::owner_tokens(&env, &owner).len()
+    }
+
+    /// Get the obligation token held by `owner` at `index`.
+    ///
+    /// # Errors
+    /// * `IndexOutOfBounds` - If `index` is not below the owner's balance
+    pub fn token_of_owner_by_index(
+        env: Env,
+        owner: Address,
+        index: u32,
+    ) -> Result<String, ObligationError> {
+        nft::owner_tokens(&env, &owner)
+            .get(index)
+            .ok_or(ObligationError::IndexOutOfBounds)
+    }
+
+    /// Get every obligation token held by `owner`.
+    pub fn tokens_of_owner(env: Env, owner: Address) -> Vec<String> {
+        nft::owner_tokens(&env, &owner)
+    }
+
+    /// Approve an address to transfer a single obligation, or clear the
+    /// approval with `None`. The approval is cleared on transfer.
+    ///
+    /// # Arguments
+    /// * `caller` - Owner of the obligation or one of its operators
+    /// * `approved` - Address to approve, or None to revoke
+    /// * `agreement_id` - Agreement identifier for the obligation
+    ///
+    /// # Errors
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    /// * `Unauthorized` - If the caller is neither the owner nor an operator
+    pub fn approve(
+        env: Env,
+        caller: Address,
+        approved: Option<Address>,
+        agreement_id: String,
+    ) -> Result<(), ObligationError> {
+        caller.require_auth();
+
+        let obligation = nft::get_obligation(&env, &agreement_id)?;
+        let owner = obligation.owner;
+
+        if caller != owner && !nft::is_approved_for_all(&env, &owner, &caller) {
+            return Err(ObligationError::Unauthorized);
+        }
+
+        let approval_key = DataKey::Approval(agreement_id.clone());
+        match &approved {
+            Some(spender) => {
+                env.storage().persistent().set(&approval_key, spender);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&approval_key, 500000, 500000);
+            }
+            None => env.storage().persistent().remove(&approval_key),
+        }
+
+        events::obligation_approved(&env, owner, agreement_id, approved);
+
+        Ok(())
+    }
+
+    /// Get the address approved to transfer a single obligation, if any.
+    pub fn get_approved(env: Env, agreement_id: String) -> Option<Address> {
+        nft::get_approved(&env, &agreement_id)
+    }
+
+    /// Allow or forbid `operator` to manage all of `owner`'s obligations.
+    pub fn set_approval_for_all(env: Env, owner: Address, operator: Address, approved: bool) {
+        owner.require_auth();
+
+        let key = DataKey::OperatorApproval(owner.clone(), operator.clone());
+        if approved {
+            env.storage().persistent().set(&key, &true);
+            env.storage().persistent().extend_ttl(&key, 500000, 500000);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        events::approval_for_all(&env, owner, operator, approved);
+    }
+
+    /// Check whether `operator` may manage all of `owner`'s obligations.
+    pub fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool {
+        nft::is_approved_for_all(&env, &owner, &operator)
+    }
+
+    /// Transfer an obligation on behalf of its owner.
+    ///
+    /// # Arguments
+    /// * `spender` - Owner, approved address or operator moving the token
+    /// * `from` - Current owner of the obligation
+    /// * `to` - New owner to transfer to
+    /// * `agreement_id` - Agreement identifier for the obligation
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    /// * `InvalidOwner` - If `from` is not the current owner or equals `to`
+    /// * `Unauthorized` - If the spender may not move the obligation
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        agreement_id: String,
+    ) -> Result<(), ObligationError> {
+        if !env.storage().persistent().has(&DataKey::Initialized) {
+            return Err(ObligationError::NotInitialized);
+        }
+
+        spender.require_auth();
+
+        let obligation = nft::get_obligation(&env, &agreement_id)?;
+
+        if obligation.owner != from {
+            return Err(ObligationError::InvalidOwner);
+        }
+
+        if !nft::is_authorized(&env, &from, &spender, &agreement_id) {
+            return Err(ObligationError::Unauthorized);
+        }
+
+        nft::transfer(&env, obligation, &to)
+    }
 }
@@ -0,0 +1,178 @@
+//! Liens that pledge a rent obligation as collateral for a loan.
+//!
+//! While a lien is active the obligation cannot change hands or be burned.
+//! If the owner agreed to redirect rent, the lienholder is reported as the
+//! rent payee for the principal that the rent the payment contract reports
+//! paying them has not yet covered, and the owner is paid the rest. The
+//! lienholder can release a lien at any time; once it is repaid in full the
+//! owner can clear it too.
+use soroban_sdk::{Address, Env, String};
+
+use crate::errors::ObligationError;
+use crate::events;
+use crate::nft;
+use crate::storage::DataKey;
+use crate::types::{Lien, LienStatus, RentPayee};
+
+pub(crate) fn get_lien(env: &Env, agreement_id: &String) -> Option<Lien> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Lien(agreement_id.clone()))
+}
+
+/// Fail if the obligation is pledged under an active lien
+pub(crate) fn ensure_unencumbered(env: &Env, agreement_id: &String) -> Result<(), ObligationError> {
+    if get_lien(env, agreement_id).is_some() {
+        return Err(ObligationError::LienActive);
+    }
+    Ok(())
+}
+
+fn set_lien(env: &Env, lien: &Lien) {
+    let key = DataKey::Lien(lien.agreement_id.clone());
+    env.storage().persistent().set(&key, lien);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+}
+
+fn status(lien: Lien) -> LienStatus {
+    let outstanding = (lien.principal - lien.repaid).max(0);
+    LienStatus {
+        redirecting: lien.redirect_rent && outstanding > 0,
+        lien,
+        outstanding,
+    }
+}
+
+pub(crate) fn get_status(env: &Env, agreement_id: &String) -> Result<LienStatus, ObligationError> {
+    let lien = get_lien(env, agreement_id).ok_or(ObligationError::LienNotFound)?;
+    Ok(status(lien))
+}
+
+/// Count rent paid to the lienholder towards the lien's principal. A
+/// negative amount takes back rent clawed back from them by a refund.
+pub(crate) fn credit_repayment(env: &Env, agreement_id: &String, payee: &Address, amount: i128) {
+    let Some(mut lien) = get_lien(env, agreement_id) else {
+        return;
+    };
+    if lien.lender != *payee {
+        return;
+    }
+
+    lien.repaid += amount;
+    set_lien(env, &lien);
+}
+
+/// Where the landlord leg of the obligation's rent is paid. A redirecting
+/// lien caps the lender's part at the principal still outstanding.
+pub(crate) fn rent_payee(env: &Env, agreement_id: &String) -> Option<RentPayee> {
+    let obligation = nft::get_obligation(env, agreement_id).ok()?;
+
+    if let Some(lien) = get_lien(env, agreement_id) {
+        let status = status(lien);
+        if status.redirecting {
+            return Some(RentPayee {
+                payee: status.lien.lender,
+                cap: Some(status.outstanding),
+                owner: obligation.owner,
+            });
+        }
+    }
+
+    Some(RentPayee {
+        payee: obligation.owner.clone(),
+        cap: None,
+        owner: obligation.owner,
+    })
+}
+
+/// Pledge an obligation to `lender`. Both parties must authorize.
+pub(crate) fn place_lien(
+    env: &Env,
+    owner: Address,
+    lender: Address,
+    agreement_id: String,
+    principal: i128,
+    redirect_rent: bool,
+) -> Result<Lien, ObligationError> {
+    owner.require_auth();
+    lender.require_auth();
+
+    let obligation = nft::get_obligation(env, &agreement_id)?;
+    if obligation.owner != owner {
+        return Err(ObligationError::Unauthorized);
+    }
+
+    if lender == owner {
+        return Err(ObligationError::InvalidOwner);
+    }
+
+    if principal <= 0 {
+        return Err(ObligationError::InvalidPrincipal);
+    }
+
+    ensure_unencumbered(env, &agreement_id)?;
+
+    let lien = Lien {
+        agreement_id: agreement_id.clone(),
+        owner: owner.clone(),
+        lender: lender.clone(),
+        principal,
+        redirect_rent,
+        created_at: env.ledger().timestamp(),
+        repaid: 0,
+    };
+    set_lien(env, &lien);
+
+    events::lien_placed(env, agreement_id, owner, lender, principal, redirect_rent);
+
+    Ok(lien)
+}
+
+/// Lift a lien, returning full control of the obligation to its owner
+pub(crate) fn release_lien(
+    env: &Env,
+    lender: Address,
+    agreement_id: String,
+) -> Result<(), ObligationError> {
+    lender.require_auth();
+
+    let lien = get_lien(env, &agreement_id).ok_or(ObligationError::LienNotFound)?;
+    if lien.lender != lender {
+        return Err(ObligationError::Unauthorized);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Lien(agreement_id.clone()));
+
+    events::lien_released(env, agreement_id, lien.owner, lender);
+
+    Ok(())
+}
+
+/// Clear a lien whose principal the redirected rent has repaid in full,
+/// for when the lienholder does not release it
+pub(crate) fn clear_lien(
+    env: &Env,
+    owner: Address,
+    agreement_id: String,
+) -> Result<(), ObligationError> {
+    owner.require_auth();
+
+    let lien = get_lien(env, &agreement_id).ok_or(ObligationError::LienNotFound)?;
+    if lien.owner != owner {
+        return Err(ObligationError::Unauthorized);
+    }
+
+    if lien.repaid < lien.principal {
+        return Err(ObligationError::LienOutstanding);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Lien(agreement_id.clone()));
+
+    events::lien_released(env, agreement_id, owner, lien.lender);
+
+    Ok(())
+}
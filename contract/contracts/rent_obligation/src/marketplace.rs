@@ -0,0 +1,343 @@
+//! Fixed-price listings and escrowed offers for rent obligations.
+//!
+//! Owners list an obligation at a price in an allowlisted token, and a buyer
+//! pays and takes ownership in the same transaction. Bidders can instead
+//! escrow an offer with an expiry, which the owner may accept until it
+//! lapses. An optional platform fee is taken from every sale, and each sale
+//! is published as a `sale` event so indexers can rebuild the trade history.
+use soroban_sdk::{token, Address, Env, String};
+
+use crate::errors::ObligationError;
+use crate::events;
+use crate::lien;
+use crate::nft;
+use crate::storage::DataKey;
+use crate::types::{Listing, MarketFee, Offer};
+
+/// Basis-point denominator for the marketplace fee
+pub const FEE_DENOMINATOR: u32 = 10_000;
+
+pub(crate) fn get_admin(env: &Env) -> Result<Address, ObligationError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Admin)
+        .ok_or(ObligationError::NotInitialized)
+}
+
+pub(crate) fn get_market_fee(env: &Env) -> Option<MarketFee> {
+    env.storage().persistent().get(&DataKey::MarketFee)
+}
+
+pub(crate) fn set_market_fee(
+    env: &Env,
+    collector: Address,
+    fee_bps: u32,
+) -> Result<(), ObligationError> {
+    let admin = get_admin(env)?;
+    admin.require_auth();
+
+    if fee_bps > FEE_DENOMINATOR {
+        return Err(ObligationError::InvalidFee);
+    }
+
+    let fee = MarketFee { collector, fee_bps };
+    env.storage().persistent().set(&DataKey::MarketFee, &fee);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::MarketFee, 500000, 500000);
+
+    events::market_fee_updated(env, admin, fee.collector, fee.fee_bps);
+
+    Ok(())
+}
+
+pub(crate) fn is_sale_token_allowed(env: &Env, token: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SaleToken(token.clone()))
+        .unwrap_or(false)
+}
+
+pub(crate) fn set_sale_token_allowed(
+    env: &Env,
+    token: Address,
+    allowed: bool,
+) -> Result<(), ObligationError> {
+    let admin = get_admin(env)?;
+    admin.require_auth();
+
+    let key = DataKey::SaleToken(token.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, 500000, 500000);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    events::sale_token_allowed(env, token, allowed);
+
+    Ok(())
+}
+
+pub(crate) fn get_listing(env: &Env, agreement_id: &String) -> Option<Listing> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Listing(agreement_id.clone()))
+}
+
+/// Drop any listing of a token; called whenever it changes hands
+pub(crate) fn clear_listing(env: &Env, agreement_id: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Listing(agreement_id.clone()));
+}
+
+pub(crate) fn get_offer(env: &Env, agreement_id: &String, bidder: &Address) -> Option<Offer> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Offer(agreement_id.clone(), bidder.clone()))
+}
+
+fn check_sale_terms(env: &Env, token: &Address, price: i128) -> Result<(), ObligationError> {
+    if price <= 0 {
+        return Err(ObligationError::InvalidPrice);
+    }
+
+    if !is_sale_token_allowed(env, token) {
+        return Err(ObligationError::SaleTokenNotAllowed);
+    }
+
+    Ok(())
+}
+
+/// Pay `price` from `payer` to the seller, less the platform fee, and
+/// return the fee taken
+fn pay_seller(env: &Env, token: &Address, payer: &Address, seller: &Address, price: i128) -> i128 {
+    let client = token::Client::new(env, token);
+
+    let mut platform_fee = 0;
+    if let Some(fee) = get_market_fee(env) {
+        platform_fee = price * fee.fee_bps as i128 / FEE_DENOMINATOR as i128;
+        if platform_fee > 0 {
+            client.transfer(payer, &fee.collector, &platform_fee);
+        }
+    }
+
+    client.transfer(payer, seller, &(price - platform_fee));
+
+    platform_fee
+}
+
+/// List an obligation for sale at a fixed price, replacing any earlier listing
+pub(crate) fn list(
+    env: &Env,
+    seller: Address,
+    agreement_id: String,
+    token: Address,
+    price: i128,
+) -> Result<Listing, ObligationError> {
+    seller.require_auth();
+
+    let obligation = nft::get_obligation(env, &agreement_id)?;
+    if obligation.owner != seller {
+        return Err(ObligationError::Unauthorized);
+    }
+
+    lien::ensure_unencumbered(env, &agreement_id)?;
+    check_sale_terms(env, &token, price)?;
+
+    let listing = Listing {
+        agreement_id: agreement_id.clone(),
+        seller: seller.clone(),
+        token: token.clone(),
+        price,
+        listed_at: env.ledger().timestamp(),
+    };
+    let key = DataKey::Listing(agreement_id.clone());
+    env.storage().persistent().set(&key, &listing);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+
+    events::obligation_listed(env, agreement_id, seller, token, price);
+
+    Ok(listing)
+}
+
+pub(crate) fn delist(
+    env: &Env,
+    seller: Address,
+    agreement_id: String,
+) -> Result<(), ObligationError> {
+    seller.require_auth();
+
+    let listing = get_listing(env, &agreement_id).ok_or(ObligationError::ListingNotFound)?;
+    if listing.seller != seller {
+        return Err(ObligationError::Unauthorized);
+    }
+
+    clear_listing(env, &agreement_id);
+
+    events::obligation_delisted(env, agreement_id, seller);
+
+    Ok(())
+}
+
+/// Buy a listed obligation. `price` must match the listing so a seller
+/// cannot reprice it underneath a pending purchase.
+pub(crate) fn buy(
+    env: &Env,
+    buyer: Address,
+    agreement_id: String,
+    price: i128,
+) -> Result<(), ObligationError> {
+    buyer.require_auth();
+
+    let listing = get_listing(env, &agreement_id).ok_or(ObligationError::ListingNotFound)?;
+    if listing.price != price {
+        return Err(ObligationError::PriceMismatch);
+    }
+
+    // The allowlist may have changed since the obligation was listed
+    check_sale_terms(env, &listing.token, listing.price)?;
+
+    let obligation = nft::get_obligation(env, &agreement_id)?;
+    if buyer == listing.seller {
+        return Err(ObligationError::InvalidOwner);
+    }
+    lien::ensure_unencumbered(env, &agreement_id)?;
+
+    let platform_fee = pay_seller(env, &listing.token, &buyer, &listing.seller, price);
+    nft::transfer(env, obligation, &buyer)?;
+
+    events::obligation_sold(
+        env,
+        agreement_id,
+        listing.seller,
+        buyer,
+        listing.token,
+        price,
+        platform_fee,
+    );
+
+    Ok(())
+}
+
+/// Escrow an offer for an obligation. A bidder has at most one offer per
+/// obligation; making another refunds and replaces the first.
+pub(crate) fn make_offer(
+    env: &Env,
+    bidder: Address,
+    agreement_id: String,
+    token: Address,
+    amount: i128,
+    expires_at: u64,
+) -> Result<Offer, ObligationError> {
+    bidder.require_auth();
+
+    let obligation = nft::get_obligation(env, &agreement_id)?;
+    if obligation.owner == bidder {
+        return Err(ObligationError::InvalidOwner);
+    }
+
+    check_sale_terms(env, &token, amount)?;
+
+    let now = env.ledger().timestamp();
+    if expires_at <= now {
+        return Err(ObligationError::OfferExpired);
+    }
+
+    if let Some(previous) = get_offer(env, &agreement_id, &bidder) {
+        token::Client::new(env, &previous.token).transfer(
+            &env.current_contract_address(),
+            &bidder,
+            &previous.amount,
+        );
+    }
+
+    token::Client::new(env, &token).transfer(&bidder, env.current_contract_address(), &amount);
+
+    let offer = Offer {
+        agreement_id: agreement_id.clone(),
+        bidder: bidder.clone(),
+        token: token.clone(),
+        amount,
+        made_at: now,
+        expires_at,
+    };
+    let key = DataKey::Offer(agreement_id.clone(), bidder.clone());
+    env.storage().persistent().set(&key, &offer);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+
+    events::offer_made(env, agreement_id, bidder, token, amount, expires_at);
+
+    Ok(offer)
+}
+
+/// Withdraw an offer, expired or not, and return its escrow
+pub(crate) fn cancel_offer(
+    env: &Env,
+    bidder: Address,
+    agreement_id: String,
+) -> Result<i128, ObligationError> {
+    bidder.require_auth();
+
+    let offer = get_offer(env, &agreement_id, &bidder).ok_or(ObligationError::OfferNotFound)?;
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Offer(agreement_id.clone(), bidder.clone()));
+
+    token::Client::new(env, &offer.token).transfer(
+        &env.current_contract_address(),
+        &bidder,
+        &offer.amount,
+    );
+
+    events::offer_cancelled(env, agreement_id, bidder, offer.amount);
+
+    Ok(offer.amount)
+}
+
+/// Sell an obligation to a bidder at their escrowed offer
+pub(crate) fn accept_offer(
+    env: &Env,
+    owner: Address,
+    agreement_id: String,
+    bidder: Address,
+) -> Result<(), ObligationError> {
+    owner.require_auth();
+
+    let obligation = nft::get_obligation(env, &agreement_id)?;
+    if obligation.owner != owner {
+        return Err(ObligationError::Unauthorized);
+    }
+
+    let offer = get_offer(env, &agreement_id, &bidder).ok_or(ObligationError::OfferNotFound)?;
+    if env.ledger().timestamp() > offer.expires_at {
+        return Err(ObligationError::OfferExpired);
+    }
+    lien::ensure_unencumbered(env, &agreement_id)?;
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Offer(agreement_id.clone(), bidder.clone()));
+
+    let platform_fee = pay_seller(
+        env,
+        &offer.token,
+        &env.current_contract_address(),
+        &owner,
+        offer.amount,
+    );
+    nft::transfer(env, obligation, &bidder)?;
+
+    events::obligation_sold(
+        env,
+        agreement_id,
+        owner,
+        bidder,
+        offer.token,
+        offer.amount,
+        platform_fee,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+//! Ownership bookkeeping behind the non-fungible token interface.
+//!
+//! Each obligation is a token identified by its agreement ID. Besides the
+//! owner, the contract tracks per-owner enumeration, single-token approvals
+//! and operator approvals so wallets and marketplaces can trade obligations.
+use soroban_sdk::{vec, Address, Env, IntoVal, String, Symbol, Vec};
+
+use crate::errors::ObligationError;
+use crate::events;
+use crate::lien::ensure_unencumbered;
+use crate::marketplace::clear_listing;
+use crate::storage::DataKey;
+use crate::types::{RentAgreement, RentObligation};
+
+/// Upper bound on the length of a token URI
+pub const MAX_TOKEN_URI_LEN: usize = 256;
+
+pub(crate) fn get_obligation(
+    env: &Env,
+    agreement_id: &String,
+) -> Result<RentObligation, ObligationError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Obligation(agreement_id.clone()))
+        .ok_or(ObligationError::ObligationNotFound)
+}
+
+/// Fetch an agreement of record from the chioma contract
+pub(crate) fn load_agreement(
+    env: &Env,
+    agreement_id: &String,
+) -> Result<RentAgreement, ObligationError> {
+    let chioma: Address = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ChiomaContract)
+        .ok_or(ObligationError::NotInitialized)?;
+    let agreement: Option<RentAgreement> = env.invoke_contract(
+        &chioma,
+        &Symbol::new(env, "get_agreement"),
+        vec![env, agreement_id.into_val(env)],
+    );
+    agreement.ok_or(ObligationError::AgreementNotFound)
+}
+
+/// Tokens held by `owner`, in the order they were received
+pub(crate) fn owner_tokens(env: &Env, owner: &Address) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnerTokens(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_owner_tokens(env: &Env, owner: &Address, tokens: &Vec<String>) {
+    let key = DataKey::OwnerTokens(owner.clone());
+    if tokens.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, tokens);
+        env.storage().persistent().extend_ttl(&key, 500000, 500000);
+    }
+}
+
+/// Record `owner` as the holder of a newly minted token
+pub(crate) fn add_token(env: &Env, owner: &Address, agreement_id: &String) {
+    let mut tokens = owner_tokens(env, owner);
+    tokens.push_back(agreement_id.clone());
+    set_owner_tokens(env, owner, &tokens);
+}
+
+/// Drop a token from its owner's enumeration
+pub(crate) fn remove_token(env: &Env, owner: &Address, agreement_id: &String) {
+    let mut tokens = owner_tokens(env, owner);
+    if let Some(index) = tokens.first_index_of(agreement_id) {
+        tokens.remove(index);
+    }
+    set_owner_tokens(env, owner, &tokens);
+}
+
+/// Address approved to transfer a single token, if any
+pub(crate) fn get_approved(env: &Env, agreement_id: &String) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Approval(agreement_id.clone()))
+}
+
+/// Whether `operator` may manage every token held by `owner`
+pub(crate) fn is_approved_for_all(env: &Env, owner: &Address, operator: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OperatorApproval(owner.clone(), operator.clone()))
+        .unwrap_or(false)
+}
+
+/// Whether `spender` may move or approve a token held by `owner`
+pub(crate) fn is_authorized(
+    env: &Env,
+    owner: &Address,
+    spender: &Address,
+    agreement_id: &String,
+) -> bool {
+    spender == owner
+        || is_approved_for_all(env, owner, spender)
+        || get_approved(env, agreement_id).as_ref() == Some(spender)
+}
+
+/// Move a token from its current owner to `to`, clearing its approval and
+/// any listing made by the previous owner. Pledged tokens cannot move.
+pub(crate) fn transfer(
+    env: &Env,
+    mut obligation: RentObligation,
+    to: &Address,
+) -> Result<(), ObligationError> {
+    let from = obligation.owner.clone();
+    let agreement_id = obligation.agreement_id.clone();
+    if *to == from {
+        return Err(ObligationError::InvalidOwner);
+    }
+
+    ensure_unencumbered(env, &agreement_id)?;
+
+    obligation.owner = to.clone();
+
+    let obligation_key = DataKey::Obligation(agreement_id.clone());
+    env.storage().persistent().set(&obligation_key, &obligation);
+    env.storage()
+        .persistent()
+        .extend_ttl(&obligation_key, 500000, 500000);
+
+    let owner_key = DataKey::Owner(agreement_id.clone());
+    env.storage().persistent().set(&owner_key, to);
+    env.storage()
+        .persistent()
+        .extend_ttl(&owner_key, 500000, 500000);
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Approval(agreement_id.clone()));
+    clear_listing(env, &agreement_id);
+    remove_token(env, &from, &agreement_id);
+    add_token(env, to, &agreement_id);
+
+    events::obligation_transferred(env, agreement_id, from, to.clone());
+
+    Ok(())
+}
+
+/// Token URI formed by appending the agreement ID to the base URI
+pub(crate) fn token_uri(
+    env: &Env,
+    base_uri: &String,
+    agreement_id: &String,
+) -> Result<String, ObligationError> {
+    let base_len = base_uri.len() as usize;
+    let len = base_len + agreement_id.len() as usize;
+    if len > MAX_TOKEN_URI_LEN {
+        return Err(ObligationError::TokenUriTooLong);
+    }
+
+    let mut buf = [0u8; MAX_TOKEN_URI_LEN];
+    base_uri.copy_into_slice(&mut buf[..base_len]);
+    agreement_id.copy_into_slice(&mut buf[base_len..len]);
+    Ok(String::from_bytes(env, &buf[..len]))
+}
@@ -0,0 +1,141 @@
+//! Rent received per payee and final settlement of an obligation.
+//!
+//! The payment contract reports every landlord leg it pays for a tokenized
+//! agreement, along with the address it actually paid: the owner, a
+//! lienholder, or this contract while the obligation is fractionalized. Each
+//! payee's running total is kept under its own key, so neither reporting nor
+//! settlement walks the agreement's payment or ownership history. When the
+//! obligation is burned the totals stay on record with its settlement.
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::errors::ObligationError;
+use crate::events;
+use crate::fractions;
+use crate::lien;
+use crate::marketplace::get_admin;
+use crate::nft;
+use crate::storage::DataKey;
+use crate::types::OwnerRentTotal;
+
+/// Upper bound on the payee totals returned per page
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Payment contract allowed to report rent, if one has been configured
+pub(crate) fn get_payment_contract(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::PaymentContract)
+}
+
+/// Set the payment contract allowed to report rent (admin only)
+pub(crate) fn set_payment_contract(
+    env: &Env,
+    payment_contract: Address,
+) -> Result<(), ObligationError> {
+    let admin = get_admin(env)?;
+    admin.require_auth();
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::PaymentContract, &payment_contract);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::PaymentContract, 500000, 500000);
+
+    events::payment_contract_updated(env, admin, payment_contract);
+
+    Ok(())
+}
+
+/// Number of addresses that have received rent for an obligation
+pub(crate) fn payee_count(env: &Env, agreement_id: &String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RentPayeeCount(agreement_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Landlord rent `payee` has received for an obligation
+pub(crate) fn rent_received(env: &Env, agreement_id: &String, payee: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RentReceived(agreement_id.clone(), payee.clone()))
+        .unwrap_or(0)
+}
+
+/// Record rent the payment contract paid for an agreement's landlord leg.
+/// A negative amount records rent clawed back by a refund. Rent for
+/// agreements without an obligation is not tracked; rent paid to a
+/// lienholder repays their lien, and rent paid to this contract is credited
+/// to the obligation's shareholders.
+pub(crate) fn record_rent_paid(
+    env: &Env,
+    agreement_id: String,
+    payee: Address,
+    amount: i128,
+) -> Result<(), ObligationError> {
+    let payment_contract =
+        get_payment_contract(env).ok_or(ObligationError::PaymentContractNotSet)?;
+    payment_contract.require_auth();
+
+    if nft::get_obligation(env, &agreement_id).is_err() {
+        return Ok(());
+    }
+
+    credit_payee(env, &agreement_id, &payee, amount);
+    lien::credit_repayment(env, &agreement_id, &payee, amount);
+    if payee == env.current_contract_address() {
+        fractions::credit_rent(env, &agreement_id, amount);
+    }
+
+    Ok(())
+}
+
+/// Totals of the payees after the first `start`, in the order they were
+/// first paid, up to `limit` of them (capped at `MAX_PAGE_SIZE`)
+pub(crate) fn owner_totals(
+    env: &Env,
+    agreement_id: &String,
+    start: u32,
+    limit: u32,
+) -> Vec<OwnerRentTotal> {
+    let count = payee_count(env, agreement_id);
+    let mut totals = Vec::new(env);
+    if start >= count {
+        return totals;
+    }
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+    for index in start..end {
+        let owner: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RentPayee(agreement_id.clone(), index))
+            .unwrap();
+        totals.push_back(OwnerRentTotal {
+            rent_received: rent_received(env, agreement_id, &owner),
+            owner,
+        });
+    }
+    totals
+}
+
+fn credit_payee(env: &Env, agreement_id: &String, payee: &Address, amount: i128) {
+    let key = DataKey::RentReceived(agreement_id.clone(), payee.clone());
+    if !env.storage().persistent().has(&key) {
+        let count = payee_count(env, agreement_id);
+        let payee_key = DataKey::RentPayee(agreement_id.clone(), count);
+        env.storage().persistent().set(&payee_key, payee);
+        env.storage()
+            .persistent()
+            .extend_ttl(&payee_key, 500000, 500000);
+        let count_key = DataKey::RentPayeeCount(agreement_id.clone());
+        env.storage().persistent().set(&count_key, &(count + 1));
+        env.storage()
+            .persistent()
+            .extend_ttl(&count_key, 500000, 500000);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&key, &(rent_received(env, agreement_id, payee) + amount));
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+}
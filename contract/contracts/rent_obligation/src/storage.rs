@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, String};
+use soroban_sdk::{contracttype, Address, String};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -7,4 +7,23 @@ pub enum DataKey {
     Obligation(String),
     Owner(String),
     ObligationCount,
+    BaseUri,
+    ChiomaContract,
+    OwnerTokens(Address),
+    Approval(String),
+    OperatorApproval(Address, Address),
+    Settlement(String),
+    FractionSeriesCount(String),
+    Fractions(String, u32),
+    Shareholder(String, u32, Address),
+    Admin,
+    MarketFee,
+    SaleToken(Address),
+    Listing(String),
+    Offer(String, Address),
+    Lien(String),
+    PaymentContract,
+    RentPayeeCount(String),
+    RentPayee(String, u32),
+    RentReceived(String, Address),
 }
@@ -1,20 +1,111 @@
 use super::*;
+use crate::types::{AgreementStatus, RentAgreement};
 use soroban_sdk::{
-    testutils::{Address as _, Events, MockAuth, MockAuthInvoke},
-    Address, Env, IntoVal, String,
+    contract, contractimpl,
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    token, Address, Bytes, Env, IntoVal, Map, String,
 };
 
+/// Mock chioma contract holding agreements of record.
+#[contract]
+pub struct MockChiomaContract;
+
+#[contractimpl]
+impl MockChiomaContract {
+    pub fn add_agreement(env: Env, agreement: RentAgreement) {
+        env.storage()
+            .persistent()
+            .set(&agreement.agreement_id.clone(), &agreement);
+    }
+
+    pub fn get_agreement(env: Env, agreement_id: String) -> Option<RentAgreement> {
+        env.storage().persistent().get(&agreement_id)
+    }
+}
+
+fn create_chioma(env: &Env) -> MockChiomaContractClient<'_> {
+    let contract_id = env.register(MockChiomaContract, ());
+    MockChiomaContractClient::new(env, &contract_id)
+}
+
+fn create_agreement(
+    env: &Env,
+    agreement_id: &String,
+    landlord: &Address,
+    status: AgreementStatus,
+) -> RentAgreement {
+    RentAgreement {
+        agreement_id: agreement_id.clone(),
+        landlord: landlord.clone(),
+        tenant: Address::generate(env),
+        agent: None,
+        monthly_rent: 1000,
+        security_deposit: 0,
+        start_date: 0,
+        end_date: 0,
+        agent_commission_rate: 0,
+        status,
+        total_rent_paid: 0,
+        payment_count: 0,
+        signed_at: None,
+        payment_token: Address::generate(env),
+        next_payment_due: 0,
+        payment_history: Map::new(env),
+    }
+}
+
+fn add_active_agreement(
+    env: &Env,
+    chioma: &MockChiomaContractClient,
+    agreement_id: &String,
+    landlord: &Address,
+) {
+    chioma.add_agreement(&create_agreement(
+        env,
+        agreement_id,
+        landlord,
+        AgreementStatus::Active,
+    ));
+}
+
 fn create_contract(env: &Env) -> TokenizedRentObligationContractClient<'_> {
     let contract_id = env.register(TokenizedRentObligationContract, ());
     TokenizedRentObligationContractClient::new(env, &contract_id)
 }
 
+fn base_uri(env: &Env) -> String {
+    String::from_str(env, "https://chioma.example/obligations/")
+}
+
+/// Initialize with only the admin's authorization mocked.
+fn initialize_as_admin(
+    env: &Env,
+    client: &TokenizedRentObligationContractClient<'_>,
+    chioma: &Address,
+) -> Address {
+    let admin = Address::generate(env);
+    client
+        .mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "initialize",
+                args: (&admin, chioma, base_uri(env)).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .initialize(&admin, chioma, &base_uri(env));
+    admin
+}
+
 #[test]
 fn test_successful_initialization() {
     let env = Env::default();
+    env.mock_all_auths();
     let client = create_contract(&env);
 
-    let result = client.try_initialize();
+    let chioma = create_chioma(&env);
+    let result = client.try_initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
     assert!(result.is_ok());
 
     let count = client.get_obligation_count();
@@ -24,11 +115,41 @@ fn test_successful_initialization() {
 #[test]
 #[should_panic(expected = "Error(Contract, #1)")]
 fn test_double_initialization_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
+}
+
+#[test]
+fn test_initialize_requires_admin_auth() {
     let env = Env::default();
     let client = create_contract(&env);
+    let chioma = create_chioma(&env);
 
-    client.initialize();
-    client.initialize();
+    // Only the admin being installed can authorize initialization
+    let attacker = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "initialize",
+                args: (&admin, &chioma.address, base_uri(&env)).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_initialize(&admin, &chioma.address, &base_uri(&env));
+    assert!(result.is_err());
+    assert_eq!(client.get_chioma_contract(), None);
+
+    let admin = initialize_as_admin(&env, &client, &chioma.address);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_chioma_contract(), Some(chioma.address.clone()));
 }
 
 #[test]
@@ -37,11 +158,13 @@ fn test_mint_obligation() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
 
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
     let result = client.try_mint_obligation(&agreement_id, &landlord);
     assert!(result.is_ok());
 
@@ -68,11 +191,13 @@ fn test_mint_obligation_requires_auth() {
     let env = Env::default();
 
     let client = create_contract(&env);
-    client.initialize();
+    let chioma = create_chioma(&env);
+    initialize_as_admin(&env, &client, &chioma.address);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
 
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
     client.mint_obligation(&agreement_id, &landlord);
 }
 
@@ -83,11 +208,13 @@ fn test_mint_duplicate_obligation_fails() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
 
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
     client.mint_obligation(&agreement_id, &landlord);
     client.mint_obligation(&agreement_id, &landlord);
 }
@@ -112,12 +239,14 @@ fn test_transfer_obligation() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
 
     let landlord = Address::generate(&env);
     let new_owner = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
 
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
     client.mint_obligation(&agreement_id, &landlord);
 
     let result = client.try_transfer_obligation(&landlord, &new_owner, &agreement_id);
@@ -138,12 +267,14 @@ fn test_transfer_obligation_requires_auth() {
     let env = Env::default();
 
     let client = create_contract(&env);
-    client.initialize();
+    let chioma = create_chioma(&env);
+    initialize_as_admin(&env, &client, &chioma.address);
 
     let landlord = Address::generate(&env);
     let new_owner = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
 
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
     client
         .mock_auths(&[MockAuth {
             address: &landlord,
@@ -166,7 +297,8 @@ fn test_transfer_nonexistent_obligation_fails() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
 
     let landlord = Address::generate(&env);
     let new_owner = Address::generate(&env);
@@ -182,13 +314,15 @@ fn test_transfer_from_non_owner_fails() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
 
     let landlord = Address::generate(&env);
     let fake_owner = Address::generate(&env);
     let new_owner = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
 
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
     client.mint_obligation(&agreement_id, &landlord);
 
     client.transfer_obligation(&fake_owner, &new_owner, &agreement_id);
@@ -200,7 +334,8 @@ fn test_multiple_obligations() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
 
     let landlord1 = Address::generate(&env);
     let landlord2 = Address::generate(&env);
@@ -210,8 +345,11 @@ fn test_multiple_obligations() {
     let agreement_id2 = String::from_str(&env, "agreement_002");
     let agreement_id3 = String::from_str(&env, "agreement_003");
 
+    add_active_agreement(&env, &chioma, &agreement_id1, &landlord1);
     client.mint_obligation(&agreement_id1, &landlord1);
+    add_active_agreement(&env, &chioma, &agreement_id2, &landlord2);
     client.mint_obligation(&agreement_id2, &landlord2);
+    add_active_agreement(&env, &chioma, &agreement_id3, &landlord3);
     client.mint_obligation(&agreement_id3, &landlord3);
 
     assert_eq!(client.get_obligation_count(), 3);
@@ -224,8 +362,10 @@ fn test_multiple_obligations() {
 #[test]
 fn test_get_nonexistent_obligation() {
     let env = Env::default();
+    env.mock_all_auths();
     let client = create_contract(&env);
-    client.initialize();
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
 
     let agreement_id = String::from_str(&env, "nonexistent");
 
@@ -245,7 +385,8 @@ fn test_transfer_chain() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
 
     let landlord = Address::generate(&env);
     let buyer1 = Address::generate(&env);
@@ -253,6 +394,7 @@ fn test_transfer_chain() {
     let buyer3 = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
 
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
     client.mint_obligation(&agreement_id, &landlord);
     assert_eq!(
         client.get_obligation_owner(&agreement_id),
@@ -286,15 +428,949 @@ fn test_events_emitted() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
 
     let landlord = Address::generate(&env);
     let new_owner = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
 
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
     client.mint_obligation(&agreement_id, &landlord);
     client.transfer_obligation(&landlord, &new_owner, &agreement_id);
 
     let all_events = env.events().all();
     assert!(!all_events.is_empty());
 }
+
+#[test]
+fn test_nft_metadata_and_enumeration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
+
+    let landlord = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let first = String::from_str(&env, "agreement_001");
+    let second = String::from_str(&env, "agreement_002");
+
+    add_active_agreement(&env, &chioma, &first, &landlord);
+    client.mint_obligation(&first, &landlord);
+    add_active_agreement(&env, &chioma, &second, &landlord);
+    client.mint_obligation(&second, &landlord);
+
+    assert_eq!(
+        client.name(),
+        String::from_str(&env, "Chioma Rent Obligation")
+    );
+    assert_eq!(client.symbol(), String::from_str(&env, "CRO"));
+    assert_eq!(
+        client.token_uri(&first),
+        String::from_str(&env, "https://chioma.example/obligations/agreement_001")
+    );
+    assert_eq!(
+        client.try_token_uri(&String::from_str(&env, "missing")),
+        Err(Ok(ObligationError::ObligationNotFound))
+    );
+
+    assert_eq!(client.balance(&landlord), 2);
+    assert_eq!(client.owner_of(&second), landlord);
+    assert_eq!(client.token_of_owner_by_index(&landlord, &1), second);
+    assert_eq!(
+        client.try_token_of_owner_by_index(&landlord, &2),
+        Err(Ok(ObligationError::IndexOutOfBounds))
+    );
+
+    client.transfer_obligation(&landlord, &buyer, &first);
+    assert_eq!(client.balance(&landlord), 1);
+    assert_eq!(
+        client.tokens_of_owner(&landlord),
+        soroban_sdk::vec![&env, second]
+    );
+    assert_eq!(
+        client.tokens_of_owner(&buyer),
+        soroban_sdk::vec![&env, first]
+    );
+}
+
+#[test]
+fn test_transfer_from_with_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
+
+    let landlord = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
+    client.mint_obligation(&agreement_id, &landlord);
+
+    let result = client.try_transfer_from(&marketplace, &landlord, &buyer, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::Unauthorized)));
+
+    client.approve(&landlord, &Some(marketplace.clone()), &agreement_id);
+    assert_eq!(
+        client.get_approved(&agreement_id),
+        Some(marketplace.clone())
+    );
+
+    let result = client.try_transfer_from(&marketplace, &buyer, &landlord, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::InvalidOwner)));
+
+    client.transfer_from(&marketplace, &landlord, &buyer, &agreement_id);
+    assert_eq!(client.owner_of(&agreement_id), buyer);
+    assert_eq!(client.get_approved(&agreement_id), None);
+
+    // An operator can approve and move every token of the owner
+    client.set_approval_for_all(&buyer, &operator, &true);
+    assert!(client.is_approved_for_all(&buyer, &operator));
+    client.approve(&operator, &Some(marketplace.clone()), &agreement_id);
+    client.transfer_from(&operator, &buyer, &landlord, &agreement_id);
+    assert_eq!(client.owner_of(&agreement_id), landlord);
+
+    client.set_approval_for_all(&buyer, &operator, &false);
+    assert!(!client.is_approved_for_all(&buyer, &operator));
+    let result = client.try_approve(&operator, &None, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::Unauthorized)));
+}
+
+#[test]
+fn test_mint_requires_matching_active_agreement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
+
+    let landlord = Address::generate(&env);
+    let impostor = Address::generate(&env);
+    let active = String::from_str(&env, "agreement_active");
+    let draft = String::from_str(&env, "agreement_draft");
+    let unknown = String::from_str(&env, "agreement_unknown");
+
+    add_active_agreement(&env, &chioma, &active, &landlord);
+    chioma.add_agreement(&create_agreement(
+        &env,
+        &draft,
+        &landlord,
+        AgreementStatus::Draft,
+    ));
+
+    let result = client.try_mint_obligation(&unknown, &landlord);
+    assert_eq!(result, Err(Ok(ObligationError::AgreementNotFound)));
+
+    let result = client.try_mint_obligation(&draft, &landlord);
+    assert_eq!(result, Err(Ok(ObligationError::AgreementNotActive)));
+
+    let result = client.try_mint_obligation(&active, &impostor);
+    assert_eq!(result, Err(Ok(ObligationError::NotAgreementLandlord)));
+
+    client.mint_obligation(&active, &landlord);
+    assert_eq!(client.get_obligation_count(), 1);
+    assert_eq!(client.get_chioma_contract(), Some(chioma.address.clone()));
+}
+
+#[test]
+fn test_burn_after_agreement_ends_records_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
+
+    let landlord = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let mut agreement = create_agreement(&env, &agreement_id, &landlord, AgreementStatus::Active);
+    chioma.add_agreement(&agreement);
+
+    let result = client.try_record_rent_paid(&agreement_id, &landlord, &900);
+    assert_eq!(result, Err(Ok(ObligationError::PaymentContractNotSet)));
+    client.set_payment_contract(&Address::generate(&env));
+
+    // Rent before minting is not attributed to anyone
+    client.record_rent_paid(&agreement_id, &landlord, &900);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.mint_obligation(&agreement_id, &landlord);
+    client.record_rent_paid(&agreement_id, &landlord, &900);
+
+    env.ledger().with_mut(|li| li.timestamp = 300);
+    client.transfer_obligation(&landlord, &buyer, &agreement_id);
+
+    let result = client.try_burn_obligation(&keeper, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::Unauthorized)));
+
+    // Rent is credited to whoever was actually paid
+    client.record_rent_paid(&agreement_id, &buyer, &900);
+    client.record_rent_paid(&agreement_id, &buyer, &900);
+    client.record_rent_paid(&agreement_id, &landlord, &-100);
+    agreement.status = AgreementStatus::Terminated;
+    chioma.add_agreement(&agreement);
+
+    env.ledger().with_mut(|li| li.timestamp = 600);
+    let settlement = client.burn_obligation(&keeper, &agreement_id);
+    assert_eq!(settlement.final_owner, buyer);
+    assert_eq!(settlement.burned_by, keeper);
+    assert_eq!(settlement.burned_at, 600);
+    assert_eq!(settlement.payee_count, 2);
+
+    let totals = client.get_owner_totals(&agreement_id, &0, &10);
+    assert_eq!(totals.len(), 2);
+    let landlord_total = totals.get(0).unwrap();
+    assert_eq!(landlord_total.owner, landlord);
+    assert_eq!(landlord_total.rent_received, 800);
+    let buyer_total = totals.get(1).unwrap();
+    assert_eq!(buyer_total.owner, buyer);
+    assert_eq!(buyer_total.rent_received, 1800);
+    assert_eq!(client.get_owner_totals(&agreement_id, &1, &10).len(), 1);
+    assert_eq!(client.get_owner_totals(&agreement_id, &2, &10).len(), 0);
+
+    assert_eq!(client.get_settlement(&agreement_id), Some(settlement));
+    assert!(!client.has_obligation(&agreement_id));
+    assert_eq!(client.get_obligation_owner(&agreement_id), None);
+    assert_eq!(client.get_obligation_count(), 0);
+    assert_eq!(client.balance(&buyer), 0);
+}
+
+#[test]
+fn test_owner_can_burn_active_obligation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
+
+    let landlord = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
+    client.mint_obligation(&agreement_id, &landlord);
+
+    let settlement = client.burn_obligation(&landlord, &agreement_id);
+    assert_eq!(settlement.payee_count, 0);
+    assert_eq!(client.get_obligation_count(), 0);
+
+    let result = client.try_burn_obligation(&landlord, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::ObligationNotFound)));
+
+    // The settlement stays on record, so the agreement cannot be re-minted
+    let result = client.try_mint_obligation(&agreement_id, &landlord);
+    assert_eq!(result, Err(Ok(ObligationError::ObligationAlreadyExists)));
+}
+
+#[test]
+fn test_fractional_shares_claim_rent_pro_rata() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
+    client.set_payment_contract(&Address::generate(&env));
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(&env, &token.address());
+    let token_admin_client = token::StellarAssetClient::new(&env, &token.address());
+
+    let landlord = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let mut agreement = create_agreement(&env, &agreement_id, &landlord, AgreementStatus::Active);
+    agreement.payment_token = token.address();
+    chioma.add_agreement(&agreement);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.mint_obligation(&agreement_id, &landlord);
+
+    let result = client.try_fractionalize(&investor, &agreement_id, &100);
+    assert_eq!(result, Err(Ok(ObligationError::Unauthorized)));
+    let result = client.try_fractionalize(&landlord, &agreement_id, &0);
+    assert_eq!(result, Err(Ok(ObligationError::InvalidShareAmount)));
+
+    let fractions = client.fractionalize(&landlord, &agreement_id, &100);
+    assert_eq!(fractions.total_shares, 100);
+    assert_eq!(fractions.fractionalized_at, 100);
+    assert_eq!(
+        client.get_obligation_owner(&agreement_id),
+        Some(client.address.clone())
+    );
+    assert_eq!(client.share_balance(&agreement_id, &landlord), 100);
+
+    // Rent routed while the landlord holds every share
+    token_admin_client.mint(&client.address, &1000);
+    client.record_rent_paid(&agreement_id, &client.address, &1000);
+
+    client.transfer_shares(&landlord, &investor, &agreement_id, &25);
+    assert_eq!(client.share_balance(&agreement_id, &landlord), 75);
+    assert_eq!(client.share_balance(&agreement_id, &investor), 25);
+
+    let result = client.try_transfer_shares(&investor, &landlord, &agreement_id, &26);
+    assert_eq!(result, Err(Ok(ObligationError::InsufficientShares)));
+
+    // Rent routed after the split is shared 75/25; rent paid to anyone
+    // else does not reach the pool
+    token_admin_client.mint(&client.address, &1000);
+    client.record_rent_paid(&agreement_id, &client.address, &1000);
+    client.record_rent_paid(&agreement_id, &landlord, &1000);
+    assert_eq!(
+        client.get_fractions(&agreement_id).unwrap().rent_received,
+        2000
+    );
+
+    assert_eq!(client.claimable_rent(&agreement_id, &landlord), 1750);
+    assert_eq!(client.claimable_rent(&agreement_id, &investor), 250);
+
+    assert_eq!(client.claim_rent(&investor, &agreement_id), 250);
+    assert_eq!(token_client.balance(&investor), 250);
+    assert_eq!(client.claim_rent(&investor, &agreement_id), 0);
+
+    assert_eq!(client.claim_rent(&landlord, &agreement_id), 1750);
+    assert_eq!(token_client.balance(&landlord), 1750);
+    assert_eq!(token_client.balance(&client.address), 0);
+
+    let result = client.try_burn_obligation(&landlord, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::AlreadyFractionalized)));
+}
+
+#[test]
+fn test_recombine_restores_single_ownership() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
+    client.set_payment_contract(&Address::generate(&env));
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(token_admin);
+    let token_admin_client = token::StellarAssetClient::new(&env, &token.address());
+
+    let landlord = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let mut agreement = create_agreement(&env, &agreement_id, &landlord, AgreementStatus::Active);
+    agreement.payment_token = token.address();
+    chioma.add_agreement(&agreement);
+
+    client.mint_obligation(&agreement_id, &landlord);
+    client.fractionalize(&landlord, &agreement_id, &10);
+    let result = client.try_fractionalize(&landlord, &agreement_id, &10);
+    assert_eq!(result, Err(Ok(ObligationError::Unauthorized)));
+
+    client.transfer_shares(&landlord, &investor, &agreement_id, &4);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    token_admin_client.mint(&client.address, &500);
+    client.record_rent_paid(&agreement_id, &client.address, &500);
+
+    let result = client.try_recombine(&landlord, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::InsufficientShares)));
+
+    client.transfer_shares(&investor, &landlord, &agreement_id, &4);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.recombine(&landlord, &agreement_id);
+
+    assert_eq!(
+        client.get_obligation_owner(&agreement_id),
+        Some(landlord.clone())
+    );
+    assert_eq!(client.share_balance(&agreement_id, &landlord), 0);
+    assert_eq!(
+        client.get_fractions(&agreement_id).unwrap().recombined_at,
+        Some(200)
+    );
+
+    // Rent after recombination goes to the owner directly, not to shares
+    client.record_rent_paid(&agreement_id, &landlord, &500);
+    assert_eq!(client.claimable_rent(&agreement_id, &landlord), 300);
+    assert_eq!(client.claim_rent(&investor, &agreement_id), 200);
+
+    let result = client.try_transfer_shares(&landlord, &investor, &agreement_id, &1);
+    assert_eq!(result, Err(Ok(ObligationError::NotFractionalized)));
+}
+
+#[test]
+fn test_refractionalize_after_recombine_starts_new_series() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
+    client.set_payment_contract(&Address::generate(&env));
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(token_admin);
+    let token_admin_client = token::StellarAssetClient::new(&env, &token.address());
+    let token_client = token::Client::new(&env, &token.address());
+
+    let landlord = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let mut agreement = create_agreement(&env, &agreement_id, &landlord, AgreementStatus::Active);
+    agreement.payment_token = token.address();
+    chioma.add_agreement(&agreement);
+
+    client.mint_obligation(&agreement_id, &landlord);
+    let first = client.fractionalize(&landlord, &agreement_id, &10);
+    assert_eq!(first.generation, 0);
+    let result = client.try_fractionalize(&landlord, &agreement_id, &10);
+    assert_eq!(result, Err(Ok(ObligationError::Unauthorized)));
+
+    // The investor earns 40% of the first series' rent
+    client.transfer_shares(&landlord, &investor, &agreement_id, &4);
+    token_admin_client.mint(&client.address, &500);
+    client.record_rent_paid(&agreement_id, &client.address, &500);
+    client.transfer_shares(&investor, &landlord, &agreement_id, &4);
+    client.recombine(&landlord, &agreement_id);
+
+    // A recombined obligation can be split again into a fresh series
+    env.ledger().with_mut(|li| li.timestamp = 300);
+    let second = client.fractionalize(&landlord, &agreement_id, &100);
+    assert_eq!(second.generation, 1);
+    assert_eq!(second.rent_received, 0);
+    assert_eq!(client.get_fractions(&agreement_id), Some(second));
+    assert_eq!(client.share_balance(&agreement_id, &landlord), 100);
+    assert_eq!(client.share_balance(&agreement_id, &investor), 0);
+    let result = client.try_fractionalize(&landlord, &agreement_id, &100);
+    assert_eq!(result, Err(Ok(ObligationError::Unauthorized)));
+
+    // Rent to the new series is shared by its holders only
+    client.transfer_shares(&landlord, &buyer, &agreement_id, &50);
+    token_admin_client.mint(&client.address, &1000);
+    client.record_rent_paid(&agreement_id, &client.address, &1000);
+
+    assert_eq!(client.claimable_rent(&agreement_id, &investor), 200);
+    assert_eq!(client.claimable_rent(&agreement_id, &buyer), 500);
+    assert_eq!(client.claimable_rent(&agreement_id, &landlord), 800);
+
+    // Earlier holders still claim what they earned before recombination
+    assert_eq!(client.claim_rent(&investor, &agreement_id), 200);
+    assert_eq!(client.claim_rent(&buyer, &agreement_id), 500);
+    assert_eq!(client.claim_rent(&landlord, &agreement_id), 800);
+    assert_eq!(token_client.balance(&investor), 200);
+    assert_eq!(token_client.balance(&buyer), 500);
+    assert_eq!(token_client.balance(&landlord), 800);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.claim_rent(&landlord, &agreement_id), 0);
+}
+
+/// Payment, chioma and obligation contracts wired together, with an active
+/// agreement of `monthly_rent` whose tenant holds enough to pay a year.
+struct Deployment<'a> {
+    client: TokenizedRentObligationContractClient<'a>,
+    payment: payment::PaymentContractClient<'a>,
+    token: token::Client<'a>,
+    tenant: Address,
+    landlord: Address,
+    agreement_id: String,
+}
+
+fn deploy_with_payments(env: &Env, monthly_rent: i128) -> Deployment<'_> {
+    let admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(env));
+
+    let chioma_id = env.register(chioma::Contract, ());
+    let chioma = chioma::ContractClient::new(env, &chioma_id);
+    chioma.initialize(
+        &admin,
+        &chioma::Config {
+            fee_bps: 0,
+            fee_collector: Address::generate(env),
+            paused: false,
+        },
+    );
+
+    let payment_id = env.register(payment::PaymentContract, ());
+    let payment = payment::PaymentContractClient::new(env, &payment_id);
+    payment.initialize(
+        &admin,
+        &chioma_id,
+        &payment::FeeConfig {
+            fee_bps: 1000,
+            collector: Address::generate(env),
+            auto_refund_fee: false,
+        },
+    );
+    chioma.set_payment_contract(&payment_id);
+
+    let client = create_contract(env);
+    client.initialize(&admin, &chioma_id, &base_uri(env));
+    client.set_payment_contract(&payment_id);
+    payment.set_obligation_contract(&client.address);
+
+    let landlord = Address::generate(env);
+    let tenant = Address::generate(env);
+    let agreement_id = String::from_str(env, "agreement_001");
+    chioma.create_agreement(
+        &agreement_id,
+        &landlord,
+        &tenant,
+        &None,
+        &monthly_rent,
+        &0,
+        &0,
+        &(12 * 2_592_000),
+        &0,
+        &token.address(),
+    );
+    chioma.submit_agreement(&landlord, &agreement_id);
+    chioma.sign_agreement(&tenant, &agreement_id);
+    token::StellarAssetClient::new(env, &token.address()).mint(&tenant, &(monthly_rent * 12));
+
+    Deployment {
+        client,
+        payment,
+        token: token::Client::new(env, &token.address()),
+        tenant,
+        landlord,
+        agreement_id,
+    }
+}
+
+#[test]
+fn test_fraction_claims_follow_rent_paid_through_payment_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let d = deploy_with_payments(&env, 1000);
+    let investor = Address::generate(&env);
+    d.client.mint_obligation(&d.agreement_id, &d.landlord);
+    d.client.fractionalize(&d.landlord, &d.agreement_id, &100);
+    d.client
+        .transfer_shares(&d.landlord, &investor, &d.agreement_id, &25);
+
+    // The landlord leg of the payment lands in the pool and is credited
+    let record = d.payment.pay_rent(
+        &d.tenant,
+        &d.agreement_id,
+        &1000,
+        &Bytes::from_slice(&env, b"rent-1"),
+    );
+    assert_eq!(record.landlord_payee, d.client.address);
+    assert_eq!(d.token.balance(&d.client.address), 900);
+    assert_eq!(
+        d.client
+            .get_fractions(&d.agreement_id)
+            .unwrap()
+            .rent_received,
+        900
+    );
+
+    // Tokens sent to the contract outside a rent payment are not rent
+    token::StellarAssetClient::new(&env, &d.token.address).mint(&d.client.address, &5_000);
+    assert_eq!(d.client.claimable_rent(&d.agreement_id, &investor), 225);
+    assert_eq!(d.client.claimable_rent(&d.agreement_id, &d.landlord), 675);
+
+    assert_eq!(d.client.claim_rent(&investor, &d.agreement_id), 225);
+    assert_eq!(d.client.claim_rent(&d.landlord, &d.agreement_id), 675);
+    assert_eq!(d.token.balance(&investor), 225);
+    assert_eq!(d.token.balance(&d.landlord), 675);
+    assert_eq!(d.token.balance(&d.client.address), 5_000);
+    assert_eq!(
+        d.client
+            .get_fractions(&d.agreement_id)
+            .unwrap()
+            .rent_claimed,
+        900
+    );
+    assert_eq!(
+        d.client
+            .get_rent_received(&d.agreement_id, &d.client.address),
+        900
+    );
+}
+
+#[test]
+fn test_lien_repaid_through_payment_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let d = deploy_with_payments(&env, 1000);
+    let lender = Address::generate(&env);
+    d.client.mint_obligation(&d.agreement_id, &d.landlord);
+    d.client
+        .place_lien(&d.landlord, &lender, &d.agreement_id, &1_500, &true);
+
+    // The first landlord leg goes to the lender and is recorded on the lien
+    let record = d.payment.pay_rent(
+        &d.tenant,
+        &d.agreement_id,
+        &1000,
+        &Bytes::from_slice(&env, b"rent-1"),
+    );
+    assert_eq!(record.landlord_payee, lender);
+    assert_eq!(d.token.balance(&lender), 900);
+    let status = d.client.get_lien_status(&d.agreement_id);
+    assert_eq!(status.lien.repaid, 900);
+    assert!(status.redirecting);
+
+    let result = d.client.try_clear_lien(&d.landlord, &d.agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::LienOutstanding)));
+
+    // The payment crossing the principal only repays what is outstanding
+    // and the rest of the leg goes to the owner
+    env.ledger().with_mut(|li| li.timestamp = 2_592_000);
+    let record = d.payment.pay_rent(
+        &d.tenant,
+        &d.agreement_id,
+        &1000,
+        &Bytes::from_slice(&env, b"rent-2"),
+    );
+    assert_eq!(record.landlord_payee, lender);
+    assert_eq!(record.excess_payee, d.landlord);
+    assert_eq!(record.excess_amount, 300);
+    assert_eq!(d.token.balance(&lender), 1500);
+    assert_eq!(d.token.balance(&d.landlord), 300);
+    let status = d.client.get_lien_status(&d.agreement_id);
+    assert_eq!(status.lien.repaid, 1500);
+    assert_eq!(status.outstanding, 0);
+    assert!(!status.redirecting);
+
+    // The lender never releases it, so the owner clears the repaid lien
+    d.client.clear_lien(&d.landlord, &d.agreement_id);
+    assert_eq!(d.client.get_lien(&d.agreement_id), None);
+
+    env.ledger().with_mut(|li| li.timestamp = 5_184_000);
+    let record = d.payment.pay_rent(
+        &d.tenant,
+        &d.agreement_id,
+        &1000,
+        &Bytes::from_slice(&env, b"rent-3"),
+    );
+    assert_eq!(record.landlord_payee, d.landlord);
+    assert_eq!(d.token.balance(&d.landlord), 1200);
+    assert_eq!(d.client.get_rent_received(&d.agreement_id, &lender), 1500);
+    assert_eq!(
+        d.client.get_rent_received(&d.agreement_id, &d.landlord),
+        1200
+    );
+}
+
+#[test]
+fn test_market_settings_require_admin_auth() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    let chioma = create_chioma(&env);
+    let admin = initialize_as_admin(&env, &client, &chioma.address);
+
+    let attacker = Address::generate(&env);
+    let token = Address::generate(&env);
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_market_fee",
+                args: (&attacker, 10_000u32).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_market_fee(&attacker, &10_000);
+    assert!(result.is_err());
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_sale_token_allowed",
+                args: (&token, true).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_sale_token_allowed(&token, &true);
+    assert!(result.is_err());
+    assert_eq!(client.get_market_fee(), None);
+    assert!(!client.is_sale_token_allowed(&token));
+
+    let collector = Address::generate(&env);
+    client
+        .mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_market_fee",
+                args: (&collector, 250u32).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_market_fee(&collector, &250);
+    assert_eq!(client.get_market_fee().unwrap().collector, collector);
+}
+
+fn create_sale_token<'a>(
+    env: &Env,
+    client: &TokenizedRentObligationContractClient,
+) -> (token::Client<'a>, token::StellarAssetClient<'a>) {
+    let token = env.register_stellar_asset_contract_v2(Address::generate(env));
+    client.set_sale_token_allowed(&token.address(), &true);
+    (
+        token::Client::new(env, &token.address()),
+        token::StellarAssetClient::new(env, &token.address()),
+    )
+}
+
+#[test]
+fn test_list_and_buy_obligation_with_platform_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let chioma = create_chioma(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &chioma.address, &base_uri(&env));
+    assert_eq!(client.get_admin(), admin);
+
+    let collector = Address::generate(&env);
+    let result = client.try_set_market_fee(&collector, &10_001);
+    assert_eq!(result, Err(Ok(ObligationError::InvalidFee)));
+    client.set_market_fee(&collector, &250);
+
+    let (token, token_admin) = create_sale_token(&env, &client);
+    let unlisted_token = Address::generate(&env);
+
+    let landlord = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
+    client.mint_obligation(&agreement_id, &landlord);
+
+    let result = client.try_list_obligation(&buyer, &agreement_id, &token.address, &10_000);
+    assert_eq!(result, Err(Ok(ObligationError::Unauthorized)));
+    let result = client.try_list_obligation(&landlord, &agreement_id, &unlisted_token, &10_000);
+    assert_eq!(result, Err(Ok(ObligationError::SaleTokenNotAllowed)));
+    let result = client.try_list_obligation(&landlord, &agreement_id, &token.address, &0);
+    assert_eq!(result, Err(Ok(ObligationError::InvalidPrice)));
+
+    client.list_obligation(&landlord, &agreement_id, &token.address, &10_000);
+    client.delist_obligation(&landlord, &agreement_id);
+    assert_eq!(client.get_listing(&agreement_id), None);
+
+    let result = client.try_buy_obligation(&buyer, &agreement_id, &10_000);
+    assert_eq!(result, Err(Ok(ObligationError::ListingNotFound)));
+
+    let listing = client.list_obligation(&landlord, &agreement_id, &token.address, &12_000);
+    assert_eq!(client.get_listing(&agreement_id), Some(listing));
+
+    let result = client.try_buy_obligation(&buyer, &agreement_id, &10_000);
+    assert_eq!(result, Err(Ok(ObligationError::PriceMismatch)));
+
+    token_admin.mint(&buyer, &12_000);
+    client.buy_obligation(&buyer, &agreement_id, &12_000);
+
+    assert_eq!(client.owner_of(&agreement_id), buyer);
+    assert_eq!(token.balance(&buyer), 0);
+    assert_eq!(token.balance(&landlord), 11_700);
+    assert_eq!(token.balance(&collector), 300);
+    assert_eq!(client.get_listing(&agreement_id), None);
+}
+
+#[test]
+fn test_transfer_clears_listing() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
+    let (token, _) = create_sale_token(&env, &client);
+
+    let landlord = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
+    client.mint_obligation(&agreement_id, &landlord);
+
+    client.list_obligation(&landlord, &agreement_id, &token.address, &5_000);
+    client.transfer_obligation(&landlord, &new_owner, &agreement_id);
+
+    // The new owner is not bound by the previous owner's asking price
+    assert_eq!(client.get_listing(&agreement_id), None);
+    let result = client.try_buy_obligation(&Address::generate(&env), &agreement_id, &5_000);
+    assert_eq!(result, Err(Ok(ObligationError::ListingNotFound)));
+}
+
+#[test]
+fn test_offers_escrow_and_expire() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
+    let (token, token_admin) = create_sale_token(&env, &client);
+
+    let landlord = Address::generate(&env);
+    let bidder = Address::generate(&env);
+    let late_bidder = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
+    client.mint_obligation(&agreement_id, &landlord);
+
+    token_admin.mint(&bidder, &10_000);
+    token_admin.mint(&late_bidder, &10_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let result = client.try_make_offer(&bidder, &agreement_id, &token.address, &8_000, &1_000);
+    assert_eq!(result, Err(Ok(ObligationError::OfferExpired)));
+
+    client.make_offer(&bidder, &agreement_id, &token.address, &8_000, &2_000);
+    // A new offer replaces the old one and its escrow
+    let offer = client.make_offer(&bidder, &agreement_id, &token.address, &9_000, &2_000);
+    assert_eq!(client.get_offer(&agreement_id, &bidder), Some(offer));
+    assert_eq!(token.balance(&bidder), 1_000);
+    assert_eq!(token.balance(&client.address), 9_000);
+
+    client.make_offer(&late_bidder, &agreement_id, &token.address, &9_500, &1_500);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_600);
+    let result = client.try_accept_offer(&landlord, &agreement_id, &late_bidder);
+    assert_eq!(result, Err(Ok(ObligationError::OfferExpired)));
+    assert_eq!(client.cancel_offer(&late_bidder, &agreement_id), 9_500);
+    assert_eq!(token.balance(&late_bidder), 10_000);
+
+    let result = client.try_accept_offer(&bidder, &agreement_id, &bidder);
+    assert_eq!(result, Err(Ok(ObligationError::Unauthorized)));
+
+    client.accept_offer(&landlord, &agreement_id, &bidder);
+    assert_eq!(client.owner_of(&agreement_id), bidder);
+    assert_eq!(token.balance(&landlord), 9_000);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(client.get_offer(&agreement_id, &bidder), None);
+
+    let result = client.try_cancel_offer(&bidder, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::OfferNotFound)));
+}
+
+#[test]
+fn test_lien_blocks_transfers_until_released() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
+    let (token, _) = create_sale_token(&env, &client);
+
+    let landlord = Address::generate(&env);
+    let lender = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
+    client.mint_obligation(&agreement_id, &landlord);
+
+    let result = client.try_place_lien(&buyer, &lender, &agreement_id, &5_000, &false);
+    assert_eq!(result, Err(Ok(ObligationError::Unauthorized)));
+    let result = client.try_place_lien(&landlord, &lender, &agreement_id, &0, &false);
+    assert_eq!(result, Err(Ok(ObligationError::InvalidPrincipal)));
+
+    client.list_obligation(&landlord, &agreement_id, &token.address, &9_000);
+    let lien = client.place_lien(&landlord, &lender, &agreement_id, &5_000, &false);
+    assert_eq!(client.get_lien(&agreement_id), Some(lien));
+
+    let result = client.try_place_lien(&landlord, &buyer, &agreement_id, &5_000, &false);
+    assert_eq!(result, Err(Ok(ObligationError::LienActive)));
+    let result = client.try_transfer_obligation(&landlord, &buyer, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::LienActive)));
+    let result = client.try_buy_obligation(&buyer, &agreement_id, &9_000);
+    assert_eq!(result, Err(Ok(ObligationError::LienActive)));
+    let result = client.try_burn_obligation(&landlord, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::LienActive)));
+
+    // Without redirection the owner keeps receiving rent
+    let payee = client.get_rent_payee(&agreement_id).unwrap();
+    assert_eq!(payee.payee, landlord);
+    assert_eq!(payee.cap, None);
+
+    let result = client.try_release_lien(&landlord, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::Unauthorized)));
+
+    client.release_lien(&lender, &agreement_id);
+    assert_eq!(client.get_lien(&agreement_id), None);
+    let result = client.try_get_lien_status(&agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::LienNotFound)));
+
+    client.transfer_obligation(&landlord, &buyer, &agreement_id);
+    assert_eq!(client.owner_of(&agreement_id), buyer);
+}
+
+#[test]
+fn test_lien_redirects_rent_until_principal_repaid() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let chioma = create_chioma(&env);
+    client.initialize(&Address::generate(&env), &chioma.address, &base_uri(&env));
+    client.set_payment_contract(&Address::generate(&env));
+
+    let landlord = Address::generate(&env);
+    let lender = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    add_active_agreement(&env, &chioma, &agreement_id, &landlord);
+    client.mint_obligation(&agreement_id, &landlord);
+    client.record_rent_paid(&agreement_id, &landlord, &1000);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.place_lien(&landlord, &lender, &agreement_id, &1_500, &true);
+    assert_eq!(
+        client.get_rent_payee(&agreement_id),
+        Some(RentPayee {
+            payee: lender.clone(),
+            cap: Some(1_500),
+            owner: landlord.clone(),
+        })
+    );
+
+    // Only rent actually paid to the lender repays the lien
+    client.record_rent_paid(&agreement_id, &lender, &1000);
+    client.record_rent_paid(&agreement_id, &landlord, &1000);
+    let status = client.get_lien_status(&agreement_id);
+    assert_eq!(status.lien.repaid, 1000);
+    assert_eq!(status.outstanding, 500);
+    assert!(status.redirecting);
+
+    // Only the principal still outstanding is redirected
+    let payee = client.get_rent_payee(&agreement_id).unwrap();
+    assert_eq!(payee.payee, lender);
+    assert_eq!(payee.cap, Some(500));
+    assert_eq!(payee.owner, landlord);
+
+    let result = client.try_clear_lien(&landlord, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::LienOutstanding)));
+
+    client.record_rent_paid(&agreement_id, &lender, &1000);
+    let status = client.get_lien_status(&agreement_id);
+    assert_eq!(status.lien.repaid, 2000);
+    assert_eq!(status.outstanding, 0);
+    assert!(!status.redirecting);
+    let payee = client.get_rent_payee(&agreement_id).unwrap();
+    assert_eq!(payee.payee, landlord);
+    assert_eq!(payee.cap, None);
+
+    // Repayment alone does not unlock the obligation
+    let result = client.try_transfer_obligation(&landlord, &lender, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::LienActive)));
+
+    // The owner can clear a repaid lien the lender has not released
+    let result = client.try_clear_lien(&lender, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::Unauthorized)));
+    client.clear_lien(&landlord, &agreement_id);
+    assert_eq!(client.get_lien(&agreement_id), None);
+    client.transfer_obligation(&landlord, &lender, &agreement_id);
+}
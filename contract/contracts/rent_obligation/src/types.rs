@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, Map, String};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -7,3 +7,179 @@ pub struct RentObligation {
     pub owner: Address,
     pub minted_at: u64,
 }
+
+/// Landlord rent paid to one address over an obligation's life
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnerRentTotal {
+    pub owner: Address,
+    pub rent_received: i128,
+}
+
+/// Final summary recorded when an obligation is burned
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ObligationSettlement {
+    pub agreement_id: String,
+    /// Owner at the time of burning
+    pub final_owner: Address,
+    pub burned_by: Address,
+    pub minted_at: u64,
+    pub burned_at: u64,
+    /// Number of addresses that received rent; their totals are listed by
+    /// `get_owner_totals`
+    pub payee_count: u32,
+}
+
+/// Share structure of a fractionalized obligation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FractionInfo {
+    pub agreement_id: String,
+    /// Series number; each fractionalization after a recombination starts a
+    /// new series with its own shares and rent pool
+    pub generation: u32,
+    pub total_shares: i128,
+    /// Token rent is paid in
+    pub token: Address,
+    pub fractionalized_at: u64,
+    /// Set once all shares were recombined; rent stops accruing to shares
+    pub recombined_at: Option<u64>,
+    /// Accumulated rent per share, scaled by 10^12
+    pub rent_per_share: i128,
+    /// Rent paid to this contract for the obligation while fractionalized
+    pub rent_received: i128,
+    /// Rent paid out to shareholders
+    pub rent_claimed: i128,
+}
+
+/// A holder's shares of an obligation and the rent they have earned
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Shareholder {
+    pub balance: i128,
+    /// Rent covered by the index at the holder's last balance change
+    pub rent_debt: i128,
+    /// Rent earned and not yet claimed
+    pub unclaimed: i128,
+}
+
+/// Platform fee taken from every marketplace sale
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketFee {
+    pub collector: Address,
+    /// Fee in basis points of the sale price
+    pub fee_bps: u32,
+}
+
+/// An obligation offered for sale at a fixed price
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Listing {
+    pub agreement_id: String,
+    pub seller: Address,
+    pub token: Address,
+    pub price: i128,
+    pub listed_at: u64,
+}
+
+/// A bid for an obligation, escrowed by the contract until it is accepted
+/// or cancelled
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Offer {
+    pub agreement_id: String,
+    pub bidder: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub made_at: u64,
+    /// Last timestamp at which the offer can be accepted
+    pub expires_at: u64,
+}
+
+/// A lender's claim on an obligation pledged as collateral
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Lien {
+    pub agreement_id: String,
+    /// Owner who pledged the obligation
+    pub owner: Address,
+    pub lender: Address,
+    /// Amount to be repaid from redirected rent
+    pub principal: i128,
+    /// Whether rent goes to the lender until the principal is repaid
+    pub redirect_rent: bool,
+    pub created_at: u64,
+    /// Rent the payment contract reported paying to the lender
+    pub repaid: i128,
+}
+
+/// Repayment progress of a lien
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LienStatus {
+    pub lien: Lien,
+    /// Principal not yet covered by `lien.repaid`
+    pub outstanding: i128,
+    /// Whether rent is currently paid to the lender
+    pub redirecting: bool,
+}
+
+/// Where the landlord leg of an obligation's rent is paid
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentPayee {
+    /// Address paid the landlord leg, up to `cap`
+    pub payee: Address,
+    /// Most `payee` is owed, or None if it takes the whole leg
+    pub cap: Option<i128>,
+    /// Obligation owner, paid whatever exceeds `cap`
+    pub owner: Address,
+}
+
+/// Agreement status (mirrors chioma's `AgreementStatus`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AgreementStatus {
+    Draft,
+    Pending,
+    Active,
+    Completed,
+    Cancelled,
+    Terminated,
+    Disputed,
+}
+
+/// Payment split recorded by chioma (mirrors chioma's `PaymentSplit`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentSplit {
+    pub landlord_amount: i128,
+    pub platform_amount: i128,
+    pub token: Address,
+    pub payment_date: u64,
+    pub payer: Address,
+}
+
+/// Rent agreement of record (mirrors chioma's `RentAgreement`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentAgreement {
+    pub agreement_id: String,
+    pub landlord: Address,
+    pub tenant: Address,
+    pub agent: Option<Address>,
+    pub monthly_rent: i128,
+    pub security_deposit: i128,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub agent_commission_rate: u32,
+    pub status: AgreementStatus,
+    pub total_rent_paid: i128,
+    pub payment_count: u32,
+    pub signed_at: Option<u64>,
+    pub payment_token: Address,
+    pub next_payment_due: u64,
+    pub payment_history: Map<u32, PaymentSplit>,
+}